@@ -1,4 +1,4 @@
-use crate::{CoordNum, Point};
+use crate::{CoordFloat, CoordNum, Point};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
@@ -94,6 +94,36 @@ where
     }
 }
 
+impl<T: CoordFloat> Coordinate<T> {
+    /// Linearly interpolate between this coordinate and `other`, where `t`
+    /// of `0` gives this coordinate and `1` gives `other`.
+    ///
+    /// Values of `t` outside `[0, 1]` extrapolate along the line through
+    /// the two coordinates, beyond `other` for `t > 1`, and back past this
+    /// coordinate for `t < 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::Coordinate;
+    ///
+    /// let a = Coordinate { x: 0., y: 10. };
+    /// let b = Coordinate { x: 4., y: 20. };
+    ///
+    /// assert_eq!(a.lerp(b, 0.5), Coordinate { x: 2., y: 15. });
+    /// assert_eq!(a.lerp(b, 2.), Coordinate { x: 8., y: 30. });
+    /// ```
+    pub fn lerp(self, other: Coordinate<T>, t: T) -> Coordinate<T> {
+        // Weighting both ends, rather than adding a fraction of the
+        // difference, gives exactly `self` and `other` at `t` of 0 and 1
+        let s = T::one() - t;
+        Coordinate {
+            x: self.x * s + other.x * t,
+            y: self.y * s + other.y * t,
+        }
+    }
+}
+
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Negate a coordinate.
@@ -303,7 +333,7 @@ where
 #[cfg(feature = "rstar")]
 impl<T> ::rstar::Point for Coordinate<T>
 where
-    T: ::num_traits::Float + ::rstar::RTreeNum,
+    T: CoordNum + ::rstar::RTreeNum,
 {
     type Scalar = T;
 
@@ -332,3 +362,22 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lerp() {
+        let a = Coordinate { x: 0.1, y: -3. };
+        let b = Coordinate { x: 0.3, y: 5. };
+        assert_eq!(a.lerp(b, 0.), a);
+        assert_eq!(a.lerp(b, 1.), b);
+        assert_relative_eq!(a.lerp(b, 0.25), Coordinate { x: 0.15, y: -1. });
+        // Extrapolated back past `a`
+        assert_relative_eq!(a.lerp(b, -0.5), Coordinate { x: 0., y: -7. });
+        assert_relative_eq!(a.lerp(b, 1.5), Coordinate { x: 0.4, y: 9. });
+        // The same coordinate throughout
+        assert_relative_eq!(a.lerp(a, 0.7), a);
+    }
+}
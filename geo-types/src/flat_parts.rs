@@ -0,0 +1,409 @@
+use crate::{
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use std::convert::TryFrom;
+
+const POINT: u64 = 1;
+const LINE_STRING: u64 = 2;
+const POLYGON: u64 = 3;
+const MULTI_POINT: u64 = 4;
+const MULTI_LINE_STRING: u64 = 5;
+const MULTI_POLYGON: u64 = 6;
+const GEOMETRY_COLLECTION: u64 = 7;
+const LINE: u64 = 101;
+const RECT: u64 = 102;
+const TRIANGLE: u64 = 103;
+
+// The deepest nesting of `GeometryCollection`s which is read, so that a
+// layout can't exhaust the stack
+const MAX_NESTING: usize = 128;
+
+/// The structure of a `Geometry` whose coordinates have been flattened into
+/// a buffer of `f64`s, by [`Geometry::to_flat_parts`].
+///
+/// The coordinate buffer holds every coordinate of the geometry, in the order
+/// they are visited below, as consecutive `x, y` pairs. The layout is a
+/// sequence of `u64`s describing how to divide it back up. It starts with a
+/// type tag, which is followed by counts, depending on the type:
+///
+/// | Type                 | Tag   | Followed by                                   | Coordinates             |
+/// |----------------------|-------|-----------------------------------------------|-------------------------|
+/// | `Point`              | `1`   |                                               | 1                       |
+/// | `LineString`         | `2`   | the number of coordinates, `n`                | `n`                     |
+/// | `Polygon`            | `3`   | the number of rings, then the length of each  | each ring, exterior first |
+/// | `MultiPoint`         | `4`   | the number of points, `n`                     | `n`                     |
+/// | `MultiLineString`    | `5`   | the number of `LineString`s, then the length of each | each `LineString` |
+/// | `MultiPolygon`       | `6`   | the number of `Polygon`s, then for each, its number of rings and their lengths | each ring |
+/// | `GeometryCollection` | `7`   | the number of members, then the layout of each member in turn | those of each member |
+/// | `Line`               | `101` |                                               | 2: start, end           |
+/// | `Rect`               | `102` |                                               | 2: min, max             |
+/// | `Triangle`           | `103` |                                               | 3                       |
+///
+/// The tags of the types in the Simple Features specification match their
+/// WKB geometry type codes. Ring lengths include the closing coordinate.
+///
+/// For example, a `Polygon` with a square exterior and one triangular
+/// interior has the layout `[3, 2, 5, 4]`, and 9 coordinates: 18 `f64`s.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct GeometryLayout(pub Vec<u64>);
+
+impl Geometry<f64> {
+    /// Flatten the geometry into a contiguous buffer of coordinates and a
+    /// [`GeometryLayout`] describing its structure, which can be passed
+    /// across an FFI boundary without converting each coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry, GeometryLayout};
+    ///
+    /// let geometry = Geometry::LineString(line_string![
+    ///     (x: 0., y: 1.),
+    ///     (x: 2., y: 3.),
+    /// ]);
+    ///
+    /// let (coords, layout) = geometry.to_flat_parts();
+    /// assert_eq!(coords, vec![0., 1., 2., 3.]);
+    /// assert_eq!(layout, GeometryLayout(vec![2, 2]));
+    /// ```
+    pub fn to_flat_parts(&self) -> (Vec<f64>, GeometryLayout) {
+        let mut coords = vec![];
+        let mut layout = vec![];
+        flatten(self, &mut coords, &mut layout);
+        (coords, GeometryLayout(layout))
+    }
+
+    /// Rebuild a geometry from a buffer of coordinates and a
+    /// [`GeometryLayout`], as produced by [`Geometry::to_flat_parts`].
+    ///
+    /// An `InvalidFlatPartsError` is returned if the layout is not valid, or
+    /// does not use exactly the coordinates in the buffer. It is also
+    /// returned if `GeometryCollection`s are nested more than 128 deep, so
+    /// that untrusted input can't overflow the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryLayout};
+    ///
+    /// let geometry = Geometry::from_flat_parts(&[1., 2.], &GeometryLayout(vec![1]));
+    /// assert_eq!(geometry, Ok(Geometry::Point(point!(x: 1., y: 2.))));
+    ///
+    /// // Too many coordinates
+    /// assert!(Geometry::from_flat_parts(&[1., 2., 3., 4.], &GeometryLayout(vec![1])).is_err());
+    /// ```
+    pub fn from_flat_parts(
+        coords: &[f64],
+        layout: &GeometryLayout,
+    ) -> Result<Geometry<f64>, InvalidFlatPartsError> {
+        let mut reader = Reader {
+            coords,
+            layout: &layout.0,
+            nesting: 0,
+        };
+        let geometry = reader.geometry()?;
+        if reader.coords.is_empty() && reader.layout.is_empty() {
+            Ok(geometry)
+        } else {
+            Err(InvalidFlatPartsError)
+        }
+    }
+}
+
+fn flatten(geometry: &Geometry<f64>, coords: &mut Vec<f64>, layout: &mut Vec<u64>) {
+    fn push_coords<'a>(coords: &mut Vec<f64>, iter: impl Iterator<Item = &'a Coordinate<f64>>) {
+        for coord in iter {
+            coords.push(coord.x);
+            coords.push(coord.y);
+        }
+    }
+    fn push_polygon(polygon: &Polygon<f64>, coords: &mut Vec<f64>, layout: &mut Vec<u64>) {
+        layout.push(1 + polygon.interiors().len() as u64);
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            layout.push(ring.0.len() as u64);
+            push_coords(coords, ring.0.iter());
+        }
+    }
+
+    match geometry {
+        Geometry::Point(point) => {
+            layout.push(POINT);
+            push_coords(coords, std::iter::once(&point.0));
+        }
+        Geometry::LineString(line_string) => {
+            layout.extend_from_slice(&[LINE_STRING, line_string.0.len() as u64]);
+            push_coords(coords, line_string.0.iter());
+        }
+        Geometry::Polygon(polygon) => {
+            layout.push(POLYGON);
+            push_polygon(polygon, coords, layout);
+        }
+        Geometry::MultiPoint(multi_point) => {
+            layout.extend_from_slice(&[MULTI_POINT, multi_point.0.len() as u64]);
+            push_coords(coords, multi_point.0.iter().map(|point| &point.0));
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            layout.extend_from_slice(&[MULTI_LINE_STRING, multi_line_string.0.len() as u64]);
+            for line_string in &multi_line_string.0 {
+                layout.push(line_string.0.len() as u64);
+                push_coords(coords, line_string.0.iter());
+            }
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            layout.extend_from_slice(&[MULTI_POLYGON, multi_polygon.0.len() as u64]);
+            for polygon in &multi_polygon.0 {
+                push_polygon(polygon, coords, layout);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            layout.extend_from_slice(&[GEOMETRY_COLLECTION, collection.0.len() as u64]);
+            for member in &collection.0 {
+                flatten(member, coords, layout);
+            }
+        }
+        Geometry::Line(line) => {
+            layout.push(LINE);
+            push_coords(coords, [line.start, line.end].iter());
+        }
+        Geometry::Rect(rect) => {
+            layout.push(RECT);
+            push_coords(coords, [rect.min(), rect.max()].iter());
+        }
+        Geometry::Triangle(triangle) => {
+            layout.push(TRIANGLE);
+            push_coords(coords, triangle.to_array().iter());
+        }
+    }
+}
+
+/// Reads geometries from the front of the flat parts, advancing past them.
+struct Reader<'a> {
+    coords: &'a [f64],
+    layout: &'a [u64],
+    // The number of `GeometryCollection`s being read
+    nesting: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn next(&mut self) -> Result<u64, InvalidFlatPartsError> {
+        let (&next, rest) = self.layout.split_first().ok_or(InvalidFlatPartsError)?;
+        self.layout = rest;
+        Ok(next)
+    }
+
+    fn count(&mut self) -> Result<usize, InvalidFlatPartsError> {
+        usize::try_from(self.next()?).map_err(|_| InvalidFlatPartsError)
+    }
+
+    fn coords(&mut self, count: usize) -> Result<Vec<Coordinate<f64>>, InvalidFlatPartsError> {
+        let len = count.checked_mul(2).ok_or(InvalidFlatPartsError)?;
+        if len > self.coords.len() {
+            return Err(InvalidFlatPartsError);
+        }
+        let (taken, rest) = self.coords.split_at(len);
+        self.coords = rest;
+        Ok(taken
+            .chunks(2)
+            .map(|pair| Coordinate {
+                x: pair[0],
+                y: pair[1],
+            })
+            .collect())
+    }
+
+    fn line_string(&mut self) -> Result<LineString<f64>, InvalidFlatPartsError> {
+        let count = self.count()?;
+        Ok(LineString(self.coords(count)?))
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>, InvalidFlatPartsError> {
+        let rings = self.count()?;
+        if rings == 0 {
+            return Err(InvalidFlatPartsError);
+        }
+        let exterior = self.line_string()?;
+        let interiors = (1..rings)
+            .map(|_| self.line_string())
+            .collect::<Result<_, _>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+
+    fn geometry(&mut self) -> Result<Geometry<f64>, InvalidFlatPartsError> {
+        let geometry = match self.next()? {
+            POINT => Geometry::Point(Point(self.coords(1)?[0])),
+            LINE_STRING => Geometry::LineString(self.line_string()?),
+            POLYGON => Geometry::Polygon(self.polygon()?),
+            MULTI_POINT => {
+                let count = self.count()?;
+                let points = self.coords(count)?.into_iter().map(Point).collect();
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            MULTI_LINE_STRING => {
+                let count = self.count()?;
+                let line_strings = (0..count)
+                    .map(|_| self.line_string())
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiLineString(MultiLineString(line_strings))
+            }
+            MULTI_POLYGON => {
+                let count = self.count()?;
+                let polygons = (0..count)
+                    .map(|_| self.polygon())
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            GEOMETRY_COLLECTION => {
+                if self.nesting == MAX_NESTING {
+                    return Err(InvalidFlatPartsError);
+                }
+                self.nesting += 1;
+                let count = self.count()?;
+                let members = (0..count)
+                    .map(|_| self.geometry())
+                    .collect::<Result<_, _>>()?;
+                self.nesting -= 1;
+                Geometry::GeometryCollection(GeometryCollection(members))
+            }
+            LINE => {
+                let coords = self.coords(2)?;
+                Geometry::Line(Line::new(coords[0], coords[1]))
+            }
+            RECT => {
+                let coords = self.coords(2)?;
+                Geometry::Rect(Rect::new(coords[0], coords[1]))
+            }
+            TRIANGLE => {
+                let coords = self.coords(3)?;
+                Geometry::Triangle(Triangle(coords[0], coords[1], coords[2]))
+            }
+            _ => return Err(InvalidFlatPartsError),
+        };
+        Ok(geometry)
+    }
+}
+
+const INVALID_FLAT_PARTS_ERROR: &str =
+    "Failed to create Geometry: the layout does not match the coordinates";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidFlatPartsError;
+
+impl std::error::Error for InvalidFlatPartsError {}
+
+impl std::fmt::Display for InvalidFlatPartsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", INVALID_FLAT_PARTS_ERROR)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn round_trip(geometry: Geometry<f64>) -> (Vec<f64>, GeometryLayout) {
+        let (coords, layout) = geometry.to_flat_parts();
+        assert_eq!(Geometry::from_flat_parts(&coords, &layout), Ok(geometry));
+        (coords, layout)
+    }
+
+    #[test]
+    fn polygon_with_holes() {
+        let polygon = polygon!(
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)],
+                [(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+            ],
+        );
+        let (coords, layout) = round_trip(Geometry::Polygon(polygon));
+        assert_eq!(layout, GeometryLayout(vec![3, 3, 5, 4, 5]));
+        assert_eq!(coords.len(), 2 * (5 + 4 + 5));
+        assert_eq!(&coords[10..12], &[1., 1.]);
+    }
+
+    #[test]
+    fn collection() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point!(x: 1., y: 2.)),
+            Geometry::MultiPolygon(MultiPolygon(vec![square.clone(), square])),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::Line(Line::new((0., 0.), (3., 4.))),
+                Geometry::MultiLineString(MultiLineString(vec![
+                    line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                    line_string![],
+                ])),
+            ])),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 3., y: 3.)])),
+            Geometry::Rect(Rect::new((0., 0.), (2., 2.))),
+            Geometry::Triangle(Triangle((0., 0.).into(), (1., 0.).into(), (0., 1.).into())),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+        ]);
+        let (coords, layout) = round_trip(Geometry::GeometryCollection(collection));
+        assert_eq!(
+            layout,
+            GeometryLayout(vec![
+                7, 7, 1, 6, 2, 1, 5, 1, 5, 7, 2, 101, 5, 2, 2, 0, 4, 1, 102, 103, 7, 0
+            ])
+        );
+        assert_eq!(coords.len(), 2 * (1 + 10 + 2 + 2 + 1 + 2 + 3));
+    }
+
+    #[test]
+    fn invalid() {
+        let (coords, layout) = Geometry::LineString(line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+        ])
+        .to_flat_parts();
+        // Missing and extra coordinates
+        assert!(Geometry::from_flat_parts(&coords[..3], &layout).is_err());
+        assert!(Geometry::from_flat_parts(&[&coords[..], &[2., 2.]].concat(), &layout).is_err());
+        // Truncated and extra layout
+        assert!(Geometry::from_flat_parts(&coords, &GeometryLayout(vec![2])).is_err());
+        assert!(Geometry::from_flat_parts(&coords, &GeometryLayout(vec![2, 2, 0])).is_err());
+        // Unknown tag, and a polygon without an exterior
+        assert!(Geometry::from_flat_parts(&coords, &GeometryLayout(vec![42, 2])).is_err());
+        assert!(Geometry::from_flat_parts(&[], &GeometryLayout(vec![3, 0])).is_err());
+        // An overflowing count
+        assert!(Geometry::from_flat_parts(&coords, &GeometryLayout(vec![2, u64::MAX])).is_err());
+    }
+
+    #[test]
+    fn nesting() {
+        let nested = |depth: usize| {
+            let mut layout = [GEOMETRY_COLLECTION, 1].repeat(depth);
+            layout.extend_from_slice(&[POINT]);
+            GeometryLayout(layout)
+        };
+        let geometry = Geometry::from_flat_parts(&[1., 2.], &nested(MAX_NESTING)).unwrap();
+        assert_eq!(
+            geometry.to_flat_parts(),
+            (vec![1., 2.], nested(MAX_NESTING))
+        );
+        assert!(Geometry::from_flat_parts(&[1., 2.], &nested(MAX_NESTING + 1)).is_err());
+
+        // Far deeper than the stack could hold
+        let deep = [GEOMETRY_COLLECTION, 1].repeat(1_000_000);
+        assert!(Geometry::from_flat_parts(&[], &GeometryLayout(deep)).is_err());
+
+        // Siblings don't count towards the nesting
+        let mut wide = vec![GEOMETRY_COLLECTION, 1000];
+        for _ in 0..1000 {
+            wide.extend_from_slice(&[GEOMETRY_COLLECTION, 0]);
+        }
+        assert!(Geometry::from_flat_parts(&[], &GeometryLayout(wide)).is_ok());
+    }
+}
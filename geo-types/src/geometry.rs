@@ -180,6 +180,112 @@ impl<T: CoordNum> Geometry<T> {
             None
         }
     }
+
+    /// Return the number of parts of the Geometry, like PostGIS's
+    /// `ST_NumGeometries`: the number of members of a multi-geometry or
+    /// `GeometryCollection`, and 1 for any other Geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, MultiPoint};
+    ///
+    /// let point = Geometry::Point(point!(x: 1., y: 1.));
+    /// assert_eq!(point.num_geometries(), 1);
+    ///
+    /// let multi_point = Geometry::MultiPoint(MultiPoint::from(vec![(0., 0.), (1., 1.)]));
+    /// assert_eq!(multi_point.num_geometries(), 2);
+    /// ```
+    pub fn num_geometries(&self) -> usize {
+        match self {
+            Geometry::MultiPoint(mp) => mp.0.len(),
+            Geometry::MultiLineString(mls) => mls.0.len(),
+            Geometry::MultiPolygon(mp) => mp.0.len(),
+            Geometry::GeometryCollection(gc) => gc.0.len(),
+            _ => 1,
+        }
+    }
+
+    /// Return a copy of the part of the Geometry at index `n`, like
+    /// PostGIS's `ST_GeometryN`, or None if there is no such part.
+    ///
+    /// The parts are those counted by
+    /// [`num_geometries`](Self::num_geometries): the members of a
+    /// multi-geometry or `GeometryCollection`, or the Geometry itself at
+    /// index 0. Unlike `ST_GeometryN`, the index starts from 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, MultiPoint};
+    ///
+    /// let multi_point = Geometry::MultiPoint(MultiPoint::from(vec![(0., 0.), (1., 1.)]));
+    ///
+    /// assert_eq!(
+    ///     multi_point.geometry_at(1),
+    ///     Some(Geometry::Point(point!(x: 1., y: 1.)))
+    /// );
+    /// assert_eq!(multi_point.geometry_at(2), None);
+    /// ```
+    pub fn geometry_at(&self, n: usize) -> Option<Geometry<T>> {
+        match self {
+            Geometry::MultiPoint(mp) => mp.0.get(n).map(|&p| Geometry::Point(p)),
+            Geometry::MultiLineString(mls) => mls.0.get(n).cloned().map(Geometry::LineString),
+            Geometry::MultiPolygon(mp) => mp.0.get(n).cloned().map(Geometry::Polygon),
+            Geometry::GeometryCollection(gc) => gc.0.get(n).cloned(),
+            _ if n == 0 => Some(self.clone()),
+            _ => None,
+        }
+    }
+
+    /// Split the Geometry into its single parts, like PostGIS's `ST_Dump`.
+    ///
+    /// The members of a multi-geometry are returned as separate
+    /// geometries, and those of a `GeometryCollection` are flattened in
+    /// turn, so that no multi-geometries or collections remain. Any other
+    /// Geometry is returned on its own. Empty multi-geometries and
+    /// collections have no parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, point, Geometry, GeometryCollection, MultiPoint};
+    ///
+    /// let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+    ///     Geometry::MultiPoint(MultiPoint::from(vec![(0., 0.), (1., 1.)])),
+    ///     Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]),
+    /// ]));
+    ///
+    /// assert_eq!(
+    ///     collection.flatten(),
+    ///     vec![
+    ///         Geometry::Point(point!(x: 0., y: 0.)),
+    ///         Geometry::Point(point!(x: 1., y: 1.)),
+    ///         Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn flatten(&self) -> Vec<Geometry<T>> {
+        let mut parts = vec![];
+        self.flatten_into(&mut parts);
+        parts
+    }
+
+    fn flatten_into(&self, parts: &mut Vec<Geometry<T>>) {
+        match self {
+            Geometry::MultiPoint(mp) => parts.extend(mp.iter().map(|&p| Geometry::Point(p))),
+            Geometry::MultiLineString(mls) => {
+                parts.extend(mls.iter().cloned().map(Geometry::LineString))
+            }
+            Geometry::MultiPolygon(mp) => parts.extend(mp.iter().cloned().map(Geometry::Polygon)),
+            Geometry::GeometryCollection(gc) => {
+                for member in gc.iter() {
+                    member.flatten_into(parts);
+                }
+            }
+            _ => parts.push(self.clone()),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -273,3 +379,102 @@ impl<T: CoordNum> TryFrom<Geometry<T>> for MultiPolygon<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn parts() {
+        let point = Geometry::Point(point!(x: 1., y: 2.));
+        assert_eq!(point.num_geometries(), 1);
+        assert_eq!(point.geometry_at(0), Some(point.clone()));
+        assert_eq!(point.geometry_at(1), None);
+
+        let polygons = vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)],
+            polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.)],
+        ];
+        let multi_polygon = Geometry::MultiPolygon(MultiPolygon(polygons.clone()));
+        assert_eq!(multi_polygon.num_geometries(), 3);
+        let parts: Vec<_> = (0..multi_polygon.num_geometries())
+            .map(|n| multi_polygon.geometry_at(n).unwrap())
+            .collect();
+        assert_eq!(
+            parts,
+            polygons
+                .into_iter()
+                .map(Geometry::Polygon)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(multi_polygon.geometry_at(3), None);
+
+        // The members of a collection, which aren't themselves split up
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+            multi_polygon.clone(),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+        ]));
+        assert_eq!(collection.num_geometries(), 2);
+        assert_eq!(collection.geometry_at(0), Some(multi_polygon));
+
+        let empty: Geometry<f64> = Geometry::MultiPoint(MultiPoint(vec![]));
+        assert_eq!(empty.num_geometries(), 0);
+        assert_eq!(empty.geometry_at(0), None);
+    }
+
+    #[test]
+    fn flatten() {
+        let is_single = |geometry: &Geometry<f64>| {
+            !matches!(
+                geometry,
+                Geometry::MultiPoint(_)
+                    | Geometry::MultiLineString(_)
+                    | Geometry::MultiPolygon(_)
+                    | Geometry::GeometryCollection(_)
+            )
+        };
+
+        let polygons = vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)],
+            polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.)],
+        ];
+        let multi_polygon = Geometry::MultiPolygon(MultiPolygon(polygons.clone()));
+        let parts = multi_polygon.flatten();
+        assert_eq!(
+            parts,
+            polygons
+                .into_iter()
+                .map(Geometry::Polygon)
+                .collect::<Vec<_>>()
+        );
+
+        let point = Geometry::Point(point!(x: 1., y: 2.));
+        assert_eq!(point.flatten(), vec![point.clone()]);
+
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![
+            point.clone(),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                multi_polygon.clone(),
+                Geometry::MultiLineString(MultiLineString(vec![
+                    line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                    line_string![(x: 1., y: 1.), (x: 2., y: 0.)],
+                ])),
+                Geometry::MultiPoint(MultiPoint(vec![])),
+            ])),
+            Geometry::GeometryCollection(GeometryCollection(vec![])),
+            Geometry::MultiPoint(MultiPoint::from(vec![(5., 5.)])),
+        ]));
+        let parts = nested.flatten();
+        assert_eq!(parts.len(), 1 + 3 + 2 + 1);
+        assert!(parts.iter().all(is_single));
+        assert_eq!(parts[0], point);
+        assert_eq!(
+            parts[4],
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)])
+        );
+        assert_eq!(parts[6], Geometry::Point(point!(x: 5., y: 5.)));
+    }
+}
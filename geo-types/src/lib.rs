@@ -81,13 +81,13 @@ mod line;
 pub use crate::line::Line;
 
 mod line_string;
-pub use crate::line_string::{LineString, PointsIter};
+pub use crate::line_string::{InvalidRingError, LineString, PointsIter};
 
 mod multi_line_string;
 pub use crate::multi_line_string::MultiLineString;
 
 mod polygon;
-pub use crate::polygon::Polygon;
+pub use crate::polygon::{Polygon, UnclosedRingError};
 
 mod multi_polygon;
 pub use crate::multi_polygon::MultiPolygon;
@@ -98,12 +98,21 @@ pub use crate::geometry::Geometry;
 mod geometry_collection;
 pub use crate::geometry_collection::GeometryCollection;
 
+mod flat_parts;
+pub use crate::flat_parts::{GeometryLayout, InvalidFlatPartsError};
+
 mod triangle;
 pub use crate::triangle::Triangle;
 
 mod rect;
 pub use crate::rect::{InvalidRectCoordinatesError, Rect};
 
+mod ordered_coord;
+pub use crate::ordered_coord::OrderedCoord;
+
+mod ordered_geometry;
+pub use crate::ordered_geometry::OrderedGeometry;
+
 #[macro_use]
 mod macros;
 
@@ -187,6 +196,30 @@ mod tests {
         assert_eq!(p.x(), 1_000_000i64);
     }
 
+    #[test]
+    fn test_defaults() {
+        assert_eq!(LineString::<f64>::default(), LineString(vec![]));
+        assert_eq!(MultiPoint::<f64>::default(), MultiPoint(vec![]));
+        assert_eq!(MultiLineString::<f64>::default(), MultiLineString(vec![]));
+        assert_eq!(MultiPolygon::<f64>::default(), MultiPolygon(vec![]));
+        assert!(GeometryCollection::<i32>::default().is_empty());
+
+        // Such as to fill in the rest of a struct
+        #[derive(Default)]
+        struct Layer {
+            name: String,
+            points: MultiPoint<f64>,
+            lines: MultiLineString<f64>,
+        }
+        let layer = Layer {
+            name: "roads".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(layer.name, "roads");
+        assert!(layer.points.0.is_empty());
+        assert!(layer.lines.0.is_empty());
+    }
+
     #[cfg(feature = "rstar")]
     #[test]
     /// ensure Line's SpatialObject impl is correct
@@ -202,6 +235,38 @@ mod tests {
         assert_relative_eq!(25.999999999999996, l.distance_2(&Point::new(4.0, 10.0)));
     }
 
+    #[cfg(feature = "rstar")]
+    #[test]
+    /// ensure integer geometries can be stored in an RTree
+    fn integer_rtree_test() {
+        use rstar::{RTree, RTreeObject, AABB};
+
+        let l = Line::new(Coordinate { x: 5, y: 0 }, Coordinate { x: 0, y: 5 });
+        assert_eq!(
+            l.envelope(),
+            AABB::from_corners(Point::new(0i64, 0), Point::new(5, 5))
+        );
+        let ls: LineString<i64> = vec![(0, 0), (10, 2), (3, 7)].into();
+        assert_eq!(
+            ls.envelope(),
+            AABB::from_corners(Point::new(0, 0), Point::new(10, 7))
+        );
+
+        let tree = RTree::bulk_load(vec![
+            Point::new(0i64, 0),
+            Point::new(3, 4),
+            Point::new(10, 10),
+        ]);
+        let found: Vec<_> = tree
+            .locate_in_envelope(&AABB::from_corners(Point::new(-1, -1), Point::new(5, 5)))
+            .collect();
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            tree.nearest_neighbor(&Coordinate { x: 9, y: 8 }.into()),
+            Some(&Point::new(10, 10))
+        );
+    }
+
     #[test]
     fn test_rects() {
         let r = Rect::new(Coordinate { x: -1., y: -1. }, Coordinate { x: 1., y: 1. });
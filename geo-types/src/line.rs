@@ -1,4 +1,4 @@
-use crate::{CoordNum, Coordinate, Point};
+use crate::{CoordFloat, CoordNum, Coordinate, Point};
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 
@@ -159,6 +159,77 @@ where
     pub fn points(&self) -> (Point<T>, Point<T>) {
         (self.start_point(), self.end_point())
     }
+
+    /// Return the `Line` with its start and end swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Line};
+    ///
+    /// let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 2. });
+    ///
+    /// assert_eq!(
+    ///     line.reverse(),
+    ///     Line::new(Coordinate { x: 1., y: 2. }, Coordinate { x: 0., y: 0. })
+    /// );
+    /// ```
+    pub fn reverse(&self) -> Line<T> {
+        Line::new(self.end, self.start)
+    }
+}
+
+impl<T: CoordFloat> Line<T> {
+    /// Calculate the perpendicular distance from a point to the infinite
+    /// line through the start and end of the `Line`.
+    ///
+    /// Unlike the distance to the line segment, this doesn't depend on
+    /// whether the point lies beyond either end. If the `Line` has zero
+    /// length, it doesn't define a direction, and the distance to its
+    /// start is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Line, Point};
+    ///
+    /// let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 0. });
+    ///
+    /// assert_eq!(line.perpendicular_distance(Point::new(1., 3.)), 3.);
+    /// assert_eq!(line.perpendicular_distance(Point::new(5., -3.)), 3.);
+    /// ```
+    pub fn perpendicular_distance(&self, p: Point<T>) -> T {
+        self.signed_perpendicular_distance(p).abs()
+    }
+
+    /// Calculate the signed perpendicular distance from a point to the
+    /// infinite line through the start and end of the `Line`.
+    ///
+    /// The distance is positive if the point lies to the left of the line,
+    /// looking from its start towards its end, negative if it lies to the
+    /// right, and zero if it lies on the line. If the `Line` has zero
+    /// length, it doesn't define a side, and the (non-negative) distance to
+    /// its start is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Line, Point};
+    ///
+    /// let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 0. });
+    ///
+    /// assert_eq!(line.signed_perpendicular_distance(Point::new(1., 3.)), 3.);
+    /// assert_eq!(line.signed_perpendicular_distance(Point::new(5., -3.)), -3.);
+    /// ```
+    pub fn signed_perpendicular_distance(&self, p: Point<T>) -> T {
+        let delta = self.delta();
+        let offset = p.0 - self.start;
+        let length = delta.x.hypot(delta.y);
+        if length == T::zero() {
+            return offset.x.hypot(offset.y);
+        }
+        (delta.x * offset.y - delta.y * offset.x) / length
+    }
 }
 
 impl<T: CoordNum> From<[(T, T); 2]> for Line<T> {
@@ -230,7 +301,7 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Line<T> {
 #[cfg(feature = "rstar")]
 impl<T> ::rstar::RTreeObject for Line<T>
 where
-    T: ::num_traits::Float + ::rstar::RTreeNum,
+    T: CoordNum + ::rstar::RTreeNum,
 {
     type Envelope = ::rstar::AABB<Point<T>>;
 
@@ -302,6 +373,44 @@ mod test {
         assert!(line.abs_diff_ne(&line_end_y, 1e-12));
     }
 
+    #[test]
+    fn test_reverse() {
+        let line = Line::new(Coordinate { x: 1., y: 2. }, Coordinate { x: 3., y: 5. });
+        assert_eq!(line.reverse().start, line.end);
+        assert_eq!(line.reverse().end, line.start);
+        assert_eq!(line.reverse().reverse(), line);
+        assert_eq!(line.reverse().determinant(), -line.determinant());
+    }
+
+    #[test]
+    fn test_perpendicular_distance() {
+        let line = Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 4., y: 5. });
+        // Above, i.e. to the left
+        let above = Point::new(-3., 4.);
+        assert_relative_eq!(line.signed_perpendicular_distance(above), 5.);
+        assert_relative_eq!(line.perpendicular_distance(above), 5.);
+        // Below, i.e. to the right, beyond the end of the segment
+        let below = Point::new(11., 6.);
+        assert_relative_eq!(line.signed_perpendicular_distance(below), -5.);
+        assert_relative_eq!(line.perpendicular_distance(below), 5.);
+        // On the line, outside the segment
+        let on = Point::new(7., 9.);
+        assert_relative_eq!(line.signed_perpendicular_distance(on), 0.);
+        assert_relative_eq!(line.perpendicular_distance(on), 0.);
+
+        // Reversing the line changes the side
+        let reversed = Line::new(line.end, line.start);
+        assert_relative_eq!(reversed.signed_perpendicular_distance(above), -5.);
+    }
+
+    #[test]
+    fn test_perpendicular_distance_degenerate() {
+        let line = Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 1., y: 1. });
+        let point = Point::new(4., -3.);
+        assert_relative_eq!(line.perpendicular_distance(point), 5.);
+        assert_relative_eq!(line.signed_perpendicular_distance(point), 5.);
+    }
+
     #[test]
     fn test_relative_eq() {
         let delta = 1e-6;
@@ -114,6 +114,12 @@ pub struct LineString<T>(pub Vec<Coordinate<T>>)
 where
     T: CoordNum;
 
+impl<T: CoordNum> Default for LineString<T> {
+    fn default() -> Self {
+        LineString(Vec::new())
+    }
+}
+
 /// A `Point` iterator returned by the `points_iter` method
 #[derive(Debug)]
 pub struct PointsIter<'a, T: CoordNum + 'a>(::std::slice::Iter<'a, Coordinate<T>>);
@@ -245,6 +251,259 @@ impl<T: CoordNum> LineString<T> {
     pub fn is_closed(&self) -> bool {
         self.0.first() == self.0.last()
     }
+
+    /// Checks if the linestring is a valid ring for a `Polygon`: it is
+    /// closed, and has at least 4 coordinates, so that it encloses an
+    /// area unless its coordinates are collinear.
+    ///
+    /// Unlike [`is_closed`](Self::is_closed), this is `false` for an empty
+    /// `LineString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+    /// assert!(ring.is_ring());
+    ///
+    /// // Closed, but only a line there and back
+    /// let too_short = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+    /// assert!(too_short.is_closed());
+    /// assert!(!too_short.is_ring());
+    /// ```
+    pub fn is_ring(&self) -> bool {
+        self.0.len() >= 4 && self.is_closed()
+    }
+
+    /// Check that the `LineString` [is a ring](Self::is_ring), for use as
+    /// the exterior or an interior of a `Polygon`, returning it unchanged
+    /// if it is.
+    ///
+    /// Unlike `Polygon::new`, this doesn't close the `LineString`, which is
+    /// useful when an open `LineString` is a sign of a problem in the
+    /// input.
+    ///
+    /// # Errors
+    ///
+    /// An `InvalidRingError` is returned if the `LineString` isn't closed,
+    /// or has fewer than 4 coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Polygon};
+    ///
+    /// let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+    /// let polygon = Polygon::new(ring.try_into_ring().unwrap(), vec![]);
+    ///
+    /// let open = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// assert!(open.try_into_ring().is_err());
+    /// ```
+    pub fn try_into_ring(self) -> Result<LineString<T>, InvalidRingError> {
+        if self.is_ring() {
+            Ok(self)
+        } else {
+            Err(InvalidRingError)
+        }
+    }
+
+    /// Twice the signed area enclosed by the `LineString`, as a ring: the
+    /// raw sum of the shoelace formula, without the division by two.
+    ///
+    /// It is positive if the ring is wound counter-clockwise, negative if
+    /// it is wound clockwise, and zero if it encloses no area, or isn't
+    /// closed. Where the ring is so thin that its orientation is in doubt,
+    /// the magnitude can be compared against a threshold, rather than
+    /// relying on the sign alone. With integer coordinates, the result is
+    /// exact, since it isn't halved.
+    ///
+    /// The coordinates are taken relative to the first, to reduce rounding
+    /// errors. Even so, each term is the product of two coordinate
+    /// differences, so with integer coordinates the sum overflows if the
+    /// ring spans more than about the square root of the range of `T`,
+    /// such as `46_340` for `i32`; this panics in debug builds, and wraps
+    /// around in release builds. Use a wider integer type if in doubt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let ccw = line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 3), (x: 0, y: 0)];
+    /// assert_eq!(ccw.signed_area_times_two(), 12);
+    /// assert_eq!(ccw.reverse().signed_area_times_two(), -12);
+    /// ```
+    pub fn signed_area_times_two(&self) -> T {
+        // Fewer than 3 coordinates enclose no area
+        if self.0.len() < 3 || !self.is_closed() {
+            return T::zero();
+        }
+        let shift = self.0[0];
+        self.lines().fold(T::zero(), |total, line| {
+            let (start, end) = (line.start - shift, line.end - shift);
+            total + (start.x * end.y - start.y * end.x)
+        })
+    }
+
+    /// Return a `LineString` with the coordinates in reverse order.
+    ///
+    /// This reverses the direction of travel along the `LineString`, and
+    /// the winding order of a ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    ///
+    /// assert_eq!(
+    ///     line_string.reverse(),
+    ///     line_string![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.)]
+    /// );
+    /// ```
+    pub fn reverse(&self) -> LineString<T> {
+        let mut reversed = self.clone();
+        reversed.reverse_inplace();
+        reversed
+    }
+
+    /// Reverse the order of the coordinates of the `LineString`, in place.
+    pub fn reverse_inplace(&mut self) {
+        self.0.reverse();
+    }
+
+    /// Whether the `LineString` has the same coordinates as `other`, in
+    /// either the same or the reverse order, such as for an edge shared by
+    /// two polygons which trace it in opposite directions.
+    ///
+    /// Coordinates are compared exactly. A closed ring starting at a
+    /// different coordinate is not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// let reversed = line_string![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.)];
+    ///
+    /// assert!(line_string.equals_undirected(&reversed));
+    /// assert_ne!(line_string, reversed);
+    /// ```
+    pub fn equals_undirected(&self, other: &LineString<T>) -> bool {
+        self.0.len() == other.0.len()
+            && (self.0 == other.0 || self.0.iter().eq(other.0.iter().rev()))
+    }
+
+    /// Rotate a closed `LineString` so that it starts, and ends, at the
+    /// coordinate at `index`.
+    ///
+    /// The result passes through the same coordinates, in the same order,
+    /// so it remains closed and has the same winding order. The last
+    /// coordinate of a closed `LineString` is the same as its first, so
+    /// `index` may also refer to it, which leaves the `LineString`
+    /// unchanged.
+    ///
+    /// A `LineString` which isn't closed is rotated as a plain sequence of
+    /// coordinates, so that the coordinate at `index` becomes the first,
+    /// and those before it are moved to the end; it remains unclosed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let ring = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 0., y: 0.),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     ring.rotate_to_start(2),
+    ///     line_string![
+    ///         (x: 1., y: 1.),
+    ///         (x: 0., y: 0.),
+    ///         (x: 1., y: 0.),
+    ///         (x: 1., y: 1.),
+    ///     ]
+    /// );
+    /// ```
+    pub fn rotate_to_start(&self, index: usize) -> LineString<T> {
+        assert!(
+            index < self.0.len(),
+            "index {} is out of bounds for a LineString of {} coordinates",
+            index,
+            self.0.len()
+        );
+        let mut coords = self.0.clone();
+        if self.is_closed() && coords.len() > 1 {
+            coords.pop();
+            let len = coords.len();
+            coords.rotate_left(index % len);
+            let first = coords[0];
+            coords.push(first);
+        } else {
+            coords.rotate_left(index);
+        }
+        LineString(coords)
+    }
+
+    /// Remove the coordinate at `index` from the `LineString`, and return
+    /// it, unless that would leave too few coordinates.
+    ///
+    /// A closed `LineString` is kept closed and must keep at least 4
+    /// coordinates, as for a `Polygon` ring. Its first and last
+    /// coordinates are the same vertex, so removing either of them removes
+    /// both, and the next coordinate becomes the start and end of the
+    /// ring. A `LineString` which isn't closed must keep at least 2
+    /// coordinates.
+    ///
+    /// `None` is returned, and the `LineString` is left unchanged, if
+    /// `index` is out of bounds or the `LineString` is too short.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, Coordinate};
+    ///
+    /// let mut line_string = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 2., y: 0.),
+    /// ];
+    ///
+    /// assert_eq!(line_string.remove_point(1), Some(Coordinate { x: 1., y: 1. }));
+    /// assert_eq!(line_string, line_string![(x: 0., y: 0.), (x: 2., y: 0.)]);
+    /// // Another would leave a single coordinate
+    /// assert_eq!(line_string.remove_point(1), None);
+    /// ```
+    pub fn remove_point(&mut self, index: usize) -> Option<Coordinate<T>> {
+        let len = self.0.len();
+        if index >= len {
+            return None;
+        }
+        if self.is_closed() {
+            if len <= 4 {
+                return None;
+            }
+            if index == 0 || index == len - 1 {
+                let removed = self.0.remove(0);
+                self.0[len - 2] = self.0[0];
+                return Some(removed);
+            }
+        } else if len <= 2 {
+            return None;
+        }
+        Some(self.0.remove(index))
+    }
 }
 
 /// Turn a `Vec` of `Point`-like objects into a `LineString`.
@@ -378,7 +637,7 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for LineString<T> {
 #[cfg(feature = "rstar")]
 impl<T> ::rstar::RTreeObject for LineString<T>
 where
-    T: ::num_traits::Float + ::rstar::RTreeNum,
+    T: CoordNum + ::rstar::RTreeNum,
 {
     type Envelope = ::rstar::AABB<Point<T>>;
 
@@ -413,9 +672,24 @@ where
     }
 }
 
+const INVALID_RING_ERROR: &str =
+    "Failed to create ring: a ring must be closed, with at least 4 coordinates";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidRingError;
+
+impl std::error::Error for InvalidRingError {}
+
+impl std::fmt::Display for InvalidRingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", INVALID_RING_ERROR)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::line_string;
 
     use approx::AbsDiffEq;
 
@@ -474,4 +748,258 @@ mod test {
         let ls_oversized: LineString<f32> = coords_x.into_iter().collect();
         assert!(ls.relative_ne(&ls_oversized, 1., 1.));
     }
+
+    #[test]
+    fn test_reverse() {
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(ring.reverse().reverse(), ring);
+
+        // Twice the signed area, which is positive for counter-clockwise rings
+        let twice_signed_area =
+            |ring: &LineString<f64>| -> f64 { ring.lines().map(|line| line.determinant()).sum() };
+        assert_eq!(twice_signed_area(&ring), 2.);
+        assert_eq!(twice_signed_area(&ring.reverse()), -2.);
+
+        let mut reversed = ring.clone();
+        reversed.reverse_inplace();
+        assert_eq!(reversed, ring.reverse());
+        assert_eq!(reversed.0[1], Coordinate { x: 0., y: 1. });
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.reverse(), empty);
+    }
+
+    #[test]
+    fn test_equals_undirected() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 3.)];
+        assert!(line_string.equals_undirected(&line_string.clone()));
+        assert!(line_string.equals_undirected(&line_string.reverse()));
+        assert!(line_string.reverse().equals_undirected(&line_string));
+
+        // An extra vertex, even on the line
+        let extra = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 3.)];
+        assert!(!line_string.equals_undirected(&extra));
+        assert!(!extra.equals_undirected(&line_string));
+
+        // The same coordinates in another order
+        let shuffled = line_string![(x: 2., y: 0.), (x: 0., y: 0.), (x: 2., y: 3.)];
+        assert!(!line_string.equals_undirected(&shuffled));
+
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.equals_undirected(&line_string![]));
+        assert!(!empty.equals_undirected(&line_string));
+    }
+
+    #[test]
+    fn test_rotate_to_start() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let canonical_edges = |ring: &LineString<f64>| {
+            let mut edges: Vec<_> = ring
+                .lines()
+                .map(|line| (line.start.x_y(), line.end.x_y()))
+                .collect();
+            edges.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            edges
+        };
+
+        for index in 0..square.0.len() {
+            let rotated = square.rotate_to_start(index);
+            assert!(rotated.is_closed());
+            assert_eq!(rotated.0.len(), square.0.len());
+            assert_eq!(rotated.0[0], square.0[index]);
+            // The same directed edges, so the same polygon with the same
+            // winding order
+            assert_eq!(canonical_edges(&rotated), canonical_edges(&square));
+        }
+        assert_eq!(square.rotate_to_start(0), square);
+        assert_eq!(square.rotate_to_start(4), square);
+        assert_eq!(
+            square.rotate_to_start(3),
+            line_string![
+                (x: 0., y: 1.),
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+                (x: 1., y: 1.),
+                (x: 0., y: 1.),
+            ]
+        );
+
+        // Rings differing only in start point have a common rotation
+        let other = square.rotate_to_start(2);
+        assert_eq!(other.rotate_to_start(2), square);
+    }
+
+    #[test]
+    fn test_rotate_to_start_unclosed() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(
+            line_string.rotate_to_start(1),
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)]
+        );
+
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.rotate_to_start(0), single);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_to_start_out_of_bounds() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        line_string.rotate_to_start(3);
+    }
+
+    #[test]
+    fn test_remove_point() {
+        // An interior vertex
+        let mut line = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.), (x: 3., y: 1.)];
+        assert_eq!(line.remove_point(2), Some(Coordinate { x: 2., y: 0. }));
+        assert_eq!(
+            line,
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 1.)]
+        );
+
+        // The ends
+        assert_eq!(line.remove_point(2), Some(Coordinate { x: 3., y: 1. }));
+        assert_eq!(line, line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+        assert_eq!(line.remove_point(0), None);
+        assert_eq!(line.remove_point(1), None);
+        assert_eq!(line.remove_point(2), None);
+        assert_eq!(line, line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+
+        let mut empty: LineString<f64> = line_string![];
+        assert_eq!(empty.remove_point(0), None);
+    }
+
+    #[test]
+    fn test_remove_point_ring() {
+        let mut ring = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        // The start and end, which moves them on to the next vertex
+        assert_eq!(ring.remove_point(4), Some(Coordinate { x: 0., y: 0. }));
+        assert_eq!(
+            ring,
+            line_string![
+                (x: 1., y: 0.),
+                (x: 1., y: 1.),
+                (x: 0., y: 1.),
+                (x: 1., y: 0.),
+            ]
+        );
+        // A triangle can't lose any more
+        for index in 0..4 {
+            assert_eq!(ring.remove_point(index), None);
+        }
+        assert!(ring.is_closed());
+        assert_eq!(ring.0.len(), 4);
+
+        let mut ring = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(ring.remove_point(2), Some(Coordinate { x: 1., y: 1. }));
+        assert_eq!(
+            ring,
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.), (x: 0., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn test_rings() {
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(ring.is_closed());
+        assert!(ring.is_ring());
+        assert_eq!(ring.clone().try_into_ring(), Ok(ring));
+
+        let open = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert!(!open.is_closed());
+        assert!(!open.is_ring());
+        assert_eq!(open.try_into_ring(), Err(InvalidRingError));
+
+        let too_short = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert!(too_short.is_closed());
+        assert!(!too_short.is_ring());
+        assert_eq!(too_short.try_into_ring(), Err(InvalidRingError));
+
+        // Closed by definition, but not a ring
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.is_closed());
+        assert!(!empty.is_ring());
+    }
+
+    #[test]
+    fn test_signed_area_times_two() {
+        let ccw = line_string![
+            (x: 1., y: 1.),
+            (x: 5., y: 1.),
+            (x: 5., y: 4.),
+            (x: 1., y: 4.),
+            (x: 1., y: 1.),
+        ];
+        assert_eq!(ccw.signed_area_times_two(), 24.);
+        let cw = ccw.reverse();
+        assert_eq!(cw.signed_area_times_two(), -24.);
+
+        // A sliver, nearly a line there and back, but wound clockwise
+        let sliver =
+            line_string![(x: 0., y: 0.), (x: 1., y: 1e-12), (x: 2., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(sliver.signed_area_times_two(), -2e-12);
+        // Exactly, with integer coordinates
+        let sliver: LineString<i64> = vec![(0, 0), (1_000_000, 1), (2_000_001, 2), (0, 0)].into();
+        assert_eq!(sliver.signed_area_times_two(), -1);
+        assert_eq!(sliver.reverse().signed_area_times_two(), 1);
+        let collinear: LineString<i64> =
+            vec![(0, 0), (1_000_000, 1), (2_000_000, 2), (0, 0)].into();
+        assert_eq!(collinear.signed_area_times_two(), 0);
+
+        // Not a ring
+        let open = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.)];
+        assert_eq!(open.signed_area_times_two(), 0.);
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.signed_area_times_two(), 0.);
+    }
+
+    #[test]
+    fn test_lines() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 3.),
+            (x: 2., y: 7.),
+        ];
+        let lines: Vec<Line<f64>> = line_string.lines().collect();
+        assert_eq!(line_string.lines().len(), 3);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], Line::new((0., 0.), (5., 0.)));
+        assert_eq!(lines[2], Line::new((5., 3.), (2., 7.)));
+        // Each segment starts where the last one ended
+        for pair in lines.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+
+        // Too short to have any segments
+        let point: LineString<f64> = line_string![(x: 1., y: 1.)];
+        assert_eq!(point.lines().next(), None);
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.lines().len(), 0);
+    }
 }
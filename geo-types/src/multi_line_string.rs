@@ -34,6 +34,12 @@ pub struct MultiLineString<T>(pub Vec<LineString<T>>)
 where
     T: CoordNum;
 
+impl<T: CoordNum> Default for MultiLineString<T> {
+    fn default() -> Self {
+        MultiLineString(Vec::new())
+    }
+}
+
 impl<T: CoordNum> MultiLineString<T> {
     /// True if the MultiLineString is empty or if all of its LineStrings are closed - see
     /// [`LineString::is_closed`].
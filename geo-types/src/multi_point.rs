@@ -34,6 +34,12 @@ pub struct MultiPoint<T>(pub Vec<Point<T>>)
 where
     T: CoordNum;
 
+impl<T: CoordNum> Default for MultiPoint<T> {
+    fn default() -> Self {
+        MultiPoint(Vec::new())
+    }
+}
+
 impl<T: CoordNum, IP: Into<Point<T>>> From<IP> for MultiPoint<T> {
     /// Convert a single `Point` (or something which can be converted to a `Point`) into a
     /// one-member `MultiPoint`
@@ -28,6 +28,12 @@ pub struct MultiPolygon<T>(pub Vec<Polygon<T>>)
 where
     T: CoordNum;
 
+impl<T: CoordNum> Default for MultiPolygon<T> {
+    fn default() -> Self {
+        MultiPolygon(Vec::new())
+    }
+}
+
 impl<T: CoordNum, IP: Into<Polygon<T>>> From<IP> for MultiPolygon<T> {
     fn from(x: IP) -> Self {
         MultiPolygon(vec![x.into()])
@@ -0,0 +1,191 @@
+use crate::{CoordFloat, Coordinate, Point};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// A wrapper around a floating point `Coordinate`, which implements
+/// `Eq`, `Ord` and `Hash`, so that it may be used as the key of a
+/// `HashMap` or `BTreeMap`, or be sorted and deduplicated.
+///
+/// Floating point numbers are usually only partially ordered. Here, the
+/// `x` and `y` values are compared as follows:
+///
+/// - `-0.0` and `0.0` are equal, and hash the same.
+/// - All `NaN`s are equal to each other (regardless of their sign or
+///   payload), and ordered _after_ every other value, including
+///   infinity.
+/// - Otherwise, values compare as usual.
+///
+/// Coordinates are ordered lexicographically: first by `x`, then by `y`.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{OrderedCoord, Point};
+/// use std::collections::HashSet;
+///
+/// let points = vec![
+///     Point::new(0., 1.),
+///     Point::new(-0., 1.),
+///     Point::new(2., f64::NAN),
+///     Point::new(2., f64::NAN),
+/// ];
+///
+/// let unique: HashSet<OrderedCoord<f64>> = points.into_iter().map(OrderedCoord::from).collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedCoord<T: CoordFloat>(pub Coordinate<T>);
+
+impl<T: CoordFloat> OrderedCoord<T> {
+    /// The wrapped `Coordinate`.
+    pub fn coord(self) -> Coordinate<T> {
+        self.0
+    }
+}
+
+impl<T: CoordFloat> From<Coordinate<T>> for OrderedCoord<T> {
+    fn from(coord: Coordinate<T>) -> Self {
+        OrderedCoord(coord)
+    }
+}
+
+impl<T: CoordFloat> From<Point<T>> for OrderedCoord<T> {
+    fn from(point: Point<T>) -> Self {
+        OrderedCoord(point.0)
+    }
+}
+
+impl<T: CoordFloat> From<OrderedCoord<T>> for Coordinate<T> {
+    fn from(coord: OrderedCoord<T>) -> Self {
+        coord.0
+    }
+}
+
+impl<T: CoordFloat> From<OrderedCoord<T>> for Point<T> {
+    fn from(coord: OrderedCoord<T>) -> Self {
+        Point(coord.0)
+    }
+}
+
+/// Total order on floats, with all `NaN`s equal and greatest.
+fn total_cmp<T: CoordFloat>(a: T, b: T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        // Neither is NaN, and `-0.0 == 0.0` here
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+/// Hash a float consistently with `total_cmp`.
+fn hash_float<T: CoordFloat, H: Hasher>(value: T, state: &mut H) {
+    if value.is_nan() {
+        // All NaNs are equal
+        T::nan().integer_decode().hash(state)
+    } else if value == T::zero() {
+        // Both zeros are equal
+        T::zero().integer_decode().hash(state)
+    } else {
+        value.integer_decode().hash(state)
+    }
+}
+
+impl<T: CoordFloat> Ord for OrderedCoord<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        total_cmp(self.0.x, other.0.x).then_with(|| total_cmp(self.0.y, other.0.y))
+    }
+}
+
+impl<T: CoordFloat> PartialOrd for OrderedCoord<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CoordFloat> PartialEq for OrderedCoord<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: CoordFloat> Eq for OrderedCoord<T> {}
+
+impl<T: CoordFloat> Hash for OrderedCoord<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_float(self.0.x, state);
+        hash_float(self.0.y, state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    fn coord(x: f64, y: f64) -> OrderedCoord<f64> {
+        OrderedCoord(Coordinate { x, y })
+    }
+
+    #[test]
+    fn signed_zeros() {
+        assert_eq!(coord(0., -0.), coord(-0., 0.));
+        let set: HashSet<_> = vec![coord(0., 0.), coord(-0., 0.), coord(0., -0.)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn near_duplicates() {
+        let set: HashSet<_> = vec![
+            coord(1., 1.),
+            coord(1., 1.),
+            coord(1. + f64::EPSILON, 1.),
+            coord(1., 1. + f64::EPSILON),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn nans() {
+        let other_nan = f64::from_bits(f64::NAN.to_bits() ^ 1);
+        assert!(other_nan.is_nan());
+        assert_eq!(coord(f64::NAN, 1.), coord(-f64::NAN, 1.));
+        assert_eq!(coord(f64::NAN, 1.), coord(other_nan, 1.));
+        assert_ne!(coord(f64::NAN, 1.), coord(f64::NAN, 2.));
+
+        let set: HashSet<_> = vec![
+            coord(f64::NAN, 1.),
+            coord(-f64::NAN, 1.),
+            coord(other_nan, 1.),
+            coord(1., f64::NAN),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn ordering() {
+        let set: BTreeSet<_> = vec![
+            coord(f64::NAN, 0.),
+            coord(f64::INFINITY, 0.),
+            coord(0., f64::NAN),
+            coord(0., 1.),
+            coord(-1., 5.),
+            coord(-0., 1.),
+        ]
+        .into_iter()
+        .collect();
+        let ordered: Vec<_> = set.into_iter().map(|c| (c.0.x, c.0.y)).collect();
+        assert_eq!(ordered.len(), 5);
+        assert_eq!(ordered[0], (-1., 5.));
+        assert_eq!(ordered[1], (0., 1.));
+        assert!(ordered[2].0 == 0. && ordered[2].1.is_nan());
+        assert_eq!(ordered[3], (f64::INFINITY, 0.));
+        assert!(ordered[4].0.is_nan() && ordered[4].1 == 0.);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{CoordFloat, Coordinate, Geometry, LineString, OrderedCoord, Polygon};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// A wrapper around a floating point `Geometry`, which implements `Eq`,
+/// `Ord` and `Hash`, so that it may be used as the key of a `BTreeMap` or
+/// `HashMap`, or be sorted and deduplicated, with a deterministic order.
+///
+/// The order has no geometric meaning: it is not by position, size or
+/// containment, and a geometry is only equal to another with exactly the
+/// same structure and coordinates. Geometries are compared as follows:
+///
+/// - First by their variant, in the order they are declared in
+///   [`Geometry`]: `Point`, `Line`, `LineString`, `Polygon`,
+///   `MultiPoint`, `MultiLineString`, `MultiPolygon`,
+///   `GeometryCollection`, `Rect`, then `Triangle`.
+/// - Then by their coordinates, as for [`OrderedCoord`], so `-0.0` and
+///   `0.0` are equal and `NaN`s are equal to each other and greater than
+///   every other value. Sequences of coordinates, rings, or members are
+///   compared lexicographically, with a sequence ordered before any longer
+///   one which it starts. A `Polygon` is compared by its exterior, then by
+///   its interiors.
+///
+/// Any geometry can be wrapped once it has been converted into a
+/// `Geometry`.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, point, Geometry, OrderedGeometry};
+/// use std::collections::BTreeSet;
+///
+/// let geometries: Vec<Geometry<f64>> = vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+///     point!(x: 2., y: 0.).into(),
+///     point!(x: 1., y: 5.).into(),
+///     point!(x: -0., y: f64::NAN).into(),
+///     point!(x: 0., y: f64::NAN).into(),
+/// ];
+///
+/// let set: BTreeSet<OrderedGeometry<f64>> =
+///     geometries.into_iter().map(OrderedGeometry::from).collect();
+/// let ordered: Vec<Geometry<f64>> = set.into_iter().map(OrderedGeometry::geometry).collect();
+///
+/// assert_eq!(ordered.len(), 4);
+/// // The points, by x and then y, with the two NaN points as one
+/// assert_eq!(ordered[1], point!(x: 1., y: 5.).into());
+/// assert_eq!(ordered[2], point!(x: 2., y: 0.).into());
+/// assert_eq!(ordered[3], line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrderedGeometry<T: CoordFloat>(pub Geometry<T>);
+
+impl<T: CoordFloat> OrderedGeometry<T> {
+    /// The wrapped `Geometry`.
+    pub fn geometry(self) -> Geometry<T> {
+        self.0
+    }
+}
+
+impl<T: CoordFloat> From<Geometry<T>> for OrderedGeometry<T> {
+    fn from(geometry: Geometry<T>) -> Self {
+        OrderedGeometry(geometry)
+    }
+}
+
+impl<T: CoordFloat> From<OrderedGeometry<T>> for Geometry<T> {
+    fn from(geometry: OrderedGeometry<T>) -> Self {
+        geometry.0
+    }
+}
+
+/// The position of a geometry's variant in the declaration of `Geometry`.
+fn variant_index<T: CoordFloat>(geometry: &Geometry<T>) -> usize {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::Line(_) => 1,
+        Geometry::LineString(_) => 2,
+        Geometry::Polygon(_) => 3,
+        Geometry::MultiPoint(_) => 4,
+        Geometry::MultiLineString(_) => 5,
+        Geometry::MultiPolygon(_) => 6,
+        Geometry::GeometryCollection(_) => 7,
+        Geometry::Rect(_) => 8,
+        Geometry::Triangle(_) => 9,
+    }
+}
+
+/// Compare two slices lexicographically, by `cmp` on their elements.
+fn cmp_slices<A>(a: &[A], b: &[A], cmp: impl Fn(&A, &A) -> Ordering) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp(a, b))
+        .find(|&ordering| ordering != Ordering::Equal)
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn cmp_coords<T: CoordFloat>(a: &Coordinate<T>, b: &Coordinate<T>) -> Ordering {
+    OrderedCoord(*a).cmp(&OrderedCoord(*b))
+}
+
+fn cmp_line_strings<T: CoordFloat>(a: &LineString<T>, b: &LineString<T>) -> Ordering {
+    cmp_slices(&a.0, &b.0, cmp_coords)
+}
+
+fn cmp_polygons<T: CoordFloat>(a: &Polygon<T>, b: &Polygon<T>) -> Ordering {
+    cmp_line_strings(a.exterior(), b.exterior())
+        .then_with(|| cmp_slices(a.interiors(), b.interiors(), cmp_line_strings))
+}
+
+fn cmp_geometries<T: CoordFloat>(a: &Geometry<T>, b: &Geometry<T>) -> Ordering {
+    match (a, b) {
+        (Geometry::Point(a), Geometry::Point(b)) => cmp_coords(&a.0, &b.0),
+        (Geometry::Line(a), Geometry::Line(b)) => {
+            cmp_coords(&a.start, &b.start).then_with(|| cmp_coords(&a.end, &b.end))
+        }
+        (Geometry::LineString(a), Geometry::LineString(b)) => cmp_line_strings(a, b),
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => cmp_polygons(a, b),
+        (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => {
+            cmp_slices(&a.0, &b.0, |a, b| cmp_coords(&a.0, &b.0))
+        }
+        (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+            cmp_slices(&a.0, &b.0, cmp_line_strings)
+        }
+        (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => {
+            cmp_slices(&a.0, &b.0, cmp_polygons)
+        }
+        (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+            cmp_slices(&a.0, &b.0, cmp_geometries)
+        }
+        (Geometry::Rect(a), Geometry::Rect(b)) => {
+            cmp_coords(&a.min(), &b.min()).then_with(|| cmp_coords(&a.max(), &b.max()))
+        }
+        (Geometry::Triangle(a), Geometry::Triangle(b)) => {
+            cmp_slices(&[a.0, a.1, a.2], &[b.0, b.1, b.2], cmp_coords)
+        }
+        (a, b) => variant_index(a).cmp(&variant_index(b)),
+    }
+}
+
+fn hash_coords<T: CoordFloat, H: Hasher>(coords: &[Coordinate<T>], state: &mut H) {
+    coords.len().hash(state);
+    for coord in coords {
+        OrderedCoord(*coord).hash(state);
+    }
+}
+
+fn hash_polygon<T: CoordFloat, H: Hasher>(polygon: &Polygon<T>, state: &mut H) {
+    hash_coords(&polygon.exterior().0, state);
+    polygon.interiors().len().hash(state);
+    for interior in polygon.interiors() {
+        hash_coords(&interior.0, state);
+    }
+}
+
+/// Hash a geometry consistently with `cmp_geometries`, including the
+/// lengths of its sequences so that differently split parts don't collide.
+fn hash_geometry<T: CoordFloat, H: Hasher>(geometry: &Geometry<T>, state: &mut H) {
+    variant_index(geometry).hash(state);
+    match geometry {
+        Geometry::Point(point) => OrderedCoord(point.0).hash(state),
+        Geometry::Line(line) => hash_coords(&[line.start, line.end], state),
+        Geometry::LineString(line_string) => hash_coords(&line_string.0, state),
+        Geometry::Polygon(polygon) => hash_polygon(polygon, state),
+        Geometry::MultiPoint(multi_point) => {
+            multi_point.0.len().hash(state);
+            for point in &multi_point.0 {
+                OrderedCoord(point.0).hash(state);
+            }
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            multi_line_string.0.len().hash(state);
+            for line_string in &multi_line_string.0 {
+                hash_coords(&line_string.0, state);
+            }
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            multi_polygon.0.len().hash(state);
+            for polygon in &multi_polygon.0 {
+                hash_polygon(polygon, state);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            collection.0.len().hash(state);
+            for geometry in &collection.0 {
+                hash_geometry(geometry, state);
+            }
+        }
+        Geometry::Rect(rect) => hash_coords(&[rect.min(), rect.max()], state),
+        Geometry::Triangle(triangle) => hash_coords(&[triangle.0, triangle.1, triangle.2], state),
+    }
+}
+
+impl<T: CoordFloat> Ord for OrderedGeometry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_geometries(&self.0, &other.0)
+    }
+}
+
+impl<T: CoordFloat> PartialOrd for OrderedGeometry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CoordFloat> PartialEq for OrderedGeometry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: CoordFloat> Eq for OrderedGeometry<T> {}
+
+impl<T: CoordFloat> Hash for OrderedGeometry<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_geometry(&self.0, state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, GeometryCollection, Line, MultiLineString, MultiPoint, Rect,
+        Triangle,
+    };
+    use std::collections::{BTreeSet, HashSet};
+
+    fn ordered<G: Into<Geometry<f64>>>(geometry: G) -> OrderedGeometry<f64> {
+        OrderedGeometry(geometry.into())
+    }
+
+    #[test]
+    fn variants_first() {
+        let geometries = vec![
+            ordered(Triangle::from([(0., 0.), (1., 0.), (0., 1.)])),
+            ordered(Rect::new((0., 0.), (1., 1.))),
+            ordered(Geometry::GeometryCollection(GeometryCollection(vec![
+                point!(x: 0., y: 0.).into(),
+            ]))),
+            ordered(polygon![(x: -5., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)]),
+            ordered(MultiPoint(vec![point!(x: -9., y: 0.)])),
+            ordered(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            ordered(Line::new((5., 5.), (6., 6.))),
+            ordered(point!(x: 100., y: 100.)),
+        ];
+        let set: BTreeSet<_> = geometries.iter().cloned().collect();
+        let indices: Vec<_> = set
+            .iter()
+            .map(|geometry| variant_index(&geometry.0))
+            .collect();
+        assert_eq!(indices, vec![0, 1, 2, 3, 4, 7, 8, 9]);
+
+        // The same, whatever order they're inserted in
+        let reversed: BTreeSet<_> = geometries.into_iter().rev().collect();
+        assert!(set.iter().eq(reversed.iter()));
+    }
+
+    #[test]
+    fn coordinates_then_lengths() {
+        let set: BTreeSet<_> = vec![
+            ordered(line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)]),
+            ordered(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+            ordered(line_string![(x: 0., y: 0.), (x: 1., y: f64::NAN)]),
+            ordered(line_string![(x: -1., y: 5.)]),
+            ordered(line_string![(x: -0., y: 0.), (x: 1., y: 1.)]),
+        ]
+        .into_iter()
+        .collect();
+        let lengths: Vec<_> = set
+            .iter()
+            .map(|geometry| match &geometry.0 {
+                Geometry::LineString(line_string) => line_string.0.len(),
+                _ => unreachable!(),
+            })
+            .collect();
+        // Signed zeros are equal, and NaN is greatest
+        assert_eq!(lengths, vec![1, 2, 3, 2]);
+    }
+
+    #[test]
+    fn structure() {
+        // The same coordinates, split into parts differently
+        let one = ordered(MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+        ]));
+        let two = ordered(MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 3., y: 0.)],
+        ]));
+        assert_ne!(one, two);
+        assert!(one < two);
+
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let donut = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.)]],
+        );
+        assert!(ordered(square.clone()) < ordered(donut.clone()));
+
+        let set: HashSet<_> = vec![
+            one.clone(),
+            two,
+            one,
+            ordered(square.clone()),
+            ordered(donut),
+            ordered(square),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 4);
+    }
+}
@@ -480,7 +480,7 @@ where
 // These are required for rstar RTree
 impl<T> ::rstar::Point for Point<T>
 where
-    T: ::num_traits::Float + ::rstar::RTreeNum,
+    T: CoordNum + ::rstar::RTreeNum,
 {
     type Scalar = T;
 
@@ -136,6 +136,37 @@ where
         }
     }
 
+    /// Create a new `Polygon` with the provided exterior `LineString` ring and
+    /// interior `LineString` rings, without closing them.
+    ///
+    /// Unlike [`Polygon::new`], which closes any unclosed rings, this returns
+    /// an error if the first and last `Coordinate`s of any non-empty ring
+    /// differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{LineString, Polygon};
+    ///
+    /// let closed = LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)]);
+    /// assert!(Polygon::try_new(closed, vec![]).is_ok());
+    ///
+    /// let open = LineString::from(vec![(0., 0.), (1., 1.), (1., 0.)]);
+    /// assert!(Polygon::try_new(open, vec![]).is_err());
+    /// ```
+    pub fn try_new(
+        exterior: LineString<T>,
+        interiors: Vec<LineString<T>>,
+    ) -> Result<Polygon<T>, UnclosedRingError> {
+        if !exterior.is_closed() || interiors.iter().any(|interior| !interior.is_closed()) {
+            return Err(UnclosedRingError);
+        }
+        Ok(Polygon {
+            exterior,
+            interiors,
+        })
+    }
+
     /// Consume the `Polygon`, returning the exterior `LineString` ring and
     /// a vector of the interior `LineString` rings.
     ///
@@ -272,6 +303,28 @@ where
         &self.interiors
     }
 
+    /// An iterator over all of the rings of the polygon: the exterior
+    /// always first, followed by the interiors in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, polygon};
+    ///
+    /// let donut = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+    /// );
+    ///
+    /// let mut rings = donut.rings();
+    /// assert_eq!(rings.next(), Some(donut.exterior()));
+    /// assert_eq!(rings.next(), Some(&donut.interiors()[0]));
+    /// assert_eq!(rings.next(), None);
+    /// ```
+    pub fn rings(&self) -> impl Iterator<Item = &LineString<T>> {
+        std::iter::once(&self.exterior).chain(&self.interiors)
+    }
+
     /// Execute the provided closure `f`, which is provided with a mutable
     /// reference to the interior `LineString` rings.
     ///
@@ -387,6 +440,37 @@ where
         self.interiors.push(new_interior);
     }
 
+    /// Return each interior ring as the exterior of its own `Polygon`,
+    /// without any interiors, such as to measure the voids in a shape.
+    ///
+    /// The rings keep their coordinates, in the same order, so the winding
+    /// order of each new `Polygon` is that of its ring in `self`. Interiors
+    /// usually wind in the opposite direction to the exterior (clockwise,
+    /// if the exterior is counter-clockwise), so the new `Polygon`s may need
+    /// to be reoriented, for instance with `geo`'s `Orient` algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, polygon};
+    ///
+    /// let donut = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+    ///     interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     donut.holes_as_polygons(),
+    ///     vec![polygon![(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]]
+    /// );
+    /// ```
+    pub fn holes_as_polygons(&self) -> Vec<Polygon<T>> {
+        self.interiors
+            .iter()
+            .map(|interior| Polygon::new(interior.clone(), vec![]))
+            .collect()
+    }
+
     /// Wrap-around previous-vertex
     fn previous_vertex(&self, current_vertex: usize) -> usize
     where
@@ -466,3 +550,94 @@ impl<T: CoordNum> From<Triangle<T>> for Polygon<T> {
         Polygon::new(vec![t.0, t.1, t.2, t.0].into(), Vec::new())
     }
 }
+
+static UNCLOSED_RING_ERROR: &str =
+    "Failed to create Polygon: the first and last coordinates of each ring must be equal";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnclosedRingError;
+
+impl std::error::Error for UnclosedRingError {}
+
+impl std::fmt::Display for UnclosedRingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", UNCLOSED_RING_ERROR)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn new_closes_rings() {
+        let exterior = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.)]);
+        let interior = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.)]);
+        let polygon = Polygon::new(exterior, vec![interior]);
+        assert!(polygon.exterior().is_closed());
+        assert_eq!(polygon.exterior().0.len(), 5);
+        assert!(polygon.interiors()[0].is_closed());
+        assert_eq!(polygon.interiors()[0].0.len(), 4);
+    }
+
+    #[test]
+    fn try_new_rejects_unclosed_rings() {
+        let closed = LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)]);
+        let open = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.)]);
+
+        assert_eq!(
+            Polygon::try_new(open.clone(), vec![]),
+            Err(UnclosedRingError)
+        );
+        assert_eq!(
+            Polygon::try_new(closed.clone(), vec![open]),
+            Err(UnclosedRingError)
+        );
+
+        let polygon = Polygon::try_new(closed.clone(), vec![]).unwrap();
+        assert_eq!(polygon.exterior(), &closed);
+        // Empty rings are considered closed
+        assert!(Polygon::try_new(LineString::<f64>(vec![]), vec![]).is_ok());
+    }
+
+    #[test]
+    fn holes_as_polygons() {
+        let exterior = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)]);
+        let hole_a = LineString::from(vec![(1., 1.), (1., 3.), (3., 3.), (3., 1.), (1., 1.)]);
+        let hole_b = LineString::from(vec![(5., 5.), (5., 9.), (9., 9.), (5., 5.)]);
+
+        let donut = Polygon::new(exterior.clone(), vec![hole_a.clone()]);
+        assert_eq!(
+            donut.holes_as_polygons(),
+            vec![Polygon::new(hole_a.clone(), vec![])]
+        );
+
+        let polygon = Polygon::new(exterior.clone(), vec![hole_a.clone(), hole_b.clone()]);
+        let holes = polygon.holes_as_polygons();
+        assert_eq!(holes.len(), 2);
+        // In order, with their coordinates and winding unchanged
+        assert_eq!(holes[0].exterior(), &hole_a);
+        assert_eq!(holes[1].exterior(), &hole_b);
+        assert!(holes.iter().all(|hole| hole.interiors().is_empty()));
+
+        let solid = Polygon::new(exterior, vec![]);
+        assert!(solid.holes_as_polygons().is_empty());
+    }
+
+    #[test]
+    fn rings() {
+        let exterior = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)]);
+        let hole_a = LineString::from(vec![(1., 1.), (1., 2.), (2., 2.), (1., 1.)]);
+        let hole_b = LineString::from(vec![(5., 5.), (5., 6.), (6., 6.), (5., 5.)]);
+        let polygon = Polygon::new(exterior.clone(), vec![hole_a.clone(), hole_b.clone()]);
+
+        let rings: Vec<_> = polygon.rings().collect();
+        assert_eq!(rings.len(), 3);
+        assert_eq!(rings[0], polygon.exterior());
+        assert_eq!(rings[1], &hole_a);
+        assert_eq!(rings[2], &hole_b);
+
+        let solid = Polygon::new(exterior, vec![]);
+        assert_eq!(solid.rings().collect::<Vec<_>>(), vec![solid.exterior()]);
+    }
+}
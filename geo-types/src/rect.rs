@@ -15,7 +15,9 @@ use crate::{polygon, CoordFloat, CoordNum, Coordinate, Polygon};
 /// preserve the original shape. In other cases, the min,
 /// and max points are rotated or transformed, and a new
 /// rectangle is created (with coordinate swaps to ensure
-/// min < max).
+/// min < max). `Rotate`, on the other hand, rotates all
+/// four corners about the center, and returns their
+/// bounding rectangle.
 ///
 /// # Examples
 ///
@@ -81,6 +83,32 @@ impl<T: CoordNum> Rect<T> {
         }
     }
 
+    /// Creates a new rectangle from its minimum and maximum coordinates,
+    /// without comparing them.
+    ///
+    /// This skips the sorting done by [`Rect::new`], for when the
+    /// coordinates are already known to be in order, such as when building
+    /// many `Rect`s at once. The caller must ensure that `min.x <= max.x`
+    /// and `min.y <= max.y`: otherwise, other methods may give incorrect
+    /// results or panic. This is only checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::new_unchecked(
+    ///     Coordinate { x: 10., y: 10. },
+    ///     Coordinate { x: 30., y: 20. },
+    /// );
+    /// assert_eq!(rect, Rect::new((10., 10.), (30., 20.)));
+    /// ```
+    pub fn new_unchecked(min: Coordinate<T>, max: Coordinate<T>) -> Rect<T> {
+        let rect = Rect { min, max };
+        debug_assert!(rect.has_valid_bounds(), "{}", RECT_INVALID_BOUNDS_ERROR);
+        rect
+    }
+
     #[deprecated(
         since = "0.6.2",
         note = "Use `Rect::new` instead, since `Rect::try_new` will never Error"
@@ -223,6 +251,81 @@ impl<T: CoordNum> Rect<T> {
         ]
     }
 
+    /// Returns the area of the overlap between two `Rect`s, which is zero if
+    /// they are disjoint, or only touch along an edge or at a corner.
+    ///
+    /// This is the area of their intersection, without creating it.
+    ///
+    /// The area is the product of the width and height of the overlap, so
+    /// for integer coordinates, it can overflow if they are very large; it
+    /// is up to the caller to avoid that.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let a = Rect::new(Coordinate { x: 0, y: 0 }, Coordinate { x: 10, y: 10 });
+    /// let b = Rect::new(Coordinate { x: 5, y: 8 }, Coordinate { x: 20, y: 20 });
+    ///
+    /// assert_eq!(a.overlap_area(b), 10);
+    /// ```
+    pub fn overlap_area(self, other: Rect<T>) -> T {
+        let overlap = |min_a: T, max_a: T, min_b: T, max_b: T| {
+            let min = if min_a > min_b { min_a } else { min_b };
+            let max = if max_a < max_b { max_a } else { max_b };
+            // Compared first, so that unsigned coordinates can't underflow
+            if max > min {
+                max - min
+            } else {
+                T::zero()
+            }
+        };
+        overlap(self.min.x, self.max.x, other.min.x, other.max.x)
+            * overlap(self.min.y, self.max.y, other.min.y, other.max.y)
+    }
+
+    /// Returns the corner of the `Rect` at `start`, and the corner opposite
+    /// it, such as the start and end of the drag which made it.
+    ///
+    /// [`Rect::new`] sorts its corners into the minimum and maximum, so the
+    /// direction of a drag from `c1` to `c2` is lost. Given the original
+    /// `c1`, this recovers `(c1, c2)` again. Along an axis where `start` is
+    /// at neither the minimum nor the maximum, it is taken to be at the
+    /// minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// // Dragged up and to the left
+    /// let start = Coordinate { x: 30., y: 10. };
+    /// let end = Coordinate { x: 10., y: 20. };
+    /// let rect = Rect::new(start, end);
+    ///
+    /// assert_eq!(rect.min(), Coordinate { x: 10., y: 10. });
+    /// assert_eq!(rect.corners_in_drag_order(start), (start, end));
+    /// ```
+    pub fn corners_in_drag_order(self, start: Coordinate<T>) -> (Coordinate<T>, Coordinate<T>) {
+        let order = |start: T, min: T, max: T| {
+            if start == max {
+                (max, min)
+            } else {
+                (min, max)
+            }
+        };
+        let (start_x, end_x) = order(start.x, self.min.x, self.max.x);
+        let (start_y, end_y) = order(start.y, self.min.y, self.max.y);
+        (
+            Coordinate {
+                x: start_x,
+                y: start_y,
+            },
+            Coordinate { x: end_x, y: end_y },
+        )
+    }
+
     fn assert_valid_bounds(&self) {
         if !self.has_valid_bounds() {
             panic!(RECT_INVALID_BOUNDS_ERROR);
@@ -260,6 +363,113 @@ impl<T: CoordFloat> Rect<T> {
         )
             .into()
     }
+
+    /// Split the `Rect` into a grid of `nx` by `ny` equally sized tiles.
+    ///
+    /// The tiles are returned row by row, starting with the row with the
+    /// smallest `y` values, and each row is ordered by increasing `x`. So the
+    /// tile in column `i` and row `j` is at index `j * nx + i`.
+    ///
+    /// Adjacent tiles share their edges exactly, so that the tiles cover the
+    /// `Rect` without overlapping. If `nx` or `ny` is zero, no tiles are
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::new(
+    ///     Coordinate { x: 0., y: 0. },
+    ///     Coordinate { x: 10., y: 20. },
+    /// );
+    ///
+    /// let tiles = rect.tiles(2, 2);
+    /// assert_eq!(
+    ///     tiles,
+    ///     vec![
+    ///         Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 10. }),
+    ///         Rect::new(Coordinate { x: 5., y: 0. }, Coordinate { x: 10., y: 10. }),
+    ///         Rect::new(Coordinate { x: 0., y: 10. }, Coordinate { x: 5., y: 20. }),
+    ///         Rect::new(Coordinate { x: 5., y: 10. }, Coordinate { x: 10., y: 20. }),
+    ///     ]
+    /// );
+    /// ```
+    pub fn tiles(self, nx: usize, ny: usize) -> Vec<Rect<T>> {
+        if nx == 0 || ny == 0 {
+            return vec![];
+        }
+        // The last split is the maximum itself, so that the tiles reach it
+        // despite rounding
+        let splits = |min: T, max: T, count: usize| -> Vec<T> {
+            let step = (max - min) / T::from(count).unwrap();
+            (0..count)
+                .map(|idx| min + step * T::from(idx).unwrap())
+                .chain(std::iter::once(max))
+                .collect()
+        };
+        let xs = splits(self.min.x, self.max.x, nx);
+        let ys = splits(self.min.y, self.max.y, ny);
+
+        let mut tiles = Vec::with_capacity(nx * ny);
+        for y in ys.windows(2) {
+            for x in xs.windows(2) {
+                tiles.push(Rect {
+                    min: Coordinate { x: x[0], y: y[0] },
+                    max: Coordinate { x: x[1], y: y[1] },
+                });
+            }
+        }
+        tiles
+    }
+
+    /// Grow the `Rect` about its center until its width is `ratio` times its
+    /// height, such as to fit an extent into a viewport without cropping it.
+    ///
+    /// Only the shorter dimension grows, so the result always contains the
+    /// original `Rect`. If `ratio` isn't positive and finite, or the `Rect`
+    /// is a single point, it is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::new(
+    ///     Coordinate { x: 0., y: 0. },
+    ///     Coordinate { x: 10., y: 10. },
+    /// );
+    ///
+    /// assert_eq!(
+    ///     rect.fit_aspect(2.),
+    ///     Rect::new(
+    ///         Coordinate { x: -5., y: 0. },
+    ///         Coordinate { x: 15., y: 10. },
+    ///     )
+    /// );
+    /// ```
+    pub fn fit_aspect(self, ratio: T) -> Rect<T> {
+        let (width, height) = (self.width(), self.height());
+        if !(ratio > T::zero() && ratio.is_finite()) || (width == T::zero() && height == T::zero())
+        {
+            return self;
+        }
+        let two = T::one() + T::one();
+        let center = self.center();
+        let (mut min, mut max) = (self.min, self.max);
+        // Only the grown axis is recomputed, so the other keeps its exact
+        // bounds. The grown bounds never move inward of the originals.
+        if width < height * ratio {
+            let half = height * ratio / two;
+            min.x = (center.x - half).min(self.min.x);
+            max.x = (center.x + half).max(self.max.x);
+        } else {
+            let half = width / ratio / two;
+            min.y = (center.y - half).min(self.min.y);
+            max.y = (center.y + half).max(self.max.y);
+        }
+        Rect { min, max }
+    }
 }
 
 static RECT_INVALID_BOUNDS_ERROR: &str = "Failed to create Rect: 'min' coordinate's x/y value must be smaller or equal to the 'max' x/y value";
@@ -295,6 +505,30 @@ mod test {
         assert_eq!(rect.max, Coordinate { x: 20, y: 20 });
     }
 
+    #[test]
+    fn rect_new_unchecked() {
+        let rect = Rect::new_unchecked(Coordinate { x: 10, y: 10 }, Coordinate { x: 20, y: 20 });
+        assert_eq!(rect, Rect::new((20, 10), (10, 20)));
+        let point = Rect::new_unchecked(Coordinate { x: 1., y: 1. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(point.width(), 0.);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Failed to create Rect")]
+    fn rect_new_unchecked_invalid_debug() {
+        Rect::new_unchecked(Coordinate { x: 20, y: 10 }, Coordinate { x: 10, y: 20 });
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn rect_new_unchecked_invalid_release() {
+        // The coordinates are kept as they are given
+        let rect = Rect::new_unchecked(Coordinate { x: 20, y: 10 }, Coordinate { x: 10, y: 20 });
+        assert_eq!(rect.min(), Coordinate { x: 20, y: 10 });
+        assert_eq!(rect.max(), Coordinate { x: 10, y: 20 });
+    }
+
     #[test]
     fn rect_width() {
         let rect = Rect::new((10, 10), (20, 20));
@@ -307,6 +541,54 @@ mod test {
         assert_relative_eq!(rect.height(), 10.);
     }
 
+    #[test]
+    fn rect_overlap_area() {
+        let rect = Rect::new((0., 0.), (10., 10.));
+        assert_eq!(rect.overlap_area(rect), 100.);
+        assert_eq!(rect.overlap_area(Rect::new((5., -5.), (15., 5.))), 25.);
+        assert_eq!(Rect::new((5., -5.), (15., 5.)).overlap_area(rect), 25.);
+        // One inside the other
+        assert_eq!(rect.overlap_area(Rect::new((2., 3.), (4., 4.))), 2.);
+
+        // Touching along an edge, or at a corner
+        assert_eq!(rect.overlap_area(Rect::new((10., 0.), (20., 10.))), 0.);
+        assert_eq!(rect.overlap_area(Rect::new((10., 10.), (20., 20.))), 0.);
+
+        // Disjoint, including where only one of the axes overlaps
+        assert_eq!(rect.overlap_area(Rect::new((20., 20.), (30., 30.))), 0.);
+        assert_eq!(rect.overlap_area(Rect::new((5., 20.), (15., 30.))), 0.);
+
+        // Unsigned, which must not underflow
+        let rect: Rect<u32> = Rect::new((10, 10), (20, 20));
+        assert_eq!(rect.overlap_area(Rect::new((0, 0), (5, 5))), 0);
+        assert_eq!(rect.overlap_area(Rect::new((15, 0), (30, 12))), 10);
+    }
+
+    #[test]
+    fn rect_corners_in_drag_order() {
+        let (left, right) = (0, 10);
+        let (bottom, top) = (0, 5);
+        // Each diagonal direction
+        for &(start, end) in &[
+            ((left, bottom), (right, top)),
+            ((right, bottom), (left, top)),
+            ((left, top), (right, bottom)),
+            ((right, top), (left, bottom)),
+        ] {
+            let (start, end): (Coordinate<i32>, Coordinate<i32>) = (start.into(), end.into());
+            let rect = Rect::new(start, end);
+            assert_eq!(rect.min(), Coordinate { x: left, y: bottom });
+            assert_eq!(rect.corners_in_drag_order(start), (start, end));
+            assert_eq!(rect.corners_in_drag_order(end), (end, start));
+        }
+
+        // A drag along one axis only
+        let start = Coordinate { x: 4., y: 2. };
+        let end = Coordinate { x: 1., y: 2. };
+        let rect = Rect::new(start, end);
+        assert_eq!(rect.corners_in_drag_order(start), (start, end));
+    }
+
     #[test]
     fn rect_center() {
         assert_relative_eq!(
@@ -322,4 +604,90 @@ mod test {
             Coordinate::from((0., 0.))
         );
     }
+
+    fn assert_tiled(rect: Rect<f64>, tiles: &[Rect<f64>], nx: usize, ny: usize) {
+        assert_eq!(tiles.len(), nx * ny);
+        let area: f64 = tiles.iter().map(|tile| tile.width() * tile.height()).sum();
+        assert_relative_eq!(area, rect.width() * rect.height());
+        for (idx, tile) in tiles.iter().enumerate() {
+            let (i, j) = (idx % nx, idx / nx);
+            // Each tile meets its neighbours exactly, and the outer tiles
+            // reach the edges of the rect
+            if i == 0 {
+                assert_eq!(tile.min().x, rect.min().x);
+            } else {
+                assert_eq!(tile.min().x, tiles[idx - 1].max().x);
+            }
+            if j == 0 {
+                assert_eq!(tile.min().y, rect.min().y);
+            } else {
+                assert_eq!(tile.min().y, tiles[idx - nx].max().y);
+            }
+            if i == nx - 1 {
+                assert_eq!(tile.max().x, rect.max().x);
+            }
+            if j == ny - 1 {
+                assert_eq!(tile.max().y, rect.max().y);
+            }
+            assert!(tile.width() > 0. && tile.height() > 0.);
+        }
+    }
+
+    #[test]
+    fn rect_tiles() {
+        let rect = Rect::new((-1., 2.), (3., 4.));
+        let tiles = rect.tiles(2, 2);
+        assert_tiled(rect, &tiles, 2, 2);
+        assert_eq!(tiles[1], Rect::new((1., 2.), (3., 3.)));
+
+        let rect = Rect::new((0., 0.), (0.3, 1.));
+        let tiles = rect.tiles(1, 3);
+        assert_tiled(rect, &tiles, 1, 3);
+        assert_relative_eq!(tiles[2].min(), Coordinate::from((0., 2. / 3.)));
+
+        let tiles = rect.tiles(3, 1);
+        assert_tiled(rect, &tiles, 3, 1);
+
+        assert!(rect.tiles(0, 3).is_empty());
+        assert!(rect.tiles(3, 0).is_empty());
+    }
+
+    #[test]
+    fn rect_fit_aspect() {
+        let square = Rect::new((-2., 3.), (2., 7.));
+        let wide = square.fit_aspect(16. / 9.);
+        assert_relative_eq!(wide.width() / wide.height(), 16. / 9.);
+        assert_relative_eq!(wide.height(), 4.);
+        assert_eq!(wide.center(), square.center());
+
+        let tall = Rect::new((10., 0.), (12., 30.));
+        let fitted = tall.fit_aspect(1.);
+        assert_eq!(fitted, Rect::new((-4., 0.), (26., 30.)));
+        assert_eq!(fitted.center(), tall.center());
+
+        // Already the right shape
+        let rect = Rect::new((0., 0.), (4., 3.));
+        assert_eq!(rect.fit_aspect(4. / 3.), rect);
+
+        // A line grows into a box, but a point can't
+        let line = Rect::new((0., 0.), (10., 0.));
+        assert_eq!(line.fit_aspect(2.), Rect::new((0., -2.5), (10., 2.5)));
+        let point = Rect::new((1., 1.), (1., 1.));
+        assert_eq!(point.fit_aspect(2.), point);
+
+        for &ratio in &[0., -1., f64::NAN, f64::INFINITY] {
+            assert_eq!(tall.fit_aspect(ratio), tall);
+        }
+
+        // The unchanged axis keeps its exact bounds
+        let rect = Rect::new((0.1, 0.1), (0.3, 0.7));
+        let wide = rect.fit_aspect(3.);
+        assert_eq!((wide.min().y, wide.max().y), (0.1, 0.7));
+        let tall = rect.fit_aspect(0.1);
+        assert_eq!((tall.min().x, tall.max().x), (0.1, 0.3));
+        for fitted in &[wide, tall] {
+            assert!(fitted.min().x <= 0.1 && fitted.min().y <= 0.1);
+            assert!(fitted.max().x >= 0.3 && fitted.max().y >= 0.7);
+        }
+    }
 }
@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::contains::Contains;
+use geo::intersects::Intersects;
+use geo::prepared_geometry::PreparedGeometry;
+use geo::{Geometry, Line, LineString, Point, Polygon};
+
+fn candidates() -> Vec<Geometry<f64>> {
+    // Short lines and points scattered over the bounding rectangle of Louisiana
+    (0..1_000)
+        .map(|i| {
+            let x = -94.0 + 5.0 * ((i * 37) % 1_000) as f64 / 1_000.;
+            let y = 29.0 + 4.0 * ((i * 91) % 1_000) as f64 / 1_000.;
+            if i % 2 == 0 {
+                Geometry::Point(Point::new(x, y))
+            } else {
+                Geometry::Line(Line::new((x, y), (x + 0.05, y + 0.05)))
+            }
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let points = include!("../src/algorithm/test_fixtures/louisiana.rs");
+    let polygon = Polygon::new(LineString::<f64>::from(points), vec![]);
+    let geometry = Geometry::Polygon(polygon.clone());
+    let candidates = candidates();
+
+    c.bench_function("repeated intersects", |bencher| {
+        bencher.iter(|| {
+            for candidate in &candidates {
+                criterion::black_box(criterion::black_box(&geometry).intersects(candidate));
+            }
+        });
+    });
+
+    c.bench_function("prepared intersects", |bencher| {
+        bencher.iter(|| {
+            let prepared = PreparedGeometry::from(criterion::black_box(geometry.clone()));
+            for candidate in &candidates {
+                criterion::black_box(prepared.intersects(candidate));
+            }
+        });
+    });
+
+    c.bench_function("repeated contains point", |bencher| {
+        bencher.iter(|| {
+            for candidate in &candidates {
+                if let Geometry::Point(point) = candidate {
+                    criterion::black_box(criterion::black_box(&polygon).contains(point));
+                }
+            }
+        });
+    });
+
+    c.bench_function("prepared contains point", |bencher| {
+        bencher.iter(|| {
+            let prepared = PreparedGeometry::from(criterion::black_box(geometry.clone()));
+            for candidate in &candidates {
+                if let Geometry::Point(point) = candidate {
+                    criterion::black_box(prepared.contains(point));
+                }
+            }
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::intersects::Intersects;
+use geo::spatial_join::{spatial_join, Predicate};
+use geo::{Geometry, Rect};
+
+fn boxes(count: usize, offset: f64) -> Vec<Geometry<f64>> {
+    // Small boxes scattered over a 1000 by 1000 square
+    (0..count)
+        .map(|i| {
+            let x = offset + 1_000. * ((i * 37) % count) as f64 / count as f64;
+            let y = offset + 1_000. * ((i * 91) % count) as f64 / count as f64;
+            let size = 5. + (i % 7) as f64;
+            Rect::new((x, y), (x + size, y + size)).into()
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let lhs = boxes(3_000, 0.);
+    let rhs = boxes(3_000, 2.5);
+
+    c.bench_function("spatial join intersects", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(spatial_join(
+                criterion::black_box(&lhs),
+                criterion::black_box(&rhs),
+                Predicate::Intersects,
+            ));
+        });
+    });
+
+    c.bench_function("pairwise intersects", |bencher| {
+        bencher.iter(|| {
+            let mut count = 0;
+            for a in &lhs {
+                for b in &rhs {
+                    if criterion::black_box(a).intersects(b) {
+                        count += 1;
+                    }
+                }
+            }
+            criterion::black_box(count);
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -7,38 +7,7 @@ pub(crate) fn twice_signed_ring_area<T>(linestring: &LineString<T>) -> T
 where
     T: CoordNum,
 {
-    // LineString with less than 3 points is empty, or a
-    // single point, or is not closed.
-    if linestring.0.len() < 3 {
-        return T::zero();
-    }
-
-    // Above test ensures the vector has at least 2 elements.
-    // We check if linestring is closed, and return 0 otherwise.
-    if linestring.0.first().unwrap() != linestring.0.last().unwrap() {
-        return T::zero();
-    }
-
-    // Use a reasonable shift for the line-string coords
-    // to avoid numerical-errors when summing the
-    // determinants.
-    //
-    // Note: we can't use the `Centroid` trait as it
-    // requries `T: Float` and in fact computes area in the
-    // implementation. Another option is to use the average
-    // of the coordinates, but it is not fool-proof to
-    // divide by the length of the linestring (eg. a long
-    // line-string with T = u8)
-    let shift = linestring.0[0];
-
-    let mut tmp = T::zero();
-    for line in linestring.lines() {
-        use crate::algorithm::map_coords::MapCoords;
-        let line = line.map_coords(|&(x, y)| (x - shift.x, y - shift.y));
-        tmp = tmp + line.determinant();
-    }
-
-    tmp
+    linestring.signed_area_times_two()
 }
 
 /// Signed and unsigned planar area of a geometry.
@@ -286,9 +255,45 @@ where
     }
 }
 
+/// Add up the (unsigned) areas of the geometries of an iterator, such as
+/// the results of a query.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::TotalArea;
+/// use geo::{polygon, Polygon};
+///
+/// let polygons: Vec<Polygon<f64>> = vec![
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+///     polygon![(x: 5., y: 0.), (x: 8., y: 0.), (x: 8., y: 1.), (x: 5., y: 1.)],
+/// ];
+///
+/// assert_eq!(polygons.iter().total_area(), 7.);
+/// ```
+pub trait TotalArea<T>
+where
+    T: CoordNum,
+{
+    fn total_area(self) -> T;
+}
+
+impl<'a, T, G, I> TotalArea<T> for I
+where
+    T: CoordNum,
+    G: 'a + Area<T>,
+    I: IntoIterator<Item = &'a G>,
+{
+    fn total_area(self) -> T {
+        self.into_iter().fold(T::zero(), |total, geometry| {
+            total + geometry.unsigned_area()
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::algorithm::area::Area;
+    use crate::algorithm::area::{Area, TotalArea};
     use crate::{line_string, polygon, Coordinate, Line, MultiPolygon, Polygon, Rect, Triangle};
 
     // Area of the polygon
@@ -557,4 +562,33 @@ mod test {
         // Value from shapely
         assert_relative_eq!(poly.unsigned_area(), 0.006547948219252177, max_relative = 0.0001);
     }
+
+    #[test]
+    fn total_area_test() {
+        let polygons: Vec<Polygon<f64>> = (0..10)
+            .map(|idx| {
+                let size = idx as f64 / 3.;
+                polygon![(x: 0., y: 0.), (x: size, y: 0.), (x: size, y: 2.), (x: 0., y: 2.)]
+            })
+            .collect();
+        let folded = polygons
+            .iter()
+            .fold(0., |total, polygon| total + polygon.unsigned_area());
+        assert_eq!(polygons.iter().total_area(), folded);
+        assert_relative_eq!(polygons.iter().total_area(), 30.);
+
+        // Clockwise polygons count positively
+        let multi_polygon = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)],
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+        ]);
+        assert_eq!(multi_polygon.iter().total_area(), 2.);
+        assert_eq!(
+            [multi_polygon.clone(), multi_polygon].iter().total_area(),
+            4.
+        );
+
+        let empty: Vec<Rect<i32>> = vec![];
+        assert_eq!(empty.iter().total_area(), 0);
+    }
 }
@@ -0,0 +1,682 @@
+use crate::kernels::*;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, Point};
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::ops::Bound;
+use std::rc::Rc;
+
+/// Find every intersection among a set of segments, with the
+/// Bentley-Ottmann sweep line algorithm.
+///
+/// Each intersection is reported as the indices of the two segments, in
+/// ascending order, and the `Point` where they meet. The intersections are
+/// reported in the order the sweep line reaches them, from the least `x`
+/// to the greatest, and for the same `x`, from the least `y`.
+///
+/// Segments which touch, because they share an end point or one ends on
+/// the other, are reported once, at the point where they touch. Collinear
+/// segments which overlap are reported twice, at each end of the overlap,
+/// so segments which are the same are reported at both of their ends.
+/// Where several segments meet at a point, every pair of them is reported
+/// there. Segments with `NaN` or infinite coordinates are ignored.
+///
+/// The sweep line's status, the segments crossing it in order, is kept in
+/// a balanced tree, so finding `k` intersections among `n` segments takes
+/// `O((n + k) log n)` time, compared with `O(n²)` for checking every pair.
+/// It pays off when there are many segments but relatively few
+/// intersections, such as when noding the edges of polygons before an
+/// overlay. Crossings are computed in floating point, so nearly parallel
+/// segments, or several segments crossing at almost the same point, may be
+/// reported at slightly different points than their exact intersections.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::bentley_ottmann::bentley_ottmann;
+/// use geo::{point, Coordinate, Line};
+///
+/// let segments = vec![
+///     Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 2. }),
+///     Line::new(Coordinate { x: 0., y: 2. }, Coordinate { x: 2., y: 0. }),
+///     // Touching the first at its end
+///     Line::new(Coordinate { x: 2., y: 2. }, Coordinate { x: 3., y: 2. }),
+///     Line::new(Coordinate { x: 5., y: 0. }, Coordinate { x: 5., y: 5. }),
+/// ];
+///
+/// assert_eq!(
+///     bentley_ottmann(&segments),
+///     vec![(0, 1, point!(x: 1., y: 1.)), (0, 2, point!(x: 2., y: 2.))]
+/// );
+/// ```
+pub fn bentley_ottmann<T: GeoFloat>(segments: &[Line<T>]) -> Vec<(usize, usize, Point<T>)> {
+    let segments: Vec<Segment<T>> = segments
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            line.start.x.is_finite()
+                && line.start.y.is_finite()
+                && line.end.x.is_finite()
+                && line.end.y.is_finite()
+        })
+        .map(|(index, line)| Segment::new(index, line))
+        .collect();
+
+    let mut sweep = Sweep {
+        queue: BTreeMap::new(),
+        status: BTreeSet::new(),
+        inserted: vec![None; segments.len()],
+        intersections: vec![],
+        reported: HashSet::new(),
+        context: Rc::new(StatusContext {
+            segments,
+            point: Cell::new(Coordinate {
+                x: T::zero(),
+                y: T::zero(),
+            }),
+        }),
+    };
+    for (id, segment) in sweep.context.segments.iter().enumerate() {
+        sweep
+            .queue
+            .entry(EventPoint(segment.start))
+            .or_default()
+            .upper
+            .push(id);
+        // Found through the status, when the sweep line gets there
+        sweep.queue.entry(EventPoint(segment.end)).or_default();
+    }
+    while let Some(&point) = sweep.queue.keys().next() {
+        let event = sweep.queue.remove(&point).unwrap();
+        sweep.handle(point.0, event);
+    }
+    sweep.intersections
+}
+
+/// A segment, from its lexicographically lesser end to its greater one
+struct Segment<T: GeoFloat> {
+    /// The index of the segment in the input
+    index: usize,
+    start: Coordinate<T>,
+    end: Coordinate<T>,
+}
+
+impl<T: GeoFloat> Segment<T> {
+    fn new(index: usize, line: &Line<T>) -> Self {
+        let (start, end) = if lex_cmp(&line.start, &line.end) == Ordering::Greater {
+            (line.end, line.start)
+        } else {
+            (line.start, line.end)
+        };
+        Segment { index, start, end }
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// The `y` of the segment where the sweep line is at `point`. A vertical
+    /// segment is taken to be at `point` itself, as far as it extends.
+    fn y_at(&self, point: Coordinate<T>) -> T {
+        if self.is_vertical() {
+            point.y.max(self.start.y).min(self.end.y)
+        } else if point.x == self.start.x {
+            self.start.y
+        } else if point.x == self.end.x {
+            self.end.y
+        } else {
+            let delta = self.end - self.start;
+            self.start.y + delta.y * (point.x - self.start.x) / delta.x
+        }
+    }
+
+    /// The order of segments through a point, just after the sweep line
+    /// passes it
+    fn slope(&self) -> T {
+        if self.is_vertical() {
+            T::infinity()
+        } else {
+            (self.end.y - self.start.y) / (self.end.x - self.start.x)
+        }
+    }
+}
+
+/// A point where the sweep line stops, ordered lexicographically
+#[derive(Clone, Copy, PartialEq)]
+struct EventPoint<T: GeoFloat>(Coordinate<T>);
+
+impl<T: GeoFloat> Eq for EventPoint<T> {}
+
+impl<T: GeoFloat> PartialOrd for EventPoint<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for EventPoint<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        lex_cmp(&self.0, &other.0)
+    }
+}
+
+#[derive(Default)]
+struct Event {
+    /// The segments which start at the point
+    upper: Vec<usize>,
+    /// The segments found to cross each other at the point
+    crossing: Vec<usize>,
+}
+
+/// How far computed crossings may be from where the segments pass `point`,
+/// by rounding
+fn tolerance<T: GeoFloat>(point: Coordinate<T>) -> T {
+    T::epsilon() * T::from(64).unwrap() * (point.x.abs().max(point.y.abs()))
+}
+
+/// What the keys of the sweep line's status need to compare themselves
+struct StatusContext<T: GeoFloat> {
+    segments: Vec<Segment<T>>,
+    /// The point the sweep line is at
+    point: Cell<Coordinate<T>>,
+}
+
+/// A key in the sweep line's status, ordered from the least `y` to the
+/// greatest
+enum StatusKey<T: GeoFloat> {
+    /// A segment, and the point where it was inserted into the status
+    Segment {
+        id: usize,
+        inserted: Coordinate<T>,
+        context: Rc<StatusContext<T>>,
+    },
+    /// The position `y` along the sweep line at its current point, to
+    /// search the status with
+    Probe(T),
+}
+
+impl<T: GeoFloat> StatusKey<T> {
+    fn id(&self) -> usize {
+        match self {
+            StatusKey::Segment { id, .. } => *id,
+            StatusKey::Probe(_) => unreachable!("probes aren't stored in the status"),
+        }
+    }
+}
+
+impl<T: GeoFloat> PartialEq for StatusKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: GeoFloat> Eq for StatusKey<T> {}
+
+impl<T: GeoFloat> PartialOrd for StatusKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for StatusKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Segments only change places in the status when both are removed
+        // and inserted again, so two segments are compared where the later
+        // of them was inserted, as they were then. This doesn't depend on
+        // where the sweep line is now, so the order of the keys in the
+        // status stays consistent as it moves.
+        match (self, other) {
+            (
+                StatusKey::Segment {
+                    id: a,
+                    inserted: a_inserted,
+                    context,
+                },
+                StatusKey::Segment {
+                    id: b,
+                    inserted: b_inserted,
+                    ..
+                },
+            ) => {
+                let point = if lex_cmp(a_inserted, b_inserted) == Ordering::Greater {
+                    *a_inserted
+                } else {
+                    *b_inserted
+                };
+                let (s, t) = (&context.segments[*a], &context.segments[*b]);
+                // Those through the point, which the point is inserted
+                // between, are ordered as just after it
+                let snapped_y = |segment: &Segment<T>| {
+                    let y = segment.y_at(point);
+                    if (y - point.y).abs() <= tolerance(point) {
+                        point.y
+                    } else {
+                        y
+                    }
+                };
+                snapped_y(s)
+                    .partial_cmp(&snapped_y(t))
+                    .unwrap()
+                    .then_with(|| s.slope().partial_cmp(&t.slope()).unwrap())
+                    .then(a.cmp(b))
+            }
+            (StatusKey::Segment { id, context, .. }, StatusKey::Probe(y)) => context.segments[*id]
+                .y_at(context.point.get())
+                .partial_cmp(y)
+                .unwrap(),
+            (StatusKey::Probe(_), StatusKey::Segment { .. }) => other.cmp(self).reverse(),
+            (StatusKey::Probe(a), StatusKey::Probe(b)) => a.partial_cmp(b).unwrap(),
+        }
+    }
+}
+
+struct Sweep<T: GeoFloat> {
+    context: Rc<StatusContext<T>>,
+    queue: BTreeMap<EventPoint<T>, Event>,
+    /// The segments crossing the sweep line
+    status: BTreeSet<StatusKey<T>>,
+    /// Where each segment in the status was inserted into it
+    inserted: Vec<Option<Coordinate<T>>>,
+    intersections: Vec<(usize, usize, Point<T>)>,
+    /// The pairs of segments which aren't collinear, and have been reported
+    reported: HashSet<(usize, usize)>,
+}
+
+impl<T: GeoFloat> Sweep<T> {
+    fn key(&self, id: usize, inserted: Coordinate<T>) -> StatusKey<T> {
+        StatusKey::Segment {
+            id,
+            inserted,
+            context: Rc::clone(&self.context),
+        }
+    }
+
+    /// Remove a segment from the status, returning whether it was there
+    fn remove(&mut self, id: usize) -> bool {
+        match self.inserted[id].take() {
+            Some(inserted) => {
+                let key = self.key(id, inserted);
+                self.status.remove(&key)
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, id: usize, point: Coordinate<T>) {
+        self.inserted[id] = Some(point);
+        let key = self.key(id, point);
+        self.status.insert(key);
+    }
+
+    fn handle(&mut self, point: Coordinate<T>, event: Event) {
+        self.context.point.set(point);
+        let tolerance = tolerance(point);
+
+        // The segments in the status which pass through the point, which
+        // are next to each other
+        let mut through: Vec<usize> = self
+            .status
+            .range(StatusKey::Probe(point.y - tolerance)..=StatusKey::Probe(point.y + tolerance))
+            .map(StatusKey::id)
+            .collect();
+        for &s in &through {
+            self.remove(s);
+        }
+        // Crossings whose rounding took them just outside the range
+        for &s in &event.crossing {
+            if !through.contains(&s) && self.remove(s) {
+                through.push(s);
+            }
+        }
+
+        let mut involved: Vec<usize> = event.upper.iter().chain(&through).copied().collect();
+        let segments = &self.context.segments;
+        involved.sort_unstable_by_key(|&s| segments[s].index);
+        for (i, &a) in involved.iter().enumerate() {
+            for &b in &involved[i + 1..] {
+                if self.is_new_intersection(a, b, point) {
+                    let segments = &self.context.segments;
+                    let (a, b) = (segments[a].index, segments[b].index);
+                    self.intersections.push((a, b, point.into()));
+                }
+            }
+        }
+
+        // The segments which carry on past the point
+        let segments = &self.context.segments;
+        let continuing: Vec<usize> = event
+            .upper
+            .iter()
+            .chain(&through)
+            .copied()
+            .filter(|&s| segments[s].end != point)
+            .collect();
+        for &s in &continuing {
+            self.insert(s, point);
+        }
+
+        // The segments next to those inserted, or to the point if there
+        // are none
+        let (below, above) = if continuing.is_empty() {
+            let below = self.status.range(..StatusKey::Probe(point.y)).next_back();
+            let above = self.status.range(StatusKey::Probe(point.y)..).next();
+            match (below, above) {
+                (Some(below), Some(above)) => (Some((below.id(), above.id())), None),
+                _ => (None, None),
+            }
+        } else {
+            let keys: Vec<StatusKey<T>> = continuing.iter().map(|&s| self.key(s, point)).collect();
+            let (first, last) = (keys.iter().min().unwrap(), keys.iter().max().unwrap());
+            let below = self.status.range(..first).next_back();
+            let above = self
+                .status
+                .range((Bound::Excluded(last), Bound::Unbounded))
+                .next();
+            (
+                below.map(|key| (key.id(), first.id())),
+                above.map(|key| (last.id(), key.id())),
+            )
+        };
+        for &(a, b) in below.iter().chain(above.iter()) {
+            self.find_crossing(a, b, point);
+        }
+    }
+
+    /// Whether to report that segments `a` and `b` meet at `point`.
+    /// Collinear segments are only reported at the ends of their overlap,
+    /// and other segments only once, in case rounding finds them crossing
+    /// at two points very close together.
+    fn is_new_intersection(&mut self, a: usize, b: usize, point: Coordinate<T>) -> bool {
+        let (s, t) = (&self.context.segments[a], &self.context.segments[b]);
+        if T::Ker::orient2d(s.start, s.end, t.start) == Orientation::Collinear
+            && T::Ker::orient2d(s.start, s.end, t.end) == Orientation::Collinear
+        {
+            point == s.start || point == s.end || point == t.start || point == t.end
+        } else {
+            self.reported.insert((a.min(b), a.max(b)))
+        }
+    }
+
+    /// Queue the crossing of two segments which are next to each other in
+    /// the status, if it is ahead of the sweep line. Other intersections
+    /// are at the segments' ends, which are already queued.
+    fn find_crossing(&mut self, a: usize, b: usize, point: Coordinate<T>) {
+        let crossing = match proper_crossing(&self.context.segments[a], &self.context.segments[b]) {
+            Some(crossing) => crossing,
+            None => return,
+        };
+        if lex_cmp(&crossing, &point) != Ordering::Greater {
+            return;
+        }
+        let event = self.queue.entry(EventPoint(crossing)).or_default();
+        for &s in &[a, b] {
+            if !event.crossing.contains(&s) {
+                event.crossing.push(s);
+            }
+        }
+    }
+}
+
+/// The point where `a` and `b` cross, if they cross at a single point
+/// which is interior to both
+fn proper_crossing<T: GeoFloat>(a: &Segment<T>, b: &Segment<T>) -> Option<Coordinate<T>> {
+    let o1 = T::Ker::orient2d(a.start, a.end, b.start);
+    let o2 = T::Ker::orient2d(a.start, a.end, b.end);
+    let o3 = T::Ker::orient2d(b.start, b.end, a.start);
+    let o4 = T::Ker::orient2d(b.start, b.end, a.end);
+    if o1 == Orientation::Collinear
+        || o2 == Orientation::Collinear
+        || o3 == Orientation::Collinear
+        || o4 == Orientation::Collinear
+        || o1 == o2
+        || o3 == o4
+    {
+        return None;
+    }
+
+    // Exactly on vertical and horizontal segments, so that the crossing is
+    // where the sweep line finds them
+    let crossing = if a.is_vertical() {
+        Coordinate {
+            x: a.start.x,
+            y: b.y_at(a.start),
+        }
+    } else if b.is_vertical() {
+        Coordinate {
+            x: b.start.x,
+            y: a.y_at(b.start),
+        }
+    } else {
+        let (a_delta, b_delta) = (a.end - a.start, b.end - b.start);
+        let denom = a_delta.x * b_delta.y - a_delta.y * b_delta.x;
+        let offset = b.start - a.start;
+        let t = (offset.x * b_delta.y - offset.y * b_delta.x) / denom;
+        let crossing = a.start + a_delta * t;
+        if a.start.y == a.end.y {
+            Coordinate {
+                x: crossing.x,
+                y: a.start.y,
+            }
+        } else if b.start.y == b.end.y {
+            Coordinate {
+                x: crossing.x,
+                y: b.start.y,
+            }
+        } else {
+            crossing
+        }
+    };
+
+    // Within both segments' bounds, despite rounding
+    let (min_x, max_x) = (a.start.x.max(b.start.x), a.end.x.min(b.end.x));
+    let min_y = a.start.y.min(a.end.y).max(b.start.y.min(b.end.y));
+    let max_y = a.start.y.max(a.end.y).min(b.start.y.max(b.end.y));
+    Some(Coordinate {
+        x: crossing.x.max(min_x).min(max_x),
+        y: crossing.y.max(min_y).min(max_y),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::algorithm::intersects::Intersects;
+    use crate::point;
+    use rand::{Rng, SeedableRng};
+
+    fn line(start: (f64, f64), end: (f64, f64)) -> Line<f64> {
+        Line::new(Coordinate::from(start), Coordinate::from(end))
+    }
+
+    /// The pairs of segments which intersect, by checking every pair
+    fn brute_force(segments: &[Line<f64>]) -> Vec<(usize, usize)> {
+        let mut pairs = vec![];
+        for (i, a) in segments.iter().enumerate() {
+            for (j, b) in segments.iter().enumerate().skip(i + 1) {
+                if a.intersects(b) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn pairs(intersections: &[(usize, usize, Point<f64>)]) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<_> = intersections.iter().map(|&(i, j, _)| (i, j)).collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+    }
+
+    #[test]
+    fn random_segments() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        for &(count, length) in &[(10, 50.), (100, 20.), (500, 5.), (300, 100.)] {
+            let segments: Vec<Line<f64>> = (0..count)
+                .map(|_| {
+                    let start = (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0));
+                    let end = (
+                        start.0 + rng.gen_range(-length..length),
+                        start.1 + rng.gen_range(-length..length),
+                    );
+                    line(start, end)
+                })
+                .collect();
+            let intersections = bentley_ottmann(&segments);
+            // Random segments only cross, so each pair is reported once
+            assert_eq!(intersections.len(), pairs(&intersections).len());
+            assert_eq!(pairs(&intersections), brute_force(&segments));
+            for &(i, j, point) in &intersections {
+                assert!(point.euclidean_distance(&segments[i]) < 1e-9);
+                assert!(point.euclidean_distance(&segments[j]) < 1e-9);
+            }
+            // The sweep line's order
+            for pair in intersections.windows(2) {
+                assert_ne!(lex_cmp(&pair[0].2 .0, &pair[1].2 .0), Ordering::Greater);
+            }
+        }
+    }
+
+    #[test]
+    fn grid() {
+        // 10 horizontal segments, crossed by 10 vertical ones, with some of
+        // them in the opposite direction
+        let mut segments = vec![];
+        for i in 0..10 {
+            let y = i as f64;
+            segments.push(if i % 2 == 0 {
+                line((-1., y), (10., y))
+            } else {
+                line((10., y), (-1., y))
+            });
+        }
+        for j in 0..10 {
+            let x = j as f64 * 0.5;
+            segments.push(if j % 3 == 0 {
+                line((x, -1.), (x, 10.))
+            } else {
+                line((x, 10.), (x, -1.))
+            });
+        }
+        let intersections = bentley_ottmann(&segments);
+        assert_eq!(intersections.len(), 100);
+        let mut expected = vec![];
+        for j in 0..10 {
+            for i in 0..10 {
+                expected.push((i, 10 + j, point!(x: j as f64 * 0.5, y: i as f64)));
+            }
+        }
+        assert_eq!(intersections, expected);
+
+        // With diagonals through the crossings
+        segments.push(line((0., 0.), (4.5, 9.)));
+        segments.push(line((0., 9.), (4.5, 0.)));
+        let intersections = bentley_ottmann(&segments);
+        assert_eq!(pairs(&intersections), brute_force(&segments));
+        assert!(intersections.contains(&(0, 20, point!(x: 0., y: 0.))));
+        assert!(intersections.contains(&(4, 20, point!(x: 2., y: 4.))));
+        assert!(intersections.contains(&(14, 20, point!(x: 2., y: 4.))));
+    }
+
+    #[test]
+    fn many_in_status() {
+        // Staggered segments, all crossing the sweep line together, crossed
+        // by a vertical and a diagonal segment
+        let mut segments: Vec<Line<f64>> = (0..200)
+            .map(|i| {
+                let (x, y) = ((i * 37 % 200) as f64 * 0.01, i as f64);
+                line((x, y), (x + 10., y + 0.5))
+            })
+            .collect();
+        segments.push(line((5., -1.), (5., 201.)));
+        segments.push(line((2.5, -1.), (9.5, 201.)));
+        let intersections = bentley_ottmann(&segments);
+        assert_eq!(pairs(&intersections), brute_force(&segments));
+        assert_eq!(intersections.len(), 2 * 200 + 1);
+    }
+
+    #[test]
+    fn touching() {
+        // A star of segments from the origin, and a chain
+        let segments = vec![
+            line((0., 0.), (1., 0.)),
+            line((0., 1.), (0., 0.)),
+            line((0., 0.), (-1., -1.)),
+            line((1., 0.), (2., 1.)),
+            // Ending on the middle of another
+            line((0.5, 0.), (0.5, -1.)),
+        ];
+        let origin = point!(x: 0., y: 0.);
+        assert_eq!(
+            bentley_ottmann(&segments),
+            vec![
+                (0, 1, origin),
+                (0, 2, origin),
+                (1, 2, origin),
+                (0, 4, point!(x: 0.5, y: 0.)),
+                (0, 3, point!(x: 1., y: 0.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn collinear() {
+        let segments = vec![
+            line((0., 0.), (4., 0.)),
+            line((6., 0.), (2., 0.)),
+            // Inside the first
+            line((1., 0.), (3., 0.)),
+            // Along the same line, but apart
+            line((7., 0.), (8., 0.)),
+        ];
+        assert_eq!(
+            bentley_ottmann(&segments),
+            vec![
+                (0, 2, point!(x: 1., y: 0.)),
+                (0, 1, point!(x: 2., y: 0.)),
+                (1, 2, point!(x: 2., y: 0.)),
+                (0, 2, point!(x: 3., y: 0.)),
+                (1, 2, point!(x: 3., y: 0.)),
+                (0, 1, point!(x: 4., y: 0.)),
+            ]
+        );
+
+        // The same segment twice, and a vertical overlap
+        let segments = vec![
+            line((1., 1.), (2., 3.)),
+            line((2., 3.), (1., 1.)),
+            line((5., 0.), (5., 2.)),
+            line((5., 1.), (5., 3.)),
+        ];
+        assert_eq!(
+            bentley_ottmann(&segments),
+            vec![
+                (0, 1, point!(x: 1., y: 1.)),
+                (0, 1, point!(x: 2., y: 3.)),
+                (2, 3, point!(x: 5., y: 1.)),
+                (2, 3, point!(x: 5., y: 2.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(bentley_ottmann::<f64>(&[]), vec![]);
+        let segments = vec![
+            line((0., 0.), (2., 2.)),
+            // A point on the first
+            line((1., 1.), (1., 1.)),
+            line((f64::NAN, 0.), (2., 0.)),
+            line((0., 2.), (2., 0.)),
+        ];
+        assert_eq!(
+            bentley_ottmann(&segments),
+            vec![
+                (0, 1, point!(x: 1., y: 1.)),
+                (0, 3, point!(x: 1., y: 1.)),
+                (1, 3, point!(x: 1., y: 1.)),
+            ]
+        );
+    }
+}
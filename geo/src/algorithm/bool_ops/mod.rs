@@ -0,0 +1,116 @@
+use crate::{GeoFloat, MultiPolygon, Polygon};
+
+pub(crate) mod overlay;
+use overlay::{overlay, OpType};
+
+/// Boolean operations on areal geometries, constructing the
+/// point-set resulting from overlaying `self` and `rhs`.
+///
+/// Unlike predicates such as [`Intersects`] or [`Contains`],
+/// which only _describe_ how two geometries relate, these
+/// operations _construct_ the resulting geometry. The output
+/// is always a [`MultiPolygon`], which is empty if the result
+/// has no area. Exteriors of the output are oriented
+/// counter-clockwise, and interiors clockwise.
+///
+/// The inputs are expected to be valid (see
+/// [`Polygon`](crate::Polygon#validity)); the output is
+/// unspecified otherwise.
+///
+/// [`Intersects`]: crate::algorithm::intersects::Intersects
+/// [`Contains`]: crate::algorithm::contains::Contains
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::bool_ops::BooleanOps;
+/// use geo::polygon;
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+/// let shifted = polygon![
+///     (x: 2., y: 2.),
+///     (x: 6., y: 2.),
+///     (x: 6., y: 6.),
+///     (x: 2., y: 6.),
+/// ];
+///
+/// let intersection = square.intersection(&shifted);
+/// assert_eq!(intersection.0.len(), 1);
+/// assert_eq!(intersection.unsigned_area(), 4.);
+/// ```
+pub trait BooleanOps<Rhs = Self> {
+    type Scalar: GeoFloat;
+
+    /// The region covered by both `self` and `rhs`. This is
+    /// analogous to PostGIS' `ST_Intersection`.
+    ///
+    /// Disjoint inputs result in an empty `MultiPolygon`,
+    /// and if one input lies within the other, the result is
+    /// (a copy of) the inner one.
+    fn intersection(&self, rhs: &Rhs) -> MultiPolygon<Self::Scalar>;
+
+    /// The region covered by `self`, but not by `rhs`. This is
+    /// analogous to PostGIS' `ST_Difference`.
+    ///
+    /// If `rhs` lies within `self`, it is punched out as an
+    /// interior of the result. If `rhs` covers `self`, the result is
+    /// empty, and if the two are disjoint it is (a copy of) `self`.
+    fn difference(&self, rhs: &Rhs) -> MultiPolygon<Self::Scalar>;
+
+    /// The region covered by exactly one of `self` and `rhs`. This
+    /// is analogous to PostGIS' `ST_SymDifference`.
+    fn symmetric_difference(&self, rhs: &Rhs) -> MultiPolygon<Self::Scalar>;
+}
+
+impl<T: GeoFloat> BooleanOps for Polygon<T> {
+    type Scalar = T;
+
+    fn intersection(&self, rhs: &Self) -> MultiPolygon<T> {
+        overlay(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            OpType::Intersection,
+        )
+    }
+
+    fn difference(&self, rhs: &Self) -> MultiPolygon<T> {
+        overlay(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            OpType::Difference,
+        )
+    }
+
+    fn symmetric_difference(&self, rhs: &Self) -> MultiPolygon<T> {
+        overlay(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            OpType::SymmetricDifference,
+        )
+    }
+}
+
+impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn intersection(&self, rhs: &Self) -> MultiPolygon<T> {
+        overlay(&self.0, &rhs.0, OpType::Intersection)
+    }
+
+    fn difference(&self, rhs: &Self) -> MultiPolygon<T> {
+        overlay(&self.0, &rhs.0, OpType::Difference)
+    }
+
+    fn symmetric_difference(&self, rhs: &Self) -> MultiPolygon<T> {
+        overlay(&self.0, &rhs.0, OpType::SymmetricDifference)
+    }
+}
+
+#[cfg(test)]
+mod test;
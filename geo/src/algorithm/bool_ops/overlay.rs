@@ -0,0 +1,475 @@
+//! A simple edge-classification overlay engine used to implement
+//! [`BooleanOps`](super::BooleanOps).
+//!
+//! The operands are first reduced to their (consistently oriented) boundary
+//! edges. The edges of each operand are then _noded_ against the edges of
+//! the other, so that two edges only ever meet at their end points. Every
+//! noded edge is then classified as lying inside, outside, or on the
+//! boundary of the other operand, and the edges relevant to the requested
+//! operation are stitched back together into rings.
+//!
+//! The operands are expected to be valid polygons; in particular, the rings
+//! of a single operand must not cross each other.
+
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::algorithm::coordinate_position::{
+    coord_pos_relative_to_ring, CoordPos, CoordinatePosition,
+};
+use crate::algorithm::orient::{Direction, Orient};
+use crate::kernels::*;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Polygon};
+use std::cmp::Ordering;
+
+/// The overlay operations supported by [`overlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OpType {
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// The location of a noded edge relative to the other operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeLocation {
+    Inside,
+    Outside,
+    /// The edge is also an edge of the other operand, and is
+    /// oriented the same way (i.e. both interiors lie on the same side).
+    SharedSameDirection,
+    /// The edge is also an edge of the other operand, but is
+    /// oriented the opposite way (i.e. the interiors lie on either side).
+    SharedOppositeDirection,
+}
+
+/// Compute the overlay of `a` and `b` for the given operation.
+pub(crate) fn overlay<T: GeoFloat>(
+    a: &[Polygon<T>],
+    b: &[Polygon<T>],
+    op: OpType,
+) -> MultiPolygon<T> {
+    let mut a_edges = boundary_edges(a);
+    let mut b_edges = boundary_edges(b);
+    node_edges(&mut a_edges, &mut b_edges);
+
+    let b_poly = MultiPolygon(b.to_vec());
+    let a_poly = MultiPolygon(a.to_vec());
+    let a_locations = classify_edges(&a_edges, &b_edges, &b_poly);
+    let b_locations = classify_edges(&b_edges, &a_edges, &a_poly);
+
+    let mut selected = vec![];
+    for (edge, location) in a_edges.iter().zip(a_locations) {
+        if let Some(edge) = select_edge(op, Operand::A, location, edge) {
+            selected.push(edge);
+        }
+    }
+    for (edge, location) in b_edges.iter().zip(b_locations) {
+        if let Some(edge) = select_edge(op, Operand::B, location, edge) {
+            selected.push(edge);
+        }
+    }
+
+    build_polygons(assemble_rings(selected))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    A,
+    B,
+}
+
+/// Decide whether a noded edge bounds the result of `op`, returning it
+/// oriented so that the result lies on its left, or `None` if it should
+/// be discarded.
+///
+/// Edges shared by both operands are only ever taken from `A`, so that
+/// they are not selected twice.
+fn select_edge<T: GeoFloat>(
+    op: OpType,
+    operand: Operand,
+    location: EdgeLocation,
+    edge: &Line<T>,
+) -> Option<Line<T>> {
+    use EdgeLocation::*;
+    let keep = || Some(*edge);
+    let reverse = || Some(Line::new(edge.end, edge.start));
+    match (op, operand, location) {
+        (OpType::Intersection, _, Inside) => keep(),
+        (OpType::Intersection, Operand::A, SharedSameDirection) => keep(),
+        (OpType::Difference, Operand::A, Outside) => keep(),
+        (OpType::Difference, Operand::A, SharedOppositeDirection) => keep(),
+        (OpType::Difference, Operand::B, Inside) => reverse(),
+        (OpType::SymmetricDifference, _, Outside) => keep(),
+        (OpType::SymmetricDifference, _, Inside) => reverse(),
+        _ => None,
+    }
+}
+
+/// Collect the non-degenerate edges of all the rings of `polygons`, with
+/// exteriors oriented counter-clockwise and interiors clockwise.
+pub(crate) fn boundary_edges<T: GeoFloat>(polygons: &[Polygon<T>]) -> Vec<Line<T>> {
+    let mut edges = vec![];
+    for polygon in polygons {
+        let polygon = polygon.orient(Direction::Default);
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            edges.extend(ring.lines().filter(|line| line.start != line.end));
+        }
+    }
+    edges
+}
+
+#[inline]
+fn bounding_boxes_overlap<T: GeoFloat>(a: &Line<T>, b: &Line<T>) -> bool {
+    let (a_min_x, a_max_x) = min_max(a.start.x, a.end.x);
+    let (a_min_y, a_max_y) = min_max(a.start.y, a.end.y);
+    let (b_min_x, b_max_x) = min_max(b.start.x, b.end.x);
+    let (b_min_y, b_max_y) = min_max(b.start.y, b.end.y);
+    a_min_x <= b_max_x && b_min_x <= a_max_x && a_min_y <= b_max_y && b_min_y <= a_max_y
+}
+
+#[inline]
+fn min_max<T: GeoFloat>(a: T, b: T) -> (T, T) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Whether `coord` lies on `line`, but is not one of its end points.
+#[inline]
+fn in_line_interior<T: GeoFloat>(line: &Line<T>, coord: Coordinate<T>) -> bool {
+    use crate::algorithm::intersects::Intersects;
+    coord != line.start && coord != line.end && line.intersects(&coord)
+}
+
+/// Split the edges of `a` and `b` wherever they meet, so that afterwards
+/// an edge of `a` and an edge of `b` are either identical (possibly
+/// reversed), or meet at most at their end points.
+///
+/// Crossing points are computed only once per pair of edges, so that both
+/// edges are split at exactly the same coordinate.
+pub(crate) fn node_edges<T: GeoFloat>(a: &mut Vec<Line<T>>, b: &mut Vec<Line<T>>) {
+    let mut a_splits: Vec<Vec<Coordinate<T>>> = vec![vec![]; a.len()];
+    let mut b_splits: Vec<Vec<Coordinate<T>>> = vec![vec![]; b.len()];
+
+    for (i, a_edge) in a.iter().enumerate() {
+        for (j, b_edge) in b.iter().enumerate() {
+            node_pair(a_edge, b_edge, &mut a_splits[i], &mut b_splits[j]);
+        }
+    }
+
+    *a = split_edges(a, a_splits);
+    *b = split_edges(b, b_splits);
+}
+
+/// Split a single set of edges wherever they meet each other, so that
+/// afterwards any two edges are either identical (possibly reversed), or
+/// meet at most at their end points.
+///
+/// Edges are first split where they touch or overlap, which only adds
+/// existing end points, so that overlapping edges become identical. Only
+/// then are crossings computed, so that they are the same for each copy.
+pub(crate) fn self_node_edges<T: GeoFloat>(edges: &mut Vec<Line<T>>) {
+    for crossings in &[false, true] {
+        let mut splits: Vec<Vec<Coordinate<T>>> = vec![vec![]; edges.len()];
+        for j in 0..edges.len() {
+            let (before, after) = splits.split_at_mut(j);
+            for i in 0..j {
+                if !bounding_boxes_overlap(&edges[i], &edges[j]) {
+                    continue;
+                }
+                if *crossings {
+                    node_crossing(&edges[i], &edges[j], &mut before[i], &mut after[0]);
+                } else {
+                    node_touching(&edges[i], &edges[j], &mut before[i], &mut after[0]);
+                }
+            }
+        }
+        *edges = split_edges(edges, splits);
+    }
+}
+
+/// Record where `a_edge` and `b_edge` must be split for them to meet at
+/// most at their end points.
+fn node_pair<T: GeoFloat>(
+    a_edge: &Line<T>,
+    b_edge: &Line<T>,
+    a_splits: &mut Vec<Coordinate<T>>,
+    b_splits: &mut Vec<Coordinate<T>>,
+) {
+    if !bounding_boxes_overlap(a_edge, b_edge) {
+        return;
+    }
+    node_touching(a_edge, b_edge, a_splits, b_splits);
+    node_crossing(a_edge, b_edge, a_splits, b_splits);
+}
+
+/// Record where an end point of either edge touches the other, including
+/// where they overlap.
+fn node_touching<T: GeoFloat>(
+    a_edge: &Line<T>,
+    b_edge: &Line<T>,
+    a_splits: &mut Vec<Coordinate<T>>,
+    b_splits: &mut Vec<Coordinate<T>>,
+) {
+    for &coord in &[b_edge.start, b_edge.end] {
+        if in_line_interior(a_edge, coord) {
+            a_splits.push(coord);
+        }
+    }
+    for &coord in &[a_edge.start, a_edge.end] {
+        if in_line_interior(b_edge, coord) {
+            b_splits.push(coord);
+        }
+    }
+}
+
+/// Record where the edges cross properly.
+fn node_crossing<T: GeoFloat>(
+    a_edge: &Line<T>,
+    b_edge: &Line<T>,
+    a_splits: &mut Vec<Coordinate<T>>,
+    b_splits: &mut Vec<Coordinate<T>>,
+) {
+    if let Some(crossing) = proper_crossing(a_edge, b_edge) {
+        a_splits.push(crossing);
+        b_splits.push(crossing);
+    }
+}
+
+/// The intersection of `a` and `b` if they cross at a single point which
+/// is interior to both.
+///
+/// The crossing is computed from the edges in a canonical direction and
+/// order, so that the same pair of undirected edges is always split at
+/// exactly the same coordinate, however they are traversed.
+fn proper_crossing<T: GeoFloat>(a: &Line<T>, b: &Line<T>) -> Option<Coordinate<T>> {
+    let (a_key, b_key) = (edge_key(a), edge_key(b));
+    let (a_key, b_key) = if cmp_keys(&a_key, &b_key) == Ordering::Greater {
+        (b_key, a_key)
+    } else {
+        (a_key, b_key)
+    };
+    let a = &Line::new(a_key.0, a_key.1);
+    let b = &Line::new(b_key.0, b_key.1);
+    let o1 = T::Ker::orient2d(a.start, a.end, b.start);
+    let o2 = T::Ker::orient2d(a.start, a.end, b.end);
+    let o3 = T::Ker::orient2d(b.start, b.end, a.start);
+    let o4 = T::Ker::orient2d(b.start, b.end, a.end);
+    if o1 == Orientation::Collinear
+        || o2 == Orientation::Collinear
+        || o3 == Orientation::Collinear
+        || o4 == Orientation::Collinear
+        || o1 == o2
+        || o3 == o4
+    {
+        return None;
+    }
+
+    let a_delta = a.delta();
+    let b_delta = b.delta();
+    let denom = a_delta.x * b_delta.y - a_delta.y * b_delta.x;
+    let offset = b.start - a.start;
+    let t = (offset.x * b_delta.y - offset.y * b_delta.x) / denom;
+    let crossing = a.start + a_delta * t;
+
+    // A crossing computed in floating point may coincide with an end-point;
+    // these are already accounted for.
+    if crossing == a.start || crossing == a.end || crossing == b.start || crossing == b.end {
+        None
+    } else {
+        Some(crossing)
+    }
+}
+
+fn split_edges<T: GeoFloat>(edges: &[Line<T>], splits: Vec<Vec<Coordinate<T>>>) -> Vec<Line<T>> {
+    let mut result = Vec::with_capacity(edges.len());
+    for (edge, mut points) in edges.iter().zip(splits) {
+        if points.is_empty() {
+            result.push(*edge);
+            continue;
+        }
+        let squared_distance = |c: &Coordinate<T>| {
+            let d = *c - edge.start;
+            d.x * d.x + d.y * d.y
+        };
+        points.sort_by(|p, q| {
+            squared_distance(p)
+                .partial_cmp(&squared_distance(q))
+                .unwrap()
+        });
+        points.dedup();
+
+        let mut start = edge.start;
+        for point in points {
+            result.push(Line::new(start, point));
+            start = point;
+        }
+        result.push(Line::new(start, edge.end));
+    }
+    result
+}
+
+/// Undirected key of an edge, used to detect identical edges.
+pub(crate) fn edge_key<T: GeoFloat>(edge: &Line<T>) -> (Coordinate<T>, Coordinate<T>) {
+    if lex_cmp(&edge.start, &edge.end) == Ordering::Greater {
+        (edge.end, edge.start)
+    } else {
+        (edge.start, edge.end)
+    }
+}
+
+fn cmp_keys<T: GeoFloat>(
+    a: &(Coordinate<T>, Coordinate<T>),
+    b: &(Coordinate<T>, Coordinate<T>),
+) -> Ordering {
+    lex_cmp(&a.0, &b.0).then_with(|| lex_cmp(&a.1, &b.1))
+}
+
+fn classify_edges<T: GeoFloat>(
+    edges: &[Line<T>],
+    other_edges: &[Line<T>],
+    other: &MultiPolygon<T>,
+) -> Vec<EdgeLocation> {
+    let mut other_keys: Vec<_> = other_edges
+        .iter()
+        .map(|edge| (edge_key(edge), edge.start))
+        .collect();
+    other_keys.sort_by(|a, b| cmp_keys(&a.0, &b.0));
+
+    let two = T::one() + T::one();
+    edges
+        .iter()
+        .map(|edge| {
+            let key = edge_key(edge);
+            if let Ok(idx) = other_keys.binary_search_by(|probe| cmp_keys(&probe.0, &key)) {
+                return if other_keys[idx].1 == edge.start {
+                    EdgeLocation::SharedSameDirection
+                } else {
+                    EdgeLocation::SharedOppositeDirection
+                };
+            }
+            let midpoint = (edge.start + edge.end) / two;
+            match other.coordinate_position(&midpoint) {
+                CoordPos::Inside => EdgeLocation::Inside,
+                // An edge touching the boundary without being shared can
+                // only arise from numerical noise, and is treated as
+                // lying outside.
+                CoordPos::OnBoundary | CoordPos::Outside => EdgeLocation::Outside,
+            }
+        })
+        .collect()
+}
+
+/// Stitch directed edges into closed rings. Wherever there is a choice
+/// of outgoing edge, the one making the sharpest left turn is picked, so
+/// that each ring traces the smallest face on its left.
+pub(crate) fn assemble_rings<T: GeoFloat>(edges: Vec<Line<T>>) -> Vec<LineString<T>> {
+    let mut by_start: Vec<usize> = (0..edges.len()).collect();
+    by_start.sort_by(|&i, &j| lex_cmp(&edges[i].start, &edges[j].start));
+    let outgoing = |coord: Coordinate<T>| {
+        let lo = by_start.partition_point(|&i| lex_cmp(&edges[i].start, &coord) == Ordering::Less);
+        let hi =
+            by_start.partition_point(|&i| lex_cmp(&edges[i].start, &coord) != Ordering::Greater);
+        &by_start[lo..hi]
+    };
+
+    let two_pi = T::from(2.0 * std::f64::consts::PI).unwrap();
+    let clockwise_angle = |from: Coordinate<T>, to: Coordinate<T>| {
+        let angle = from.y.atan2(from.x) - to.y.atan2(to.x);
+        if angle <= T::zero() {
+            angle + two_pi
+        } else {
+            angle
+        }
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let ring_start = edges[first].start;
+        let mut coords = vec![ring_start];
+        let mut current = edges[first];
+        loop {
+            coords.push(current.end);
+            if current.end == ring_start {
+                rings.push(LineString(coords));
+                break;
+            }
+            let back = current.start - current.end;
+            let next = outgoing(current.end)
+                .iter()
+                .copied()
+                .filter(|&i| !used[i])
+                .min_by(|&i, &j| {
+                    let angle_i = clockwise_angle(back, edges[i].delta());
+                    let angle_j = clockwise_angle(back, edges[j].delta());
+                    angle_i.partial_cmp(&angle_j).unwrap()
+                });
+            match next {
+                Some(next) => {
+                    used[next] = true;
+                    current = edges[next];
+                }
+                // A dangling chain; only possible with invalid input.
+                None => break,
+            }
+        }
+    }
+    rings
+}
+
+/// Turn counter-clockwise rings into polygon exteriors, and assign each
+/// clockwise ring as an interior of the smallest exterior containing it.
+pub(crate) fn build_polygons<T: GeoFloat>(rings: Vec<LineString<T>>) -> MultiPolygon<T> {
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for ring in rings {
+        let area = twice_signed_ring_area(&ring);
+        if area > T::zero() {
+            shells.push((area, ring, vec![]));
+        } else if area < T::zero() {
+            holes.push(ring);
+        }
+    }
+
+    for hole in holes {
+        let best = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, shell, _))| ring_within(&hole, shell))
+            .min_by(|(_, (a, _, _)), (_, (b, _, _))| a.partial_cmp(b).unwrap())
+            .map(|(idx, _)| idx);
+        if let Some(idx) = best {
+            shells[idx].2.push(hole);
+        }
+    }
+
+    shells
+        .into_iter()
+        .map(|(_, exterior, interiors)| Polygon::new(exterior, interiors))
+        .collect()
+}
+
+/// Whether the ring `inner`, which must not cross `outer`, lies within it.
+fn ring_within<T: GeoFloat>(inner: &LineString<T>, outer: &LineString<T>) -> bool {
+    let two = T::one() + T::one();
+    let candidates = inner
+        .0
+        .iter()
+        .copied()
+        .chain(inner.lines().map(|line| (line.start + line.end) / two));
+    for coord in candidates {
+        match coord_pos_relative_to_ring(coord, outer) {
+            CoordPos::Inside => return true,
+            CoordPos::Outside => return false,
+            CoordPos::OnBoundary => {}
+        }
+    }
+    false
+}
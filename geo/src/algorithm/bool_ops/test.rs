@@ -0,0 +1,218 @@
+use super::BooleanOps;
+use crate::algorithm::area::Area;
+use crate::algorithm::winding_order::Winding;
+use crate::{polygon, MultiPolygon, Polygon};
+
+fn square(min: f64, max: f64) -> Polygon<f64> {
+    polygon![
+        (x: min, y: min),
+        (x: max, y: min),
+        (x: max, y: max),
+        (x: min, y: max),
+    ]
+}
+
+fn assert_no_larger_than_inputs(result: &MultiPolygon<f64>, a: &Polygon<f64>, b: &Polygon<f64>) {
+    assert!(result.unsigned_area() <= a.unsigned_area());
+    assert!(result.unsigned_area() <= b.unsigned_area());
+}
+
+#[test]
+fn intersection_disjoint() {
+    let a = square(0., 1.);
+    let b = square(2., 3.);
+    let result = a.intersection(&b);
+    assert!(result.0.is_empty());
+    assert!(b.intersection(&a).0.is_empty());
+}
+
+#[test]
+fn intersection_nested() {
+    let outer = square(0., 10.);
+    let inner = square(2., 4.);
+
+    let result = outer.intersection(&inner);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 4.);
+    assert_no_larger_than_inputs(&result, &outer, &inner);
+
+    let result = inner.intersection(&outer);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 4.);
+}
+
+#[test]
+fn intersection_partial_overlap() {
+    let a = square(0., 4.);
+    let b = square(2., 6.);
+    let result = a.intersection(&b);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 4.);
+    assert_no_larger_than_inputs(&result, &a, &b);
+    assert!(result.0[0].exterior().is_ccw());
+}
+
+#[test]
+fn intersection_crossing_into_two_parts() {
+    // A U-shape intersected with a horizontal bar yields two disjoint parts
+    let u_shape = polygon![
+        (x: 0., y: 0.),
+        (x: 3., y: 0.),
+        (x: 3., y: 3.),
+        (x: 2., y: 3.),
+        (x: 2., y: 1.),
+        (x: 1., y: 1.),
+        (x: 1., y: 3.),
+        (x: 0., y: 3.),
+    ];
+    let bar = polygon![
+        (x: -1., y: 2.),
+        (x: 4., y: 2.),
+        (x: 4., y: 2.5),
+        (x: -1., y: 2.5),
+    ];
+    let result = u_shape.intersection(&bar);
+    assert_eq!(result.0.len(), 2);
+    assert_relative_eq!(result.unsigned_area(), 1.);
+    assert_no_larger_than_inputs(&result, &u_shape, &bar);
+}
+
+#[test]
+fn intersection_shared_edges() {
+    let a = square(0., 2.);
+    let b = polygon![
+        (x: 0., y: 0.),
+        (x: 1., y: 0.),
+        (x: 1., y: 2.),
+        (x: 0., y: 2.),
+    ];
+    let result = a.intersection(&b);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 2.);
+
+    // Polygons sharing an edge, but no area
+    let c = polygon![
+        (x: 2., y: 0.),
+        (x: 3., y: 0.),
+        (x: 3., y: 2.),
+        (x: 2., y: 2.),
+    ];
+    assert!(a.intersection(&c).0.is_empty());
+}
+
+#[test]
+fn intersection_with_hole() {
+    let donut = polygon![
+        exterior: [
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ],
+        interiors: [
+            [
+                (x: 4., y: 4.),
+                (x: 6., y: 4.),
+                (x: 6., y: 6.),
+                (x: 4., y: 6.),
+            ],
+        ],
+    ];
+
+    // covers the hole entirely
+    let b = square(3., 7.);
+    let result = donut.intersection(&b);
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].interiors().len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 12.);
+    assert!(result.0[0].interiors()[0].is_cw());
+
+    // lies within the hole
+    let c = square(4.5, 5.5);
+    assert!(donut.intersection(&c).0.is_empty());
+
+    // straddles the boundary of the hole
+    let d = square(5., 8.);
+    let result = donut.intersection(&d);
+    assert_eq!(result.0.len(), 1);
+    assert!(result.0[0].interiors().is_empty());
+    assert_relative_eq!(result.unsigned_area(), 8.);
+    assert_no_larger_than_inputs(&result, &donut, &d);
+}
+
+#[test]
+fn intersection_multi_polygon() {
+    let a = MultiPolygon(vec![square(0., 2.), square(4., 6.)]);
+    let b = MultiPolygon(vec![square(1., 5.)]);
+    let result = a.intersection(&b);
+    assert_eq!(result.0.len(), 2);
+    assert_relative_eq!(result.unsigned_area(), 2.);
+}
+
+#[test]
+fn difference_punches_hole() {
+    let outer = square(0., 10.);
+    let inner = square(2., 4.);
+    let result = outer.difference(&inner);
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].interiors().len(), 1);
+    assert!(result.0[0].interiors()[0].is_cw());
+    assert_relative_eq!(result.unsigned_area(), 96.);
+}
+
+#[test]
+fn difference_full_coverage() {
+    let inner = square(2., 4.);
+    let outer = square(0., 10.);
+    assert!(inner.difference(&outer).0.is_empty());
+    assert!(inner.difference(&inner).0.is_empty());
+}
+
+#[test]
+fn difference_disjoint() {
+    let a = square(0., 1.);
+    let b = square(2., 3.);
+    let result = a.difference(&b);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), a.unsigned_area());
+    assert_eq!(result.0[0].exterior(), a.exterior());
+}
+
+#[test]
+fn difference_partial_overlap() {
+    let a = square(0., 4.);
+    let b = square(2., 6.);
+    let result = a.difference(&b);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 12.);
+
+    // Adjacent polygons sharing an edge
+    let c = polygon![
+        (x: 4., y: 0.),
+        (x: 5., y: 0.),
+        (x: 5., y: 4.),
+        (x: 4., y: 4.),
+    ];
+    let result = a.difference(&c);
+    assert_eq!(result.0.len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 16.);
+}
+
+#[test]
+fn symmetric_difference() {
+    let a = square(0., 4.);
+    let b = square(2., 6.);
+    let result = a.symmetric_difference(&b);
+    assert_eq!(result.0.len(), 2);
+    assert_relative_eq!(result.unsigned_area(), 24.);
+    assert_relative_eq!(b.symmetric_difference(&a).unsigned_area(), 24.);
+
+    let outer = square(0., 10.);
+    let inner = square(2., 4.);
+    let result = outer.symmetric_difference(&inner);
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].interiors().len(), 1);
+    assert_relative_eq!(result.unsigned_area(), 96.);
+
+    assert!(a.symmetric_difference(&a).0.is_empty());
+}
@@ -0,0 +1,231 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{Coordinate, GeoFloat, Point};
+
+/// Calculate the minimum bounding circle of a geometry: the smallest circle
+/// containing all of its coordinates, as its centre and radius.
+///
+/// The circle is found with Welzl's algorithm, in expected linear time. It
+/// touches either two of the coordinates, at opposite ends of a diameter,
+/// or three of them.
+///
+/// `None` is returned for an empty geometry, and the circle around a single
+/// coordinate has a radius of zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::bounding_circle::BoundingCircle;
+/// use geo::{line_string, point};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 1.),
+///     (x: 4., y: 0.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.bounding_circle(),
+///     Some((point!(x: 2., y: 0.), 2.))
+/// );
+/// ```
+pub trait BoundingCircle<T: GeoFloat> {
+    fn bounding_circle(&self) -> Option<(Point<T>, T)>;
+}
+
+impl<T, G> BoundingCircle<T> for G
+where
+    T: GeoFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> {
+        let mut coords: Vec<Coordinate<T>> = self.coords_iter().collect();
+        if coords.is_empty() {
+            return None;
+        }
+        shuffle(&mut coords);
+
+        let mut circle = Circle::new(coords[0], T::zero());
+        for i in 1..coords.len() {
+            if circle.contains(coords[i]) {
+                continue;
+            }
+            // The circle around the first i + 1 coordinates touches the i-th
+            circle = Circle::new(coords[i], T::zero());
+            for j in 0..i {
+                if circle.contains(coords[j]) {
+                    continue;
+                }
+                // And also the j-th
+                circle = Circle::diameter(coords[i], coords[j]);
+                for k in 0..j {
+                    if !circle.contains(coords[k]) {
+                        circle = Circle::circumcircle(coords[i], coords[j], coords[k]);
+                    }
+                }
+            }
+        }
+        Some((circle.center.into(), circle.radius))
+    }
+}
+
+struct Circle<T: GeoFloat> {
+    center: Coordinate<T>,
+    radius: T,
+}
+
+impl<T: GeoFloat> Circle<T> {
+    fn new(center: Coordinate<T>, radius: T) -> Self {
+        Circle { center, radius }
+    }
+
+    fn diameter(a: Coordinate<T>, b: Coordinate<T>) -> Self {
+        let two = T::one() + T::one();
+        let center = Coordinate {
+            x: (a.x + b.x) / two,
+            y: (a.y + b.y) / two,
+        };
+        Circle::new(center, distance(a, b) / two)
+    }
+
+    fn circumcircle(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> Self {
+        let (ab, ac) = (b - a, c - a);
+        let d = (ab.x * ac.y - ab.y * ac.x) * (T::one() + T::one());
+        if d == T::zero() {
+            // Collinear, so the circle is the one around the farthest pair
+            let pairs = [(b, c), (a, c), (a, b)];
+            let (p, q) = pairs
+                .iter()
+                .copied()
+                .max_by(|&(p, q), &(r, s)| distance(p, q).partial_cmp(&distance(r, s)).unwrap())
+                .unwrap();
+            return Circle::diameter(p, q);
+        }
+        let (ab2, ac2) = (ab.x * ab.x + ab.y * ab.y, ac.x * ac.x + ac.y * ac.y);
+        let offset = Coordinate {
+            x: (ac.y * ab2 - ab.y * ac2) / d,
+            y: (ab.x * ac2 - ac.x * ab2) / d,
+        };
+        Circle::new(a + offset, offset.x.hypot(offset.y))
+    }
+
+    fn contains(&self, coord: Coordinate<T>) -> bool {
+        // Allow for rounding in the radius, so that a coordinate which
+        // defines the circle is never found to be outside it
+        let tolerance = T::from(1e-10).unwrap() * (T::one() + self.radius);
+        distance(self.center, coord) <= self.radius + tolerance
+    }
+}
+
+fn distance<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>) -> T {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Shuffle the coordinates, which gives Welzl's algorithm its expected
+/// linear time whatever their order. The shuffle is seeded, so that the
+/// result is the same each time.
+fn shuffle<T>(items: &mut [T]) {
+    // xorshift64
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Geometry, MultiPoint, Polygon};
+    use rand::{Rng, SeedableRng};
+
+    fn assert_encloses<G>(geometry: &G, center: Point<f64>, radius: f64)
+    where
+        G: for<'a> CoordsIter<'a, Scalar = f64>,
+    {
+        for coord in geometry.coords_iter() {
+            assert!(distance(center.0, coord) <= radius * (1. + 1e-9));
+        }
+    }
+
+    #[test]
+    fn three_points() {
+        // An acute triangle, whose circle passes through all three
+        let points: MultiPoint<f64> = vec![(0., 0.), (4., 0.), (2., 3.)].into();
+        let (center, radius) = points.bounding_circle().unwrap();
+        // The circumcentre, equidistant from all three
+        assert_relative_eq!(center, point!(x: 2., y: 5. / 6.));
+        assert_relative_eq!(radius, 13. / 6.);
+        for point in points.iter() {
+            assert_relative_eq!(distance(center.0, point.0), radius);
+        }
+
+        // An obtuse triangle, whose circle is around its longest side
+        let points: MultiPoint<f64> = vec![(0., 0.), (4., 0.), (2., 1.)].into();
+        assert_eq!(points.bounding_circle(), Some((point!(x: 2., y: 0.), 2.)));
+    }
+
+    #[test]
+    fn random_points() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        for &len in &[2, 3, 10, 100, 1000] {
+            let points: MultiPoint<f64> = (0..len)
+                .map(|_| (rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)))
+                .collect::<Vec<_>>()
+                .into();
+            let (center, radius) = points.bounding_circle().unwrap();
+            assert_encloses(&points, center, radius);
+            // It is the smallest such circle, so at least two of the points
+            // are on it
+            let on_circle = points
+                .iter()
+                .filter(|point| (distance(center.0, point.0) - radius).abs() < 1e-9)
+                .count();
+            assert!(on_circle >= 2);
+        }
+    }
+
+    #[test]
+    fn geometries() {
+        let polygon: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let (center, radius) = polygon.bounding_circle().unwrap();
+        assert_relative_eq!(center, point!(x: 1., y: 1.));
+        assert_relative_eq!(radius, std::f64::consts::SQRT_2);
+        assert_encloses(&polygon, center, radius);
+
+        // Collinear coordinates
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 3., y: 3.),
+            (x: 1., y: 1.),
+            (x: -1., y: -1.),
+        ];
+        let (center, radius) = line_string.bounding_circle().unwrap();
+        assert_relative_eq!(center, point!(x: 1., y: 1.));
+        assert_relative_eq!(radius, 2. * std::f64::consts::SQRT_2);
+
+        let geometry = Geometry::Polygon(polygon);
+        assert_relative_eq!(
+            geometry.bounding_circle().unwrap().1,
+            std::f64::consts::SQRT_2
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert_eq!(empty.bounding_circle(), None);
+
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(point.bounding_circle(), Some((point, 0.)));
+
+        let repeated: MultiPoint<f64> = vec![(1., 2.), (1., 2.), (1., 2.)].into();
+        assert_eq!(repeated.bounding_circle(), Some((point, 0.)));
+    }
+}
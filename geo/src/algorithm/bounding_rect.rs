@@ -196,6 +196,67 @@ where
     }
 }
 
+/// Compute a bounding rectangle from coordinates added one at a time, such
+/// as when streaming data too large to hold in memory.
+///
+/// The result is the same as the [`BoundingRect`] of a `MultiPoint` of the
+/// same coordinates, but only the extent so far is stored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::bounding_rect::BoundingRectAccumulator;
+/// use geo::{Coordinate, Rect};
+///
+/// let mut accumulator = BoundingRectAccumulator::new();
+/// for &(x, y) in &[(3., 1.), (-2., 4.), (0., -5.)] {
+///     accumulator.add_coord(Coordinate { x, y });
+/// }
+/// assert_eq!(accumulator.finish(), Some(Rect::new((-2., -5.), (3., 4.))));
+///
+/// assert_eq!(BoundingRectAccumulator::<f64>::new().finish(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingRectAccumulator<T: CoordNum> {
+    extent: Option<(Coordinate<T>, Coordinate<T>)>,
+}
+
+impl<T: CoordNum> Default for BoundingRectAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> BoundingRectAccumulator<T> {
+    /// An accumulator with no coordinates added yet.
+    pub fn new() -> Self {
+        BoundingRectAccumulator { extent: None }
+    }
+
+    /// Extend the bounding rectangle to cover `coord`.
+    pub fn add_coord(&mut self, coord: Coordinate<T>) {
+        self.extent = Some(match self.extent {
+            None => (coord, coord),
+            Some((min, max)) => (
+                Coordinate {
+                    x: partial_min(min.x, coord.x),
+                    y: partial_min(min.y, coord.y),
+                },
+                Coordinate {
+                    x: partial_max(max.x, coord.x),
+                    y: partial_max(max.y, coord.y),
+                },
+            ),
+        });
+    }
+
+    /// The bounding rectangle of the coordinates added, or `None` if there
+    /// were none.
+    pub fn finish(self) -> Option<Rect<T>> {
+        self.extent.map(|(min, max)| Rect::new(min, max))
+    }
+}
+
 // Return a new rectangle that encompasses the provided rectangles
 fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
     Rect::new(
@@ -213,7 +274,7 @@ fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
 #[cfg(test)]
 mod test {
     use super::bounding_rect_merge;
-    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::algorithm::bounding_rect::{BoundingRect, BoundingRectAccumulator};
     use crate::line_string;
     use crate::{
         polygon, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
@@ -323,6 +384,50 @@ mod test {
         );
     }
 
+    #[test]
+    fn accumulator_matches_bounding_rect() {
+        let coords: Vec<Coordinate<f64>> = (0..100)
+            .map(|i| {
+                let i = i as f64;
+                Coordinate {
+                    x: (i * 0.37).sin() * i,
+                    y: (i * 0.11).cos() * 50. - i,
+                }
+            })
+            .collect();
+        let mut accumulator = BoundingRectAccumulator::new();
+        for (idx, &coord) in coords.iter().enumerate() {
+            accumulator.add_coord(coord);
+            let so_far: MultiPoint<f64> = coords[..=idx].iter().map(|&c| Point(c)).collect();
+            assert_eq!(accumulator.finish(), so_far.bounding_rect());
+        }
+        assert_eq!(accumulator.finish(), LineString(coords).bounding_rect());
+    }
+
+    #[test]
+    fn accumulator_edge_cases() {
+        assert_eq!(BoundingRectAccumulator::<i32>::new().finish(), None);
+
+        let mut accumulator = BoundingRectAccumulator::default();
+        accumulator.add_coord(Coordinate { x: 2, y: -3 });
+        assert_eq!(
+            accumulator.finish(),
+            Some(Rect::new(
+                Coordinate { x: 2, y: -3 },
+                Coordinate { x: 2, y: -3 }
+            ))
+        );
+        accumulator.add_coord(Coordinate { x: -7, y: 10 });
+        accumulator.add_coord(Coordinate { x: 0, y: 0 });
+        assert_eq!(
+            accumulator.finish(),
+            Some(Rect::new(
+                Coordinate { x: -7, y: -3 },
+                Coordinate { x: 2, y: 10 }
+            ))
+        );
+    }
+
     #[test]
     fn point_bounding_rect_test() {
         assert_eq!(
@@ -345,4 +450,24 @@ mod test {
             .bounding_rect(),
         );
     }
+
+    #[test]
+    fn integer_bounding_rect_test() {
+        let polygon: Polygon<i64> = polygon![(x: 3, y: -2), (x: 10, y: 4), (x: -1, y: 7)];
+        assert_eq!(
+            polygon.bounding_rect(),
+            Some(Rect::new(
+                Coordinate { x: -1, y: -2 },
+                Coordinate { x: 10, y: 7 }
+            ))
+        );
+        let geometry: Geometry<i64> = Line::from([(5, 5), (-5, 0)]).into();
+        assert_eq!(
+            geometry.bounding_rect(),
+            Some(Rect::new(
+                Coordinate { x: -5, y: 0 },
+                Coordinate { x: 5, y: 5 }
+            ))
+        );
+    }
 }
@@ -4,7 +4,8 @@ use std::iter::Sum;
 use crate::algorithm::area::{get_linestring_area, Area};
 use crate::algorithm::euclidean_length::EuclideanLength;
 use crate::{
-    CoordFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+    CoordFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 
 /// Calculation of the centroid.
@@ -354,14 +355,248 @@ where
     }
 }
 
+impl<T> Centroid for Triangle<T>
+where
+    T: CoordFloat,
+{
+    type Output = Point<T>;
+
+    fn centroid(&self) -> Self::Output {
+        let three = T::one() + T::one() + T::one();
+        Point::new(
+            (self.0.x + self.1.x + self.2.x) / three,
+            (self.0.y + self.1.y + self.2.y) / three,
+        )
+    }
+}
+
+impl<T> Centroid for Geometry<T>
+where
+    T: CoordFloat + FromPrimitive + Sum,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        match self {
+            Geometry::Point(g) => Some(g.centroid()),
+            Geometry::Line(g) => Some(g.centroid()),
+            Geometry::LineString(g) => g.centroid(),
+            Geometry::Polygon(g) => g.centroid(),
+            Geometry::MultiPoint(g) => g.centroid(),
+            Geometry::MultiLineString(g) => g.centroid(),
+            Geometry::MultiPolygon(g) => g.centroid(),
+            Geometry::GeometryCollection(g) => g.centroid(),
+            Geometry::Rect(g) => Some(g.centroid()),
+            Geometry::Triangle(g) => Some(g.centroid()),
+        }
+    }
+}
+
+/// The centroid of a collection is that of its members of the highest
+/// dimension only, as for PostGIS' `ST_Centroid`: areas are weighted by
+/// their area, and any lines and points are ignored; without areas,
+/// lines are weighted by their length, and any points are ignored;
+/// otherwise it is the mean of the points. Flat polygons count as lines,
+/// and lines of no length as points.
+///
+/// ```
+/// use geo::algorithm::centroid::Centroid;
+/// use geo::{point, polygon, Geometry, GeometryCollection};
+///
+/// let collection = GeometryCollection(vec![
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into(),
+///     point!(x: 100., y: 100.).into(),
+/// ]);
+/// assert_eq!(collection.centroid(), Some(point!(x: 1., y: 1.)));
+///
+/// let empty: GeometryCollection<f64> = GeometryCollection(vec![]);
+/// assert_eq!(empty.centroid(), None);
+/// ```
+impl<T> Centroid for GeometryCollection<T>
+where
+    T: CoordFloat + FromPrimitive + Sum,
+{
+    type Output = Option<Point<T>>;
+
+    fn centroid(&self) -> Self::Output {
+        let mut sums = DimensionSums::default();
+        for geometry in self.iter() {
+            sums.add_geometry(geometry);
+        }
+        sums.centroid()
+    }
+}
+
+/// Calculation of the centroid of the boundary of a polygon: of its rings,
+/// treated as lines, each weighted by its length. Unlike the centroid of
+/// its area, it is pulled towards any holes, such as to place a label
+/// along the edges.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::centroid::{BoundaryCentroid, Centroid};
+/// use geo::{point, polygon};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// assert_eq!(square.boundary_centroid(), Some(point!(x: 2., y: 2.)));
+///
+/// // With a hole towards the bottom left
+/// let holed = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+/// );
+/// assert_eq!(holed.boundary_centroid(), Some(point!(x: 1.9, y: 1.9)));
+/// assert!(holed.centroid().unwrap().x() > 2.);
+/// ```
+pub trait BoundaryCentroid<T: CoordFloat> {
+    /// The centroid of the boundary, or `None` if it has no length.
+    fn boundary_centroid(&self) -> Option<Point<T>>;
+}
+
+impl<T> BoundaryCentroid<T> for Polygon<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn boundary_centroid(&self) -> Option<Point<T>> {
+        let mut sum = WeightedSum::default();
+        for ring in std::iter::once(self.exterior()).chain(self.interiors()) {
+            if let Some(centroid) = ring.centroid() {
+                sum.add(centroid, ring.euclidean_length());
+            }
+        }
+        sum.mean()
+    }
+}
+
+impl<T> BoundaryCentroid<T> for MultiPolygon<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn boundary_centroid(&self) -> Option<Point<T>> {
+        let mut sum = WeightedSum::default();
+        for ring in self
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        {
+            if let Some(centroid) = ring.centroid() {
+                sum.add(centroid, ring.euclidean_length());
+            }
+        }
+        sum.mean()
+    }
+}
+
+/// A weighted sum of positions, and the total weight.
+#[derive(Clone, Copy)]
+struct WeightedSum<T: CoordFloat> {
+    x: T,
+    y: T,
+    weight: T,
+}
+
+impl<T: CoordFloat> Default for WeightedSum<T> {
+    fn default() -> Self {
+        WeightedSum {
+            x: T::zero(),
+            y: T::zero(),
+            weight: T::zero(),
+        }
+    }
+}
+
+impl<T: CoordFloat> WeightedSum<T> {
+    fn add(&mut self, point: Point<T>, weight: T) {
+        self.x = self.x + point.x() * weight;
+        self.y = self.y + point.y() * weight;
+        self.weight = self.weight + weight;
+    }
+
+    fn mean(&self) -> Option<Point<T>> {
+        if self.weight == T::zero() {
+            return None;
+        }
+        Some(Point::new(self.x / self.weight, self.y / self.weight))
+    }
+}
+
+/// Weighted sums of the centroids of the parts of a collection, kept
+/// apart by their dimension.
+struct DimensionSums<T: CoordFloat> {
+    areas: WeightedSum<T>,
+    lines: WeightedSum<T>,
+    points: WeightedSum<T>,
+}
+
+impl<T: CoordFloat> Default for DimensionSums<T> {
+    fn default() -> Self {
+        DimensionSums {
+            areas: WeightedSum::default(),
+            lines: WeightedSum::default(),
+            points: WeightedSum::default(),
+        }
+    }
+}
+
+impl<T> DimensionSums<T>
+where
+    T: CoordFloat + FromPrimitive + Sum,
+{
+    fn add_point(&mut self, point: Point<T>) {
+        self.points.add(point, T::one());
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        let length = line_string.euclidean_length();
+        if let Some(centroid) = line_string.centroid() {
+            if length == T::zero() {
+                self.add_point(centroid);
+            } else {
+                self.lines.add(centroid, length);
+            }
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        let area = polygon.unsigned_area();
+        if area == T::zero() {
+            self.add_line_string(polygon.exterior());
+        } else if let Some(centroid) = polygon.centroid() {
+            self.areas.add(centroid, area);
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &Geometry<T>) {
+        match geometry {
+            Geometry::Point(g) => self.add_point(*g),
+            Geometry::Line(g) => self.add_line_string(&LineString(vec![g.start, g.end])),
+            Geometry::LineString(g) => self.add_line_string(g),
+            Geometry::Polygon(g) => self.add_polygon(g),
+            Geometry::MultiPoint(g) => g.iter().for_each(|point| self.add_point(*point)),
+            Geometry::MultiLineString(g) => g.iter().for_each(|ls| self.add_line_string(ls)),
+            Geometry::MultiPolygon(g) => g.iter().for_each(|poly| self.add_polygon(poly)),
+            Geometry::GeometryCollection(g) => g.iter().for_each(|g| self.add_geometry(g)),
+            Geometry::Rect(g) => self.add_polygon(&g.to_polygon()),
+            Geometry::Triangle(g) => self.add_polygon(&g.to_polygon()),
+        }
+    }
+
+    fn centroid(&self) -> Option<Point<T>> {
+        self.areas
+            .mean()
+            .or_else(|| self.lines.mean())
+            .or_else(|| self.points.mean())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::algorithm::centroid::Centroid;
+    use crate::algorithm::centroid::{BoundaryCentroid, Centroid};
     use crate::algorithm::euclidean_distance::EuclideanDistance;
     use crate::line_string;
     use crate::{
-        polygon, CoordFloat, Coordinate, Line, LineString, MultiLineString, MultiPolygon, Point,
-        Polygon, Rect,
+        point, polygon, CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString,
+        MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
     };
 
     /// small helper to create a coordinate
@@ -684,8 +919,134 @@ mod test {
         assert_eq!(point, bounding_rect.centroid());
     }
     #[test]
+    fn rect_centroid_is_center_test() {
+        for rect in &[
+            Rect::new(c(-3., 1.), c(7., 2.5)),
+            Rect::new(c(1e10, -1e-10), c(1e10 + 1., 1e-10)),
+            // Degenerate, as a line and a point
+            Rect::new(c(0., 0.), c(0., 10.)),
+            Rect::new(c(5., 5.), c(5., 5.)),
+        ] {
+            assert_eq!(rect.centroid(), Point(rect.center()));
+            assert_relative_eq!(
+                rect.centroid(),
+                rect.to_polygon().centroid().unwrap(),
+                epsilon = 1e-9
+            );
+        }
+    }
+    #[test]
     fn line_test() {
         let line1 = Line::new(c(0., 1.), c(1., 3.));
         assert_eq!(line1.centroid(), Point::new(0.5, 2.));
     }
+    #[test]
+    fn triangle_test() {
+        let triangle = Triangle(c(0., 0.), c(3., 0.), c(0., 6.));
+        assert_eq!(triangle.centroid(), Point::new(1., 2.));
+        assert_relative_eq!(
+            triangle.centroid(),
+            triangle.to_polygon().centroid().unwrap()
+        );
+    }
+    #[test]
+    fn geometry_test() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+        let geometry: Geometry<f64> = polygon.clone().into();
+        assert_eq!(geometry.centroid(), polygon.centroid());
+        let geometry: Geometry<f64> = p(3., 4.).into();
+        assert_eq!(geometry.centroid(), Some(p(3., 4.)));
+        let geometry: Geometry<f64> = MultiPoint(vec![]).into();
+        assert_eq!(geometry.centroid(), None);
+    }
+    #[test]
+    fn collection_polygon_and_stray_points_test() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let collection = GeometryCollection(vec![
+            square.clone().into(),
+            point!(x: 100., y: 100.).into(),
+            MultiPoint(vec![p(-50., 3.), p(20., -7.)]).into(),
+            line_string![(x: 10., y: 10.), (x: 30., y: 10.)].into(),
+        ]);
+        assert_eq!(collection.centroid(), square.centroid());
+
+        // Several areas are weighted by their area, nested or not
+        let rect = Rect::new(c(10., 0.), c(14., 2.));
+        let collection = GeometryCollection(vec![
+            square.into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![rect.into()])),
+        ]);
+        assert_relative_eq!(collection.centroid().unwrap(), p(25. / 3., 1.));
+    }
+    #[test]
+    fn collection_lower_dimensions_test() {
+        // Lines dominate points
+        let collection = GeometryCollection(vec![
+            Line::new(c(0., 0.), c(2., 0.)).into(),
+            line_string![(x: 0., y: 10.), (x: 0., y: 16.)].into(),
+            point!(x: 100., y: 100.).into(),
+        ]);
+        assert_relative_eq!(collection.centroid().unwrap(), p(0.25, 9.75));
+
+        // A flat polygon counts as a line, and a line of no length as a point
+        let collection = GeometryCollection(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 0., y: 0.)].into(),
+            point!(x: 100., y: 100.).into(),
+        ]);
+        assert_relative_eq!(collection.centroid().unwrap(), p(1., 0.));
+        let collection = GeometryCollection(vec![
+            Line::new(c(4., 4.), c(4., 4.)).into(),
+            point!(x: 0., y: 2.).into(),
+        ]);
+        assert_relative_eq!(collection.centroid().unwrap(), p(2., 3.));
+    }
+    #[test]
+    fn empty_collection_test() {
+        let empty: GeometryCollection<f64> = GeometryCollection(vec![]);
+        assert_eq!(empty.centroid(), None);
+        let empty_members = GeometryCollection(vec![
+            MultiPoint::<f64>(vec![]).into(),
+            LineString::<f64>(vec![]).into(),
+        ]);
+        assert_eq!(empty_members.centroid(), None);
+    }
+    #[test]
+    fn boundary_centroid_test() {
+        let off_center_hole = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+        );
+        // The hole pulls the area centroid away, but the boundary centroid
+        // towards it
+        assert_relative_eq!(
+            off_center_hole.centroid().unwrap(),
+            p(492. / 96., 492. / 96.)
+        );
+        assert_relative_eq!(
+            off_center_hole.boundary_centroid().unwrap(),
+            p(216. / 48., 216. / 48.)
+        );
+
+        // Without holes, the same for a symmetric polygon
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_relative_eq!(
+            square.boundary_centroid().unwrap(),
+            square.centroid().unwrap()
+        );
+        let multi_polygon = MultiPolygon(vec![square, off_center_hole.clone()]);
+        assert_relative_eq!(
+            multi_polygon.boundary_centroid().unwrap(),
+            p((5. * 40. + 216.) / 88., (5. * 40. + 216.) / 88.)
+        );
+
+        // Degenerate polygons
+        let empty: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.boundary_centroid(), None);
+        let point = Polygon::new(LineString(vec![c(1., 1.)]), vec![]);
+        assert_eq!(point.boundary_centroid(), None);
+        assert_eq!(MultiPolygon::<f64>(vec![]).boundary_centroid(), None);
+        // A flat polygon still has a boundary
+        let flat = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 0., y: 0.)];
+        assert_relative_eq!(flat.boundary_centroid().unwrap(), p(1., 0.));
+    }
 }
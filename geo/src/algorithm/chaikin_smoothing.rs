@@ -0,0 +1,208 @@
+use crate::{CoordFloat, Coordinate, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Smooth a geometry by cutting its corners, using Chaikin's algorithm.
+///
+/// Each iteration replaces every segment by two new coordinates, a quarter
+/// and three quarters of the way along it, rounding off the corners between
+/// segments. The result converges on a quadratic B-spline as the number of
+/// iterations increases, and each iteration roughly doubles the number of
+/// coordinates, so only a few are usually needed.
+///
+/// The first and last coordinates of an open `LineString` are kept in place.
+/// A closed `LineString` is smoothed as a ring: its start is a corner like
+/// any other, and the result is closed too. The rings of a `Polygon` are
+/// always smoothed this way.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::chaikin_smoothing::ChaikinSmoothing;
+/// use geo::line_string;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.chaikin_smooth(1),
+///     line_string![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 3., y: 0.),
+///         (x: 4., y: 1.),
+///         (x: 4., y: 3.),
+///         (x: 4., y: 4.),
+///     ]
+/// );
+/// ```
+pub trait ChaikinSmoothing<T: CoordFloat> {
+    fn chaikin_smooth(&self, iterations: usize) -> Self;
+}
+
+impl<T: CoordFloat> ChaikinSmoothing<T> for LineString<T> {
+    fn chaikin_smooth(&self, iterations: usize) -> Self {
+        let mut smoothed = self.clone();
+        for _ in 0..iterations {
+            smoothed = if smoothed.is_closed() {
+                smooth_ring(&smoothed)
+            } else {
+                smooth_line_string(&smoothed)
+            };
+        }
+        smoothed
+    }
+}
+
+impl<T: CoordFloat> ChaikinSmoothing<T> for MultiLineString<T> {
+    fn chaikin_smooth(&self, iterations: usize) -> Self {
+        MultiLineString(
+            self.iter()
+                .map(|line_string| line_string.chaikin_smooth(iterations))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> ChaikinSmoothing<T> for Polygon<T> {
+    fn chaikin_smooth(&self, iterations: usize) -> Self {
+        Polygon::new(
+            self.exterior().chaikin_smooth(iterations),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.chaikin_smooth(iterations))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> ChaikinSmoothing<T> for MultiPolygon<T> {
+    fn chaikin_smooth(&self, iterations: usize) -> Self {
+        MultiPolygon(
+            self.iter()
+                .map(|polygon| polygon.chaikin_smooth(iterations))
+                .collect(),
+        )
+    }
+}
+
+fn smooth_line_string<T: CoordFloat>(line_string: &LineString<T>) -> LineString<T> {
+    if line_string.0.len() < 3 {
+        return line_string.clone();
+    }
+    let mut coords = Vec::with_capacity(line_string.0.len() * 2);
+    coords.push(line_string.0[0]);
+    for pair in line_string.0.windows(2) {
+        let (near, far) = cut(pair[0], pair[1]);
+        coords.push(near);
+        coords.push(far);
+    }
+    coords.push(*line_string.0.last().unwrap());
+    LineString(coords)
+}
+
+fn smooth_ring<T: CoordFloat>(ring: &LineString<T>) -> LineString<T> {
+    // Fewer than three coordinates, including the closing one, have no corners
+    if ring.0.len() < 3 {
+        return ring.clone();
+    }
+    let mut coords = Vec::with_capacity(ring.0.len() * 2);
+    for pair in ring.0.windows(2) {
+        let (near, far) = cut(pair[0], pair[1]);
+        coords.push(near);
+        coords.push(far);
+    }
+    coords.push(coords[0]);
+    LineString(coords)
+}
+
+/// The coordinates a quarter and three quarters of the way from `start` to
+/// `end`.
+fn cut<T: CoordFloat>(start: Coordinate<T>, end: Coordinate<T>) -> (Coordinate<T>, Coordinate<T>) {
+    let quarter = T::from(0.25).unwrap();
+    let delta = end - start;
+    (start + delta * quarter, end - delta * quarter)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn open_vertex_count() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 8., y: 4.),
+        ];
+        assert_eq!(line_string.chaikin_smooth(0), line_string);
+        // Every iteration doubles the number of coordinates
+        for iterations in 1..5 {
+            let smoothed = line_string.chaikin_smooth(iterations);
+            assert_eq!(smoothed.0.len(), 4 << iterations);
+            assert_eq!(smoothed.0.first(), line_string.0.first());
+            assert_eq!(smoothed.0.last(), line_string.0.last());
+        }
+    }
+
+    #[test]
+    fn closed_ring() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let smoothed = square.chaikin_smooth(1);
+        assert_eq!(
+            smoothed,
+            line_string![
+                (x: 1., y: 0.),
+                (x: 3., y: 0.),
+                (x: 4., y: 1.),
+                (x: 4., y: 3.),
+                (x: 3., y: 4.),
+                (x: 1., y: 4.),
+                (x: 0., y: 3.),
+                (x: 0., y: 1.),
+                (x: 1., y: 0.),
+            ]
+        );
+        // Four corners become eight, then sixteen, ... and the ring stays closed
+        for iterations in 1..5 {
+            let smoothed = square.chaikin_smooth(iterations);
+            assert!(smoothed.is_closed());
+            assert_eq!(smoothed.0.len(), (4 << iterations) + 1);
+        }
+    }
+
+    #[test]
+    fn polygon() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let smoothed = polygon.chaikin_smooth(1);
+        assert!(smoothed.exterior().is_closed());
+        // Each corner loses a right triangle with legs of 1
+        assert_relative_eq!(smoothed.unsigned_area(), 14.);
+        // The corners are rounded further, but the area converges
+        let area = polygon.chaikin_smooth(8).unsigned_area();
+        assert!(area < 14. && area > 13.);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.chaikin_smooth(3), empty);
+        let line = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(line.chaikin_smooth(3), line);
+    }
+}
@@ -3,6 +3,18 @@ use num_traits::Float;
 
 /// Calculate the signed approximate geodesic area of a `Geometry`.
 ///
+/// The coordinates are interpreted as longitude/latitude, in degrees,
+/// and the area is computed on a sphere whose radius is the equatorial
+/// radius of the WGS84 ellipsoid (6,378,137 meters).
+///
+/// The sign of the area is that of the exterior ring: positive if it is
+/// oriented counter-clockwise, negative otherwise. The area of any
+/// interior rings is subtracted, regardless of their orientation.
+///
+/// Rings crossing the antimeridian are supported, by taking the shorter
+/// way around between consecutive coordinates. Rings enclosing one of
+/// the poles are not.
+///
 /// # Units
 ///
 /// - return value: meters²
@@ -58,11 +70,19 @@ where
     T: Float + CoordNum,
 {
     fn chamberlain_duquette_signed_area(&self) -> T {
-        self.interiors()
+        let area = ring_area(self.exterior());
+        let is_negative = area < T::zero();
+
+        let area = self
+            .interiors()
             .iter()
-            .fold(ring_area(self.exterior()), |total, next| {
-                total - ring_area(next)
-            })
+            .fold(area.abs(), |total, next| total - ring_area(next).abs());
+
+        if is_negative {
+            -area
+        } else {
+            area
+        }
     }
 
     fn chamberlain_duquette_unsigned_area(&self) -> T {
@@ -74,33 +94,31 @@ fn ring_area<T>(coords: &LineString<T>) -> T
 where
     T: Float + CoordNum,
 {
-    let mut total = T::zero();
-    let coords_len = coords.0.len();
-
-    if coords_len > 2 {
-        for i in 0..coords_len {
-            let (lower_index, middle_index, upper_index) = if i == coords_len - 2 {
-                // i = N-2
-                (coords_len - 2, coords_len - 1, 0)
-            } else if i == coords_len - 1 {
-                // i = N-1
-                (coords_len - 1, 0, 1)
-            } else {
-                // i = 0 to N-3
-                (i, i + 1, i + 2)
-            };
-            let p1 = coords[lower_index];
-            let p2 = coords[middle_index];
-            let p3 = coords[upper_index];
-            total = total + (p3.x.to_radians() - p1.x.to_radians()) * p2.y.to_radians().sin();
-        }
+    // Summing over the edges, rather than the vertices as in the paper,
+    // lets us take the shorter way around for each longitude difference.
+    let total = coords.lines().fold(T::zero(), |total, line| {
+        let delta_lon = normalize_longitude_delta(line.end.x - line.start.x).to_radians();
+        total + delta_lon * (line.start.y.to_radians().sin() + line.end.y.to_radians().sin())
+    });
+
+    total * T::from(EQUATORIAL_EARTH_RADIUS).unwrap() * T::from(EQUATORIAL_EARTH_RADIUS).unwrap()
+        / T::from(-2).unwrap()
+}
 
-        total = total
-            * T::from(EQUATORIAL_EARTH_RADIUS).unwrap()
-            * T::from(EQUATORIAL_EARTH_RADIUS).unwrap()
-            / T::from(-2).unwrap();
+/// Wrap a difference of longitudes, in degrees, into `[-180, 180]`.
+fn normalize_longitude_delta<T>(delta: T) -> T
+where
+    T: Float + CoordNum,
+{
+    let half_turn = T::from(180).unwrap();
+    let full_turn = T::from(360).unwrap();
+    if delta > half_turn {
+        delta - full_turn
+    } else if delta < -half_turn {
+        delta + full_turn
+    } else {
+        delta
     }
-    total
 }
 
 #[cfg(test)]
@@ -173,4 +191,85 @@ mod test {
         ];
         assert_relative_eq!(1208198651182.4727, poly.chamberlain_duquette_signed_area());
     }
+
+    #[test]
+    fn test_holes_oriented_clockwise() {
+        let exterior = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)];
+        let interior = vec![(1., 1.), (2., 1.), (2., 2.), (1., 2.), (1., 1.)];
+        let ccw_hole = Polygon::new(exterior.clone().into(), vec![interior.clone().into()]);
+        let cw_hole = Polygon::new(
+            exterior.into(),
+            vec![interior.into_iter().rev().collect::<Vec<_>>().into()],
+        );
+        assert_relative_eq!(
+            ccw_hole.chamberlain_duquette_signed_area(),
+            cw_hole.chamberlain_duquette_signed_area()
+        );
+    }
+
+    #[test]
+    fn test_country_scale() {
+        // Colorado is (almost) bounded by lines of latitude and longitude,
+        // so its area on the sphere has a closed form.
+        let polygon = polygon![
+            (x: -109.05, y: 37.),
+            (x: -102.05, y: 37.),
+            (x: -102.05, y: 41.),
+            (x: -109.05, y: 41.),
+        ];
+        let expected = EQUATORIAL_EARTH_RADIUS
+            * EQUATORIAL_EARTH_RADIUS
+            * 7f64.to_radians()
+            * (41f64.to_radians().sin() - 37f64.to_radians().sin());
+        assert_relative_eq!(
+            expected,
+            polygon.chamberlain_duquette_signed_area(),
+            max_relative = 1e-10
+        );
+        // Its official area is 269,837 km²
+        assert_relative_eq!(
+            269_837e6,
+            polygon.chamberlain_duquette_unsigned_area(),
+            max_relative = 0.005
+        );
+    }
+
+    #[test]
+    fn test_small_patch_at_equator() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 0.001, y: 0.),
+            (x: 0.001, y: 0.001),
+            (x: 0., y: 0.001),
+        ];
+        // Very nearly a square, with sides of 1/1000 of a degree
+        let side = EQUATORIAL_EARTH_RADIUS * 0.001f64.to_radians();
+        assert_relative_eq!(
+            side * side,
+            polygon.chamberlain_duquette_signed_area(),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_antimeridian() {
+        let spanning = polygon![
+            (x: 179., y: 10.),
+            (x: -179., y: 10.),
+            (x: -179., y: 12.),
+            (x: 179., y: 12.),
+        ];
+        let not_spanning = polygon![
+            (x: 1., y: 10.),
+            (x: 3., y: 10.),
+            (x: 3., y: 12.),
+            (x: 1., y: 12.),
+        ];
+        assert!(spanning.chamberlain_duquette_signed_area() > 0.);
+        assert_relative_eq!(
+            not_spanning.chamberlain_duquette_signed_area(),
+            spanning.chamberlain_duquette_signed_area(),
+            max_relative = 1e-10
+        );
+    }
 }
@@ -0,0 +1,347 @@
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::{
+    CoordFloat, Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon,
+    Rect,
+};
+
+/// Clip a geometry to an axis-aligned rectangle, keeping only the part
+/// of it which lies within (or on the boundary of) the rectangle.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::clip_to_rect::ClipToRect;
+/// use geo::{Coordinate, Line, Rect};
+///
+/// let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 10., y: 10. });
+///
+/// let line = Line::from([(-5., 5.), (5., 5.)]);
+/// assert_eq!(line.clip_to_rect(rect), Some(Line::from([(0., 5.), (5., 5.)])));
+///
+/// let outside = Line::from([(-5., 5.), (-1., 5.)]);
+/// assert_eq!(outside.clip_to_rect(rect), None);
+/// ```
+pub trait ClipToRect<T: CoordFloat> {
+    type Output;
+
+    fn clip_to_rect(&self, rect: Rect<T>) -> Self::Output;
+}
+
+impl<T: CoordFloat> ClipToRect<T> for Line<T> {
+    /// The part of the `Line` within the rectangle, if any.
+    ///
+    /// A `Line` lying entirely within the rectangle is returned unchanged,
+    /// and one which only touches it at a single point results in a
+    /// degenerate `Line` (whose start and end are equal).
+    type Output = Option<Line<T>>;
+
+    fn clip_to_rect(&self, rect: Rect<T>) -> Self::Output {
+        let (t0, t1) = liang_barsky(self, rect)?;
+        let delta = self.delta();
+        let start = if t0 > T::zero() {
+            self.start + delta * t0
+        } else {
+            self.start
+        };
+        let end = if t1 < T::one() {
+            self.start + delta * t1
+        } else {
+            self.end
+        };
+        Some(Line::new(start, end))
+    }
+}
+
+impl<T: CoordFloat> ClipToRect<T> for LineString<T> {
+    /// The parts of the `LineString` within the rectangle, in order.
+    ///
+    /// Consecutive segments which remain connected after clipping are
+    /// kept in the same part, so a `LineString` lying entirely within the
+    /// rectangle results in a single, unchanged, `LineString`. Parts which
+    /// only touch the rectangle at a single point are discarded.
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: Rect<T>) -> Self::Output {
+        let mut parts = vec![];
+        let mut current: Vec<Coordinate<T>> = vec![];
+        for line in self.lines() {
+            match line.clip_to_rect(rect) {
+                Some(clipped) if current.last() == Some(&clipped.start) => {
+                    current.push(clipped.end)
+                }
+                Some(clipped) => {
+                    flush(&mut current, &mut parts);
+                    current.push(clipped.start);
+                    current.push(clipped.end);
+                }
+                None => flush(&mut current, &mut parts),
+            }
+        }
+        flush(&mut current, &mut parts);
+        MultiLineString(parts)
+    }
+}
+
+impl<T: GeoFloat> ClipToRect<T> for Polygon<T> {
+    /// The parts of the `Polygon` within the rectangle.
+    ///
+    /// Clipping a concave `Polygon` may split it into several parts, and
+    /// any interiors are clipped along with the exterior. A `Polygon`
+    /// lying entirely within the rectangle is returned unchanged, and one
+    /// lying entirely outside of it results in an empty `MultiPolygon`.
+    ///
+    /// Otherwise, this is the [intersection](BooleanOps::intersection)
+    /// of the `Polygon` and the rectangle.
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: Rect<T>) -> Self::Output {
+        let bounds = match self.bounding_rect() {
+            Some(bounds) => bounds,
+            None => return MultiPolygon(vec![]),
+        };
+        if rect_within(bounds, rect) {
+            return MultiPolygon(vec![self.clone()]);
+        }
+        if rect_disjoint(bounds, rect) {
+            return MultiPolygon(vec![]);
+        }
+        self.intersection(&rect.to_polygon())
+    }
+}
+
+impl<T: GeoFloat> ClipToRect<T> for MultiPolygon<T> {
+    /// The parts of each `Polygon` within the rectangle.
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: Rect<T>) -> Self::Output {
+        self.iter()
+            .flat_map(|polygon| polygon.clip_to_rect(rect))
+            .collect()
+    }
+}
+
+fn rect_within<T: CoordFloat>(inner: Rect<T>, outer: Rect<T>) -> bool {
+    outer.min().x <= inner.min().x
+        && outer.min().y <= inner.min().y
+        && inner.max().x <= outer.max().x
+        && inner.max().y <= outer.max().y
+}
+
+fn rect_disjoint<T: CoordFloat>(a: Rect<T>, b: Rect<T>) -> bool {
+    a.max().x < b.min().x || b.max().x < a.min().x || a.max().y < b.min().y || b.max().y < a.min().y
+}
+
+/// Move `current` into `parts`, unless it has no length.
+fn flush<T: CoordFloat>(current: &mut Vec<Coordinate<T>>, parts: &mut Vec<LineString<T>>) {
+    let coords = std::mem::take(current);
+    if coords.windows(2).any(|pair| pair[0] != pair[1]) {
+        parts.push(LineString(coords));
+    }
+}
+
+/// The range of parameters `t` in `[0, 1]` for which `start + t * delta`
+/// lies within `rect`, using the Liang–Barsky algorithm.
+fn liang_barsky<T: CoordFloat>(line: &Line<T>, rect: Rect<T>) -> Option<(T, T)> {
+    let delta = line.delta();
+    let (min, max) = (rect.min(), rect.max());
+    let mut t0 = T::zero();
+    let mut t1 = T::one();
+
+    // Each boundary is given as `p * t <= q`
+    let boundaries = [
+        (-delta.x, line.start.x - min.x),
+        (delta.x, max.x - line.start.x),
+        (-delta.y, line.start.y - min.y),
+        (delta.y, max.y - line.start.y),
+    ];
+    for &(p, q) in &boundaries {
+        if p == T::zero() {
+            // Parallel to this boundary, and entirely outside of it
+            if q < T::zero() {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < T::zero() {
+                // Entering
+                if t > t1 {
+                    return None;
+                }
+                if t > t0 {
+                    t0 = t;
+                }
+            } else {
+                // Leaving
+                if t < t0 {
+                    return None;
+                }
+                if t < t1 {
+                    t1 = t;
+                }
+            }
+        }
+    }
+    Some((t0, t1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    fn rect() -> Rect<f64> {
+        Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 10., y: 10. })
+    }
+
+    #[test]
+    fn line_inside() {
+        let line = Line::from([(1., 1.), (9., 3.)]);
+        assert_eq!(line.clip_to_rect(rect()), Some(line));
+
+        // On the boundary
+        let line = Line::from([(0., 0.), (0., 10.)]);
+        assert_eq!(line.clip_to_rect(rect()), Some(line));
+    }
+
+    #[test]
+    fn line_outside() {
+        let line = Line::from([(-1., -1.), (-5., 20.)]);
+        assert_eq!(line.clip_to_rect(rect()), None);
+
+        // Outside, but with overlapping bounding boxes
+        let line = Line::from([(-2., 9.), (2., 13.)]);
+        assert_eq!(line.clip_to_rect(rect()), None);
+
+        // Parallel to a side
+        let line = Line::from([(-1., 11.), (20., 11.)]);
+        assert_eq!(line.clip_to_rect(rect()), None);
+    }
+
+    #[test]
+    fn line_straddling() {
+        let line = Line::from([(5., 5.), (15., 5.)]);
+        assert_eq!(
+            line.clip_to_rect(rect()),
+            Some(Line::from([(5., 5.), (10., 5.)]))
+        );
+
+        let line = Line::from([(-5., -5.), (15., 15.)]);
+        assert_eq!(
+            line.clip_to_rect(rect()),
+            Some(Line::from([(0., 0.), (10., 10.)]))
+        );
+
+        // Direction is preserved
+        let line = Line::from([(15., 10.), (-5., 0.)]);
+        assert_eq!(
+            line.clip_to_rect(rect()),
+            Some(Line::from([(10., 7.5), (0., 2.5)]))
+        );
+
+        // Touching a corner
+        let line = Line::from([(-5., 5.), (5., 15.)]);
+        assert_eq!(
+            line.clip_to_rect(rect()),
+            Some(Line::from([(0., 10.), (0., 10.)]))
+        );
+    }
+
+    #[test]
+    fn line_string_inside() {
+        let ls = line_string![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.)];
+        assert_eq!(ls.clip_to_rect(rect()), MultiLineString(vec![ls]));
+    }
+
+    #[test]
+    fn line_string_outside() {
+        let ls = line_string![(x: -1., y: -1.), (x: -9., y: 1.), (x: -9., y: 20.)];
+        assert!(ls.clip_to_rect(rect()).0.is_empty());
+
+        // Touching a corner only
+        let ls = line_string![(x: -5., y: 5.), (x: 0., y: 10.), (x: 5., y: 15.)];
+        assert!(ls.clip_to_rect(rect()).0.is_empty());
+    }
+
+    #[test]
+    fn line_string_straddling() {
+        // Enters, leaves, and enters again
+        let ls = line_string![
+            (x: -5., y: 5.),
+            (x: 5., y: 5.),
+            (x: 5., y: 15.),
+            (x: 8., y: 15.),
+            (x: 8., y: 5.),
+        ];
+        assert_eq!(
+            ls.clip_to_rect(rect()),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 5.), (x: 5., y: 5.), (x: 5., y: 10.)],
+                line_string![(x: 8., y: 10.), (x: 8., y: 5.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn polygon_inside() {
+        let polygon = polygon![
+            exterior: [(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)],
+            interiors: [[(x: 4., y: 4.), (x: 4., y: 6.), (x: 6., y: 6.), (x: 6., y: 4.)]],
+        ];
+        assert_eq!(polygon.clip_to_rect(rect()), MultiPolygon(vec![polygon]));
+    }
+
+    #[test]
+    fn polygon_outside() {
+        let polygon = polygon![(x: 11., y: 1.), (x: 19., y: 1.), (x: 19., y: 9.)];
+        assert!(polygon.clip_to_rect(rect()).0.is_empty());
+
+        // Outside, but with overlapping bounding boxes
+        let polygon = polygon![(x: 9., y: 12.), (x: 12., y: 9.), (x: 12., y: 12.)];
+        assert!(polygon.clip_to_rect(rect()).0.is_empty());
+    }
+
+    #[test]
+    fn polygon_straddling() {
+        // Sticks out of the right hand side
+        let polygon = polygon![(x: 5., y: 2.), (x: 15., y: 2.), (x: 15., y: 8.), (x: 5., y: 8.)];
+        let clipped = polygon.clip_to_rect(rect());
+        assert_eq!(clipped.0.len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), 30.);
+
+        // A U-shape, whose arms are cut off from each other
+        let polygon = polygon![
+            (x: 2., y: 5.),
+            (x: 2., y: 15.),
+            (x: 4., y: 15.),
+            (x: 4., y: 12.),
+            (x: 6., y: 12.),
+            (x: 6., y: 15.),
+            (x: 8., y: 15.),
+            (x: 8., y: 5.),
+        ];
+        let clipped = polygon.clip_to_rect(rect());
+        assert_eq!(clipped.0.len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), 30.);
+        let clipped = polygon.clip_to_rect(Rect::new(
+            Coordinate { x: 0., y: 13. },
+            Coordinate { x: 10., y: 20. },
+        ));
+        assert_eq!(clipped.0.len(), 2);
+        assert_relative_eq!(clipped.unsigned_area(), 8.);
+
+        // Interiors are clipped too
+        let polygon = polygon![
+            exterior: [(x: 5., y: 2.), (x: 15., y: 2.), (x: 15., y: 8.), (x: 5., y: 8.)],
+            interiors: [
+                [(x: 6., y: 4.), (x: 6., y: 6.), (x: 8., y: 6.), (x: 8., y: 4.)],
+                [(x: 9., y: 4.), (x: 9., y: 6.), (x: 11., y: 6.), (x: 11., y: 4.)],
+            ],
+        ];
+        let clipped = polygon.clip_to_rect(rect());
+        assert_eq!(clipped.0.len(), 1);
+        assert_eq!(clipped.0[0].interiors().len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), 30. - 4. - 2.);
+    }
+}
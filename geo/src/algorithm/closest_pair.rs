@@ -0,0 +1,214 @@
+use crate::utils::lex_cmp;
+use crate::{CoordFloat, Coordinate, MultiPoint, Point};
+
+/// Find the two closest points of a set, and the (Euclidean) distance
+/// between them.
+///
+/// This uses the classic divide and conquer algorithm, which takes
+/// `O(n log n)` time. Points with `NaN` or infinite coordinates are
+/// ignored, and `None` is returned if fewer than two points remain. If
+/// several pairs are equally close, it is unspecified which one is
+/// returned, as is the order of the two points of the pair.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::closest_pair::ClosestPair;
+/// use geo::{point, MultiPoint};
+///
+/// let points = MultiPoint(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 5., y: 5.),
+///     point!(x: 9., y: 0.),
+///     point!(x: 5., y: 2.),
+/// ]);
+///
+/// let (a, b, distance) = points.closest_pair().unwrap();
+/// assert_eq!(distance, 3.);
+/// assert!(a == point!(x: 5., y: 5.) || b == point!(x: 5., y: 5.));
+/// assert!(a == point!(x: 5., y: 2.) || b == point!(x: 5., y: 2.));
+/// ```
+pub trait ClosestPair<T: CoordFloat> {
+    fn closest_pair(&self) -> Option<(Point<T>, Point<T>, T)>;
+}
+
+impl<T: CoordFloat> ClosestPair<T> for MultiPoint<T> {
+    fn closest_pair(&self) -> Option<(Point<T>, Point<T>, T)> {
+        let mut coords: Vec<Coordinate<T>> = self
+            .iter()
+            .map(|point| point.0)
+            .filter(|coord| coord.x.is_finite() && coord.y.is_finite())
+            .collect();
+        if coords.len() < 2 {
+            return None;
+        }
+        coords.sort_by(lex_cmp);
+
+        let mut best = Pair::new(coords[0], coords[1]);
+        let mut buffer = Vec::with_capacity(coords.len());
+        closest_in(&mut coords, &mut best, &mut buffer);
+        Some((best.a.into(), best.b.into(), best.distance))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Pair<T: CoordFloat> {
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    distance: T,
+}
+
+impl<T: CoordFloat> Pair<T> {
+    fn new(a: Coordinate<T>, b: Coordinate<T>) -> Self {
+        let delta = b - a;
+        Pair {
+            a,
+            b,
+            distance: delta.x.hypot(delta.y),
+        }
+    }
+
+    fn update(&mut self, a: Coordinate<T>, b: Coordinate<T>) {
+        let candidate = Pair::new(a, b);
+        if candidate.distance < self.distance {
+            *self = candidate;
+        }
+    }
+}
+
+/// Update `best` with the closest pair amongst `coords`, which must be
+/// sorted by `x`. Afterwards, `coords` is sorted by `y` instead.
+fn closest_in<T: CoordFloat>(
+    coords: &mut [Coordinate<T>],
+    best: &mut Pair<T>,
+    buffer: &mut Vec<Coordinate<T>>,
+) {
+    if coords.len() <= 3 {
+        for (idx, &a) in coords.iter().enumerate() {
+            for &b in &coords[idx + 1..] {
+                best.update(a, b);
+            }
+        }
+        coords.sort_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
+        return;
+    }
+
+    let mid = coords.len() / 2;
+    let mid_x = coords[mid].x;
+    {
+        let (left, right) = coords.split_at_mut(mid);
+        closest_in(left, best, buffer);
+        closest_in(right, best, buffer);
+    }
+
+    // Merge the halves, which are now sorted by `y`
+    buffer.clear();
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < coords.len() {
+        if coords[j].y < coords[i].y {
+            buffer.push(coords[j]);
+            j += 1;
+        } else {
+            buffer.push(coords[i]);
+            i += 1;
+        }
+    }
+    buffer.extend_from_slice(&coords[i..mid]);
+    buffer.extend_from_slice(&coords[j..]);
+    coords.copy_from_slice(buffer);
+
+    // Only pairs straddling the split and within `best.distance` of it can
+    // be closer. Visiting these by `y`, each only needs comparing with a
+    // bounded number of the preceding ones.
+    buffer.clear();
+    for &coord in coords.iter() {
+        if (coord.x - mid_x).abs() >= best.distance {
+            continue;
+        }
+        for &other in buffer.iter().rev() {
+            if coord.y - other.y >= best.distance {
+                break;
+            }
+            best.update(other, coord);
+        }
+        buffer.push(coord);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::point;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force(points: &MultiPoint<f64>) -> f64 {
+        let mut best = f64::INFINITY;
+        for (idx, a) in points.iter().enumerate() {
+            for b in &points.0[idx + 1..] {
+                best = best.min(a.euclidean_distance(b));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn random_points() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for &count in &[2, 3, 4, 5, 10, 100, 1000] {
+            for _ in 0..10 {
+                let points = MultiPoint(
+                    (0..count)
+                        .map(|_| point!(x: rng.gen_range(-100. ..100.), y: rng.gen_range(-100. ..100.)))
+                        .collect(),
+                );
+                let (a, b, distance) = points.closest_pair().unwrap();
+                assert_eq!(distance, brute_force(&points));
+                assert_eq!(distance, a.euclidean_distance(&b));
+                assert!(points.0.contains(&a) && points.0.contains(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn grid() {
+        // Many equal coordinates, and ties
+        let points = MultiPoint(
+            (0..100)
+                .map(|idx| point!(x: (idx % 10) as f64, y: (idx / 10) as f64 * 1.5))
+                .collect(),
+        );
+        let (_, _, distance) = points.closest_pair().unwrap();
+        assert_eq!(distance, 1.);
+    }
+
+    #[test]
+    fn coincident() {
+        let points = MultiPoint(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 3., y: 4.),
+            point!(x: 10., y: 10.),
+            point!(x: 3., y: 4.),
+            point!(x: -1., y: 6.),
+        ]);
+        assert_eq!(
+            points.closest_pair(),
+            Some((point!(x: 3., y: 4.), point!(x: 3., y: 4.), 0.))
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert_eq!(empty.closest_pair(), None);
+        let single = MultiPoint(vec![point!(x: 1., y: 1.)]);
+        assert_eq!(single.closest_pair(), None);
+        let non_finite = MultiPoint(vec![point!(x: 1., y: 1.), point!(x: f64::NAN, y: 1.)]);
+        assert_eq!(non_finite.closest_pair(), None);
+
+        let pair = MultiPoint(vec![point!(x: 1., y: 1.), point!(x: 4., y: 5.)]);
+        let (_, _, distance) = pair.closest_pair().unwrap();
+        assert_eq!(distance, 5.);
+    }
+}
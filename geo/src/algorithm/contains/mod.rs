@@ -271,6 +271,34 @@ mod test {
         assert!(!multipoly.contains(&Point::new(3., 2.)));
         assert!(!multipoly.contains(&Point::new(7., 2.)));
     }
+    #[test]
+    fn multipolygon_boundary_test() {
+        let poly1 = Polygon::new(
+            LineString::from(vec![(0., 0.), (5., 0.), (5., 5.), (0., 5.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (2., 1.),
+                (2., 2.),
+                (1., 2.),
+                (1., 1.),
+            ])],
+        );
+        let poly2 = Polygon::new(
+            LineString::from(vec![(5., 0.), (9., 0.), (9., 5.), (5., 5.), (5., 0.)]),
+            Vec::new(),
+        );
+        let multipoly = MultiPolygon(vec![poly1, poly2]);
+        // In a member, in a hole, and outside every member
+        assert!(multipoly.contains(&Point::new(3., 3.)));
+        assert!(multipoly.contains(&Point::new(7., 3.)));
+        assert!(!multipoly.contains(&Point::new(1.5, 1.5)));
+        assert!(!multipoly.contains(&Point::new(10., 3.)));
+        // On the boundary of a member, or of a hole, as for `Polygon`
+        assert!(!multipoly.contains(&Point::new(0., 3.)));
+        assert!(!multipoly.contains(&Point::new(2., 1.5)));
+        assert!(!multipoly.contains(&Point::new(5., 3.)));
+        assert!(!multipoly.0[0].contains(&Point::new(5., 3.)));
+    }
     /// Tests: LineString in Polygon
     #[test]
     fn linestring_in_polygon_with_linestring_is_boundary_test() {
@@ -444,6 +472,28 @@ mod test {
         assert!(bounding_rect.contains(&smaller_bounding_rect));
     }
 
+    #[test]
+    fn integer_polygon_contains_point() {
+        let polygon: Polygon<i64> = Polygon::new(
+            LineString::from(vec![(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)]),
+            vec![LineString::from(vec![
+                (3, 3),
+                (6, 3),
+                (6, 6),
+                (3, 6),
+                (3, 3),
+            ])],
+        );
+        assert!(polygon.contains(&Point::new(1, 1)));
+        assert!(polygon.contains(&Point::new(7, 5)));
+        // On the boundary, or in the hole
+        assert!(!polygon.contains(&Point::new(10, 5)));
+        assert!(!polygon.contains(&Point::new(3, 4)));
+        assert!(!polygon.contains(&Point::new(4, 4)));
+        let multi_polygon = MultiPolygon(vec![polygon]);
+        assert!(multi_polygon.contains(&Point::new(9, 9)));
+    }
+
     #[test]
     fn triangle_not_contains_point_on_edge() {
         let t = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
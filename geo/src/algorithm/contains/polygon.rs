@@ -77,6 +77,14 @@ where
 // │ Implementations for MultiPolygon │
 // └──────────────────────────────────┘
 // TODO: ensure DE-9IM compliance
+
+/// A `MultiPolygon` contains a geometry if any of its members does.
+///
+/// For a `Point` or `Coordinate`, this is a point-in-polygon test of each
+/// member in turn: members whose bounding rect doesn't contain the point are
+/// skipped, and the others are tested by ray casting against their exterior
+/// and interiors. As with `Polygon`, a point on the boundary of a member
+/// (including the boundary of a hole) is not contained by it.
 impl<G, T> Contains<G> for MultiPolygon<T>
 where
     T: CoordNum,
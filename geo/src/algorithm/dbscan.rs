@@ -0,0 +1,223 @@
+use crate::{GeoFloat, MultiPoint, Point};
+use rstar::primitives::PointWithData;
+use rstar::{RTree, RTreeNum};
+
+/// Cluster the points of a `MultiPoint` by density, using the DBSCAN
+/// algorithm.
+///
+/// A point is a _core_ point if at least `min_points` points (including
+/// itself) lie within a (Euclidean) distance of `eps` of it. Core points
+/// within `eps` of each other are in the same cluster, along with the
+/// other points within `eps` of them, at its edge. The points which aren't
+/// close enough to any core point are _noise_.
+///
+/// The neighbours of each point are found with an R-tree, so this takes
+/// `O(n log n)` time for points which are spread out relative to `eps`.
+///
+/// The points are identified by their indices in the `MultiPoint`. The
+/// indices in each cluster are in ascending order, and the clusters are in
+/// the order of their first index. An edge point within `eps` of core
+/// points in two clusters is put in the one found first. Points with `NaN`
+/// or infinite coordinates are always noise.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dbscan::Dbscan;
+/// use geo::MultiPoint;
+///
+/// let points: MultiPoint<f64> = vec![
+///     (0., 0.),
+///     (1., 0.),
+///     (0., 1.),
+///     (10., 10.),
+///     (10., 11.),
+///     (11., 11.),
+///     (5., 5.),
+/// ]
+/// .into();
+///
+/// let clusters = points.dbscan(1.5, 3);
+///
+/// assert_eq!(clusters.clusters, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+/// assert_eq!(clusters.noise, vec![6]);
+/// ```
+pub trait Dbscan<T: GeoFloat> {
+    fn dbscan(&self, eps: T, min_points: usize) -> DbscanClusters;
+}
+
+/// The clusters found by [`Dbscan`], as the indices of their points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbscanClusters {
+    pub clusters: Vec<Vec<usize>>,
+    /// The points which aren't in any cluster
+    pub noise: Vec<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Label {
+    Unvisited,
+    Noise,
+    Cluster(usize),
+}
+
+impl<T> Dbscan<T> for MultiPoint<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn dbscan(&self, eps: T, min_points: usize) -> DbscanClusters {
+        let tree: RTree<PointWithData<usize, Point<T>>> = RTree::bulk_load(
+            self.iter()
+                .enumerate()
+                .filter(|(_, point)| point.x().is_finite() && point.y().is_finite())
+                .map(|(index, &point)| PointWithData::new(index, point))
+                .collect(),
+        );
+        let eps_squared = if eps >= T::zero() {
+            eps * eps
+        } else {
+            T::zero()
+        };
+        let neighbours = |index: usize| -> Vec<usize> {
+            tree.locate_within_distance(self.0[index], eps_squared)
+                .map(|neighbour| neighbour.data)
+                .collect()
+        };
+
+        let mut labels = vec![Label::Unvisited; self.0.len()];
+        let mut num_clusters = 0;
+        for index in 0..self.0.len() {
+            if labels[index] != Label::Unvisited {
+                continue;
+            }
+            let mut queue = neighbours(index);
+            // Points which aren't in the tree have no neighbours at all
+            if queue.is_empty() || queue.len() < min_points {
+                labels[index] = Label::Noise;
+                continue;
+            }
+
+            let cluster = Label::Cluster(num_clusters);
+            num_clusters += 1;
+            labels[index] = cluster;
+            while let Some(neighbour) = queue.pop() {
+                match labels[neighbour] {
+                    Label::Unvisited => {}
+                    // An edge point, which can't extend the cluster
+                    Label::Noise => {
+                        labels[neighbour] = cluster;
+                        continue;
+                    }
+                    Label::Cluster(_) => continue,
+                }
+                labels[neighbour] = cluster;
+                let next = neighbours(neighbour);
+                if next.len() >= min_points {
+                    queue.extend(next);
+                }
+            }
+        }
+
+        let mut clusters = vec![vec![]; num_clusters];
+        let mut noise = vec![];
+        for (index, label) in labels.into_iter().enumerate() {
+            match label {
+                Label::Cluster(cluster) => clusters[cluster].push(index),
+                _ => noise.push(index),
+            }
+        }
+        DbscanClusters { clusters, noise }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn blobs_and_noise() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut blob = |center: (f64, f64), count: usize| -> Vec<Point<f64>> {
+            (0..count)
+                .map(|_| {
+                    point!(
+                        x: center.0 + rng.gen_range(-1.0..1.0),
+                        y: center.1 + rng.gen_range(-1.0..1.0)
+                    )
+                })
+                .collect()
+        };
+        let mut points = blob((0., 0.), 50);
+        points.extend(blob((20., 20.), 30));
+        // Scattered well away from the blobs and each other
+        points.extend(vec![
+            point!(x: 10., y: 0.),
+            point!(x: 0., y: 10.),
+            point!(x: 10., y: 10.),
+            point!(x: -10., y: 30.),
+        ]);
+        let points = MultiPoint(points);
+
+        let clusters = points.dbscan(1.0, 4);
+        assert_eq!(
+            clusters,
+            DbscanClusters {
+                clusters: vec![(0..50).collect(), (50..80).collect()],
+                noise: (80..84).collect(),
+            }
+        );
+
+        // Too sparse for any clusters
+        let clusters = points.dbscan(1.0, 100);
+        assert!(clusters.clusters.is_empty());
+        assert_eq!(clusters.noise.len(), 84);
+    }
+
+    #[test]
+    fn chains_and_edges() {
+        // A chain of core points, spaced 1 apart, with an edge point at the
+        // end which is only near one of them
+        let points: MultiPoint<f64> = vec![
+            (0., 0.),
+            (1., 0.),
+            (2., 0.),
+            (3., 0.),
+            (4., 0.),
+            (5.4, 0.),
+            (-5., 0.),
+        ]
+        .into();
+        let clusters = points.dbscan(1.5, 3);
+        assert_eq!(clusters.clusters, vec![vec![0, 1, 2, 3, 4, 5]]);
+        assert_eq!(clusters.noise, vec![6]);
+
+        // The edge point is found before the core point it is near
+        let points: MultiPoint<f64> =
+            vec![(5.4, 0.), (0., 0.), (1., 0.), (2., 0.), (3., 0.), (4., 0.)].into();
+        assert_eq!(points.dbscan(1.5, 3).clusters, vec![vec![0, 1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert_eq!(
+            empty.dbscan(1., 2),
+            DbscanClusters {
+                clusters: vec![],
+                noise: vec![],
+            }
+        );
+
+        let points: MultiPoint<f64> = vec![(0., 0.), (0., 0.), (f64::NAN, 0.), (1., 0.)].into();
+        // Coincident points are within any distance of each other
+        let clusters = points.dbscan(0., 2);
+        assert_eq!(clusters.clusters, vec![vec![0, 1]]);
+        assert_eq!(clusters.noise, vec![2, 3]);
+        // Every point is a cluster on its own, except the invalid one
+        let clusters = points.dbscan(-1., 1);
+        assert_eq!(clusters.clusters, vec![vec![0, 1], vec![3]]);
+        assert_eq!(clusters.noise, vec![2]);
+    }
+}
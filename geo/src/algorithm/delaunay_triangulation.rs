@@ -0,0 +1,294 @@
+use crate::kernels::*;
+use crate::{Coordinate, GeoFloat, MultiPoint, OrderedCoord, Triangle};
+use num_traits::NumCast;
+use std::collections::HashMap;
+
+/// Compute the [Delaunay triangulation] of a set of points.
+///
+/// The triangles are oriented counter-clockwise, and no input point lies
+/// strictly inside the circumcircle of any triangle. Their union is the
+/// convex hull of the points. Where four or more points are cocircular,
+/// any one of the valid triangulations is returned.
+///
+/// Duplicate points are only used once, and points with `NaN` or infinite
+/// coordinates are ignored. If there are fewer than three distinct points,
+/// or if they are all collinear, an empty `Vec` is returned.
+///
+/// The points are inserted in sorted order, and the triangulation is then
+/// made Delaunay by edge flips. This takes `O(n²)` time in the worst
+/// case.
+///
+/// [Delaunay triangulation]: https://en.wikipedia.org/wiki/Delaunay_triangulation
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::delaunay_triangulation::DelaunayTriangulation;
+/// use geo::{point, MultiPoint};
+///
+/// let points = MultiPoint(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 2., y: 0.),
+///     point!(x: 1., y: 1.),
+///     point!(x: 1., y: 3.),
+/// ]);
+///
+/// let triangles = points.delaunay_triangulation();
+/// assert_eq!(triangles.len(), 3);
+/// ```
+pub trait DelaunayTriangulation<T: GeoFloat> {
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>>;
+}
+
+impl<T: GeoFloat> DelaunayTriangulation<T> for MultiPoint<T> {
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        let mut coords: Vec<OrderedCoord<T>> = self
+            .iter()
+            .filter(|point| point.x().is_finite() && point.y().is_finite())
+            .map(|&point| point.into())
+            .collect();
+        coords.sort();
+        coords.dedup();
+        let coords: Vec<Coordinate<T>> = coords.into_iter().map(OrderedCoord::coord).collect();
+
+        let mut triangles = sweep(&coords);
+        flip_edges(&coords, &mut triangles);
+        triangles
+            .into_iter()
+            .map(|[a, b, c]| Triangle(coords[a], coords[b], coords[c]))
+            .collect()
+    }
+}
+
+/// Triangulate the convex hull of sorted, distinct, coordinates, by
+/// connecting each of them to the visible edges of the hull of the
+/// previous ones. The triangles are given by the indices of their
+/// vertices, in counter-clockwise order.
+fn sweep<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<[usize; 3]> {
+    let mut triangles = vec![];
+    if coords.len() < 3 {
+        return triangles;
+    }
+
+    // Fan out from the first point which isn't collinear with the ones
+    // before it.
+    let (first, orientation) = match (2..coords.len())
+        .map(|idx| (idx, T::Ker::orient2d(coords[0], coords[1], coords[idx])))
+        .find(|&(_, orientation)| orientation != Orientation::Collinear)
+    {
+        Some(found) => found,
+        None => return triangles,
+    };
+    // The hull, in counter-clockwise order
+    let mut hull: Vec<usize> = (0..=first).collect();
+    for idx in 0..first - 1 {
+        if orientation == Orientation::CounterClockwise {
+            triangles.push([idx, idx + 1, first]);
+        } else {
+            triangles.push([idx + 1, idx, first]);
+        }
+    }
+    if orientation == Orientation::Clockwise {
+        hull.reverse();
+    }
+
+    for idx in first + 1..coords.len() {
+        // The point lies outside the hull, so the edges it sees form a
+        // single non-empty chain. Rotate the hull so that it starts with
+        // this chain.
+        let start = (0..hull.len())
+            .find(|&edge| {
+                is_visible(coords, &hull, edge, idx)
+                    && !is_visible(coords, &hull, (edge + hull.len() - 1) % hull.len(), idx)
+            })
+            .unwrap();
+        hull.rotate_left(start);
+        let visible = (0..hull.len())
+            .take_while(|&edge| is_visible(coords, &hull, edge, idx))
+            .count();
+
+        for edge in 0..visible {
+            triangles.push([hull[edge + 1], hull[edge], idx]);
+        }
+        hull.splice(1..visible, std::iter::once(idx));
+    }
+    triangles
+}
+
+/// Whether the point at `idx` lies strictly to the right of the `edge`th
+/// edge of `hull`.
+fn is_visible<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    hull: &[usize],
+    edge: usize,
+    idx: usize,
+) -> bool {
+    let start = coords[hull[edge]];
+    let end = coords[hull[(edge + 1) % hull.len()]];
+    T::Ker::orient2d(start, end, coords[idx]) == Orientation::Clockwise
+}
+
+/// Flip the edges of a triangulation until it is Delaunay.
+fn flip_edges<T: GeoFloat>(coords: &[Coordinate<T>], triangles: &mut [[usize; 3]]) {
+    // The triangle to the left of each directed edge
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    for (idx, triangle) in triangles.iter().enumerate() {
+        for edge in triangle_edges(triangle) {
+            edges.insert(edge, idx);
+        }
+    }
+
+    let mut pending: Vec<(usize, usize)> = edges.keys().copied().collect();
+    while let Some((a, b)) = pending.pop() {
+        let (left, right) = match (edges.get(&(a, b)), edges.get(&(b, a))) {
+            (Some(&left), Some(&right)) => (left, right),
+            _ => continue,
+        };
+        let c = opposite(&triangles[left], a, b);
+        let d = opposite(&triangles[right], b, a);
+        if !in_circle(coords[a], coords[b], coords[c], coords[d]) {
+            continue;
+        }
+
+        for edge in triangle_edges(&triangles[left])
+            .iter()
+            .chain(triangle_edges(&triangles[right]).iter())
+        {
+            edges.remove(edge);
+        }
+        triangles[left] = [a, d, c];
+        triangles[right] = [d, b, c];
+        for &idx in &[left, right] {
+            for edge in triangle_edges(&triangles[idx]) {
+                edges.insert(edge, idx);
+            }
+        }
+        pending.extend_from_slice(&[(a, d), (d, b), (b, c), (c, a)]);
+    }
+}
+
+fn triangle_edges(triangle: &[usize; 3]) -> [(usize, usize); 3] {
+    let [a, b, c] = *triangle;
+    [(a, b), (b, c), (c, a)]
+}
+
+/// The vertex of `triangle` which isn't on the edge from `a` to `b`.
+fn opposite(triangle: &[usize; 3], a: usize, b: usize) -> usize {
+    *triangle
+        .iter()
+        .find(|&&vertex| vertex != a && vertex != b)
+        .unwrap()
+}
+
+/// Whether `d` lies strictly inside the circumcircle of the
+/// counter-clockwise triangle `a`, `b`, `c`.
+fn in_circle<T: GeoFloat>(
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    c: Coordinate<T>,
+    d: Coordinate<T>,
+) -> bool {
+    let coord = |coord: Coordinate<T>| ::robust::Coord {
+        x: <f64 as NumCast>::from(coord.x).unwrap(),
+        y: <f64 as NumCast>::from(coord.y).unwrap(),
+    };
+    ::robust::incircle(coord(a), coord(b), coord(c), coord(d)) > 0.
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{point, Point};
+
+    fn assert_delaunay(points: &MultiPoint<f64>, triangles: &[Triangle<f64>]) {
+        for triangle in triangles {
+            assert!(triangle.signed_area() > 0.);
+            for point in points {
+                assert!(!in_circle(triangle.0, triangle.1, triangle.2, point.0));
+            }
+        }
+    }
+
+    #[test]
+    fn square() {
+        let points = MultiPoint(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 1., y: 1.),
+            point!(x: 0., y: 1.),
+        ]);
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 2);
+        let area: f64 = triangles.iter().map(|t| t.signed_area()).sum();
+        assert_relative_eq!(area, 1.);
+        assert_delaunay(&points, &triangles);
+    }
+
+    #[test]
+    fn empty_circumcircles() {
+        let points: MultiPoint<f64> = vec![
+            (0., 0.),
+            (4., 0.),
+            (8., 1.),
+            (1., 3.),
+            (3., 2.),
+            (6., 4.),
+            (2., 7.),
+            (5., 6.),
+            (7., 8.),
+            (4., 4.),
+        ]
+        .into();
+        let triangles = points.delaunay_triangulation();
+        assert_delaunay(&points, &triangles);
+
+        // With `h` points on the hull, there are `2n - h - 2` triangles
+        assert_eq!(triangles.len(), 2 * 10 - 5 - 2);
+    }
+
+    #[test]
+    fn duplicates() {
+        let points = MultiPoint(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 1., y: 0.),
+            point!(x: -0., y: 1.),
+        ]);
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 1);
+        assert_delaunay(&points, &triangles);
+    }
+
+    #[test]
+    fn collinear() {
+        let points: MultiPoint<f64> = (0..5)
+            .map(|i| Point::new(i as f64, 2. * i as f64))
+            .collect();
+        assert!(points.delaunay_triangulation().is_empty());
+
+        // A single point off the line is connected to each segment of it
+        let mut points = points;
+        points.0.push(point!(x: 0., y: 5.));
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 4);
+        assert_delaunay(&points, &triangles);
+
+        let few = MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]);
+        assert!(few.delaunay_triangulation().is_empty());
+    }
+
+    #[test]
+    fn cocircular_grid() {
+        let points: MultiPoint<f64> = (0..100)
+            .map(|i| Point::new((i % 10) as f64, (i / 10) as f64))
+            .collect();
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 2 * 9 * 9);
+        let area: f64 = triangles.iter().map(|t| t.signed_area()).sum();
+        assert_relative_eq!(area, 81.);
+        assert_delaunay(&points, &triangles);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::algorithm::convex_hull::quick_hull;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{Coordinate, GeoFloat, Point};
+
+/// Find the two farthest apart coordinates of a geometry, and the
+/// (Euclidean) distance between them: its diameter.
+///
+/// The farthest pair are both vertices of the convex hull, so the hull is
+/// found first, and then its antipodal pairs of vertices are checked with
+/// the rotating calipers method, taking `O(n log n)` time overall. If
+/// several pairs are equally far apart, it is unspecified which one is
+/// returned, as is the order of the two points of the pair.
+///
+/// `None` is returned for an empty geometry, and a geometry with a single
+/// coordinate, or with all of its coordinates equal, has that coordinate
+/// as both points of the pair, with a distance of zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::diameter::Diameter;
+/// use geo::{point, polygon};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 3.),
+///     (x: 1., y: 1.),
+/// ];
+///
+/// let (a, b, distance) = polygon.diameter().unwrap();
+/// assert_eq!(distance, 5.);
+/// assert!(a == point!(x: 0., y: 0.) || b == point!(x: 0., y: 0.));
+/// assert!(a == point!(x: 4., y: 3.) || b == point!(x: 4., y: 3.));
+/// ```
+pub trait Diameter<T: GeoFloat> {
+    fn diameter(&self) -> Option<(Point<T>, Point<T>, T)>;
+}
+
+impl<T, G> Diameter<T> for G
+where
+    T: GeoFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn diameter(&self) -> Option<(Point<T>, Point<T>, T)> {
+        let mut coords: Vec<Coordinate<T>> = self.coords_iter().collect();
+        let first = *coords.first()?;
+        let mut hull = quick_hull(&mut coords).0;
+        // The hull is closed, and counter-clockwise
+        hull.pop();
+
+        let mut best = Pair::new(first, first);
+        let n = hull.len();
+        if n < 4 {
+            for (idx, &a) in hull.iter().enumerate() {
+                for &b in &hull[idx + 1..] {
+                    best.update(a, b);
+                }
+            }
+            return Some(best.into());
+        }
+
+        let edge = |idx: usize| hull[(idx + 1) % n] - hull[idx % n];
+        let cross = |a: Coordinate<T>, b: Coordinate<T>| a.x * b.y - a.y * b.x;
+        // For each edge, move the opposite vertex on while the next edge
+        // still turns towards it; the vertices of both edges are then
+        // antipodal to it
+        let mut j = 1;
+        for i in 0..n {
+            let (start, end) = (hull[i], hull[(i + 1) % n]);
+            while j < i + n && cross(edge(i), edge(j)) > T::zero() {
+                j += 1;
+            }
+            let opposite = hull[j % n];
+            best.update(start, opposite);
+            best.update(end, opposite);
+            // Where the edges are parallel, the next vertex is as well
+            if cross(edge(i), edge(j)) == T::zero() {
+                let next = hull[(j + 1) % n];
+                best.update(start, next);
+                best.update(end, next);
+            }
+        }
+        Some(best.into())
+    }
+}
+
+struct Pair<T: GeoFloat> {
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+    distance: T,
+}
+
+impl<T: GeoFloat> Pair<T> {
+    fn new(a: Coordinate<T>, b: Coordinate<T>) -> Self {
+        let delta = b - a;
+        Pair {
+            a,
+            b,
+            distance: delta.x.hypot(delta.y),
+        }
+    }
+
+    fn update(&mut self, a: Coordinate<T>, b: Coordinate<T>) {
+        let candidate = Pair::new(a, b);
+        if candidate.distance > self.distance {
+            *self = candidate;
+        }
+    }
+}
+
+impl<T: GeoFloat> From<Pair<T>> for (Point<T>, Point<T>, T) {
+    fn from(pair: Pair<T>) -> Self {
+        (pair.a.into(), pair.b.into(), pair.distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::{point, polygon, MultiPoint, Rect};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force(points: &MultiPoint<f64>) -> f64 {
+        let mut best = 0.;
+        for (idx, a) in points.iter().enumerate() {
+            for b in &points.0[idx + 1..] {
+                best = a.euclidean_distance(b).max(best);
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn random_points() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for &count in &[1, 2, 3, 4, 5, 10, 100, 1000] {
+            for _ in 0..10 {
+                let points = MultiPoint(
+                    (0..count)
+                        .map(|_| point!(x: rng.gen_range(-100. ..100.), y: rng.gen_range(-100. ..100.)))
+                        .collect(),
+                );
+                let (a, b, distance) = points.diameter().unwrap();
+                assert_eq!(distance, brute_force(&points));
+                assert_eq!(distance, a.euclidean_distance(&b));
+                assert!(points.0.contains(&a) && points.0.contains(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn rectangle() {
+        let rect = Rect::new(Coordinate { x: 1., y: 2. }, Coordinate { x: 4., y: 6. });
+        let (a, b, distance) = rect.diameter().unwrap();
+        assert_eq!(distance, 5.);
+        assert_eq!(a.euclidean_distance(&b), 5.);
+
+        // An octagon, with parallel edges and several equally far pairs
+        let octagon = polygon![
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 3., y: 1.),
+            (x: 3., y: 2.),
+            (x: 2., y: 3.),
+            (x: 1., y: 3.),
+            (x: 0., y: 2.),
+            (x: 0., y: 1.),
+        ];
+        let (_, _, distance) = octagon.diameter().unwrap();
+        assert_relative_eq!(distance, 10f64.sqrt());
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert_eq!(empty.diameter(), None);
+
+        let single = point!(x: 3., y: -1.);
+        assert_eq!(single.diameter(), Some((single, single, 0.)));
+
+        let repeated = MultiPoint(vec![single; 5]);
+        assert_eq!(repeated.diameter(), Some((single, single, 0.)));
+
+        // All on a line
+        let collinear = MultiPoint(
+            (0..10)
+                .map(|idx| point!(x: (idx * 7 % 10) as f64, y: (idx * 7 % 10) as f64 * 2.))
+                .collect(),
+        );
+        let (_, _, distance) = collinear.diameter().unwrap();
+        assert_relative_eq!(distance, 405f64.sqrt());
+    }
+}
@@ -0,0 +1,409 @@
+use std::mem::discriminant;
+
+use crate::{
+    CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// The changes which turn one `Geometry` into another, as found by
+/// [`Diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeometryDiff<T: CoordNum> {
+    /// Edits to the coordinates of the geometry, to be applied in order.
+    /// There are none if the geometries are equal.
+    Edit(Vec<CoordinateEdit<T>>),
+    /// The geometries have different structures, so the new one replaces
+    /// the old one as a whole.
+    Replace(Geometry<T>),
+}
+
+/// A change to one coordinate of a `Geometry`, within one of its parts.
+///
+/// The parts of a geometry are its sequences of coordinates, in order: the
+/// single coordinate of a `Point`, the ends of a `Line`, the coordinates
+/// of a `LineString`, the exterior and then each interior ring of a
+/// `Polygon`, the minimum and maximum of a `Rect`, and the vertices of a
+/// `Triangle`. The parts of a multi-geometry or `GeometryCollection` are
+/// those of each of its members in turn.
+///
+/// `index` is the position in the part at the time the edit is applied,
+/// after the edits before it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoordinateEdit<T: CoordNum> {
+    /// The coordinate at `index` moves to `to`
+    Move {
+        part: usize,
+        index: usize,
+        to: Coordinate<T>,
+    },
+    /// `coord` is inserted at `index`, before the coordinate there
+    Insert {
+        part: usize,
+        index: usize,
+        coord: Coordinate<T>,
+    },
+    /// The coordinate at `index` is removed
+    Remove { part: usize, index: usize },
+}
+
+impl<T: CoordNum> GeometryDiff<T> {
+    /// Whether there are no changes.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, GeometryDiff::Edit(edits) if edits.is_empty())
+    }
+
+    /// Apply the changes to `geometry`, which should be the geometry the
+    /// diff was made from.
+    ///
+    /// # Panics
+    ///
+    /// May panic if an edit refers to a part or index which `geometry`
+    /// doesn't have.
+    pub fn apply(&self, geometry: &Geometry<T>) -> Geometry<T> {
+        let edits = match self {
+            GeometryDiff::Edit(edits) => edits,
+            GeometryDiff::Replace(replacement) => return replacement.clone(),
+        };
+        let mut parts = vec![];
+        push_parts(geometry, &mut parts);
+        for edit in edits {
+            match *edit {
+                CoordinateEdit::Move { part, index, to } => parts[part][index] = to,
+                CoordinateEdit::Insert { part, index, coord } => parts[part].insert(index, coord),
+                CoordinateEdit::Remove { part, index } => {
+                    parts[part].remove(index);
+                }
+            }
+        }
+        rebuild(geometry, &mut parts.into_iter())
+    }
+}
+
+/// Find the changes between two geometries, such as to send only those to
+/// a client which already has the first one.
+///
+/// Geometries with the same structure, that is of the same type, with the
+/// same numbers of members and rings, are compared part by part. Within
+/// each part, the coordinates shared at the start and at the end are kept,
+/// and those between are moved, with any extra ones inserted or removed, so
+/// a single moved, inserted or removed vertex is a single edit. Geometries
+/// with different structures are replaced as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::diff::{CoordinateEdit, Diff, GeometryDiff};
+/// use geo::{polygon, Coordinate, Geometry};
+///
+/// let before = Geometry::Polygon(polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+/// ]);
+/// // Dragging a corner
+/// let after = Geometry::Polygon(polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 5., y: 6.),
+///     (x: 0., y: 4.),
+/// ]);
+///
+/// let diff = before.diff(&after);
+/// assert_eq!(
+///     diff,
+///     GeometryDiff::Edit(vec![CoordinateEdit::Move {
+///         part: 0,
+///         index: 2,
+///         to: Coordinate { x: 5., y: 6. },
+///     }])
+/// );
+/// assert_eq!(diff.apply(&before), after);
+/// ```
+pub trait Diff<T: CoordNum> {
+    fn diff(&self, other: &Self) -> GeometryDiff<T>;
+}
+
+impl<T: CoordNum> Diff<T> for Geometry<T> {
+    fn diff(&self, other: &Self) -> GeometryDiff<T> {
+        let (mut old_parts, mut new_parts) = (vec![], vec![]);
+        push_parts(self, &mut old_parts);
+        push_parts(other, &mut new_parts);
+        if !same_structure(self, other) || old_parts.len() != new_parts.len() {
+            return GeometryDiff::Replace(other.clone());
+        }
+
+        let mut edits = vec![];
+        for (part, (old, new)) in old_parts.iter().zip(&new_parts).enumerate() {
+            diff_part(part, old, new, &mut edits);
+        }
+        GeometryDiff::Edit(edits)
+    }
+}
+
+fn diff_part<T: CoordNum>(
+    part: usize,
+    old: &[Coordinate<T>],
+    new: &[Coordinate<T>],
+    edits: &mut Vec<CoordinateEdit<T>>,
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let (old, new) = (&old[prefix..], &new[prefix..]);
+    let suffix = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old, new) = (&old[..old.len() - suffix], &new[..new.len() - suffix]);
+
+    let common = old.len().min(new.len());
+    for (offset, (a, b)) in old.iter().zip(new).enumerate() {
+        if a != b {
+            edits.push(CoordinateEdit::Move {
+                part,
+                index: prefix + offset,
+                to: *b,
+            });
+        }
+    }
+    for (offset, &coord) in new[common..].iter().enumerate() {
+        edits.push(CoordinateEdit::Insert {
+            part,
+            index: prefix + common + offset,
+            coord,
+        });
+    }
+    for _ in common..old.len() {
+        edits.push(CoordinateEdit::Remove {
+            part,
+            index: prefix + common,
+        });
+    }
+}
+
+fn same_structure<T: CoordNum>(a: &Geometry<T>, b: &Geometry<T>) -> bool {
+    match (a, b) {
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => same_polygon_structure(a, b),
+        (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => {
+            a.0.len() == b.0.len() && a.iter().zip(b).all(|(a, b)| same_polygon_structure(a, b))
+        }
+        (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+            a.0.len() == b.0.len() && a.iter().zip(b).all(|(a, b)| same_structure(a, b))
+        }
+        // The number of parts is compared separately
+        _ => discriminant(a) == discriminant(b),
+    }
+}
+
+fn same_polygon_structure<T: CoordNum>(a: &Polygon<T>, b: &Polygon<T>) -> bool {
+    a.interiors().len() == b.interiors().len()
+}
+
+fn push_parts<T: CoordNum>(geometry: &Geometry<T>, parts: &mut Vec<Vec<Coordinate<T>>>) {
+    match geometry {
+        Geometry::Point(g) => parts.push(vec![g.0]),
+        Geometry::Line(g) => parts.push(vec![g.start, g.end]),
+        Geometry::LineString(g) => parts.push(g.0.clone()),
+        Geometry::Polygon(g) => push_polygon_parts(g, parts),
+        Geometry::MultiPoint(g) => parts.extend(g.iter().map(|point| vec![point.0])),
+        Geometry::MultiLineString(g) => parts.extend(g.iter().map(|line| line.0.clone())),
+        Geometry::MultiPolygon(g) => {
+            for polygon in g {
+                push_polygon_parts(polygon, parts);
+            }
+        }
+        Geometry::GeometryCollection(g) => {
+            for geometry in g {
+                push_parts(geometry, parts);
+            }
+        }
+        Geometry::Rect(g) => parts.push(vec![g.min(), g.max()]),
+        Geometry::Triangle(g) => parts.push(vec![g.0, g.1, g.2]),
+    }
+}
+
+fn push_polygon_parts<T: CoordNum>(polygon: &Polygon<T>, parts: &mut Vec<Vec<Coordinate<T>>>) {
+    parts.push(polygon.exterior().0.clone());
+    parts.extend(polygon.interiors().iter().map(|ring| ring.0.clone()));
+}
+
+/// Build a geometry with the structure of `template` from its parts.
+fn rebuild<T: CoordNum>(
+    template: &Geometry<T>,
+    parts: &mut impl Iterator<Item = Vec<Coordinate<T>>>,
+) -> Geometry<T> {
+    match template {
+        Geometry::Point(_) => Point(next_part(parts)[0]).into(),
+        Geometry::Line(_) => {
+            let part = next_part(parts);
+            Line::new(part[0], part[1]).into()
+        }
+        Geometry::LineString(_) => LineString(next_part(parts)).into(),
+        Geometry::Polygon(g) => rebuild_polygon(g, parts).into(),
+        Geometry::MultiPoint(g) => {
+            MultiPoint(g.iter().map(|_| Point(next_part(parts)[0])).collect()).into()
+        }
+        Geometry::MultiLineString(g) => {
+            MultiLineString(g.iter().map(|_| LineString(next_part(parts))).collect()).into()
+        }
+        Geometry::MultiPolygon(g) => MultiPolygon(
+            g.iter()
+                .map(|polygon| rebuild_polygon(polygon, parts))
+                .collect(),
+        )
+        .into(),
+        Geometry::GeometryCollection(g) => Geometry::GeometryCollection(GeometryCollection(
+            g.iter().map(|geometry| rebuild(geometry, parts)).collect(),
+        )),
+        Geometry::Rect(_) => {
+            let part = next_part(parts);
+            Rect::new(part[0], part[1]).into()
+        }
+        Geometry::Triangle(_) => {
+            let part = next_part(parts);
+            Triangle(part[0], part[1], part[2]).into()
+        }
+    }
+}
+
+fn rebuild_polygon<T: CoordNum>(
+    template: &Polygon<T>,
+    parts: &mut impl Iterator<Item = Vec<Coordinate<T>>>,
+) -> Polygon<T> {
+    let exterior = LineString(next_part(parts));
+    let interiors = template
+        .interiors()
+        .iter()
+        .map(|_| LineString(next_part(parts)))
+        .collect();
+    Polygon::new(exterior, interiors)
+}
+
+fn next_part<T: CoordNum>(
+    parts: &mut impl Iterator<Item = Vec<Coordinate<T>>>,
+) -> Vec<Coordinate<T>> {
+    parts
+        .next()
+        .expect("the parts should match the structure of the geometry")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn assert_round_trip(before: &Geometry<f64>, after: &Geometry<f64>) -> GeometryDiff<f64> {
+        let diff = before.diff(after);
+        assert_eq!(&diff.apply(before), after);
+        diff
+    }
+
+    #[test]
+    fn moved_vertex() {
+        let before: Geometry<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 2., y: 0.),
+            (x: 3., y: 1.),
+        ]
+        .into();
+        let after: Geometry<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 2., y: -5.),
+            (x: 3., y: 1.),
+        ]
+        .into();
+        assert_eq!(
+            assert_round_trip(&before, &after),
+            GeometryDiff::Edit(vec![CoordinateEdit::Move {
+                part: 0,
+                index: 2,
+                to: Coordinate { x: 2., y: -5. },
+            }])
+        );
+
+        let diff = before.diff(&before);
+        assert!(diff.is_unchanged());
+        assert_eq!(diff.apply(&before), before);
+    }
+
+    #[test]
+    fn inserted_and_removed() {
+        let donut = |hole: LineString<f64>| -> Geometry<f64> {
+            Polygon::new(
+                line_string![(x: 0., y: 0.), (x: 9., y: 0.), (x: 9., y: 9.), (x: 0., y: 9.)],
+                vec![hole],
+            )
+            .into()
+        };
+        let triangle = donut(line_string![(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.)]);
+        let square =
+            donut(line_string![(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]);
+
+        assert_eq!(
+            assert_round_trip(&triangle, &square),
+            GeometryDiff::Edit(vec![CoordinateEdit::Insert {
+                part: 1,
+                index: 2,
+                coord: Coordinate { x: 2., y: 2. },
+            }])
+        );
+        assert_eq!(
+            assert_round_trip(&square, &triangle),
+            GeometryDiff::Edit(vec![CoordinateEdit::Remove { part: 1, index: 2 }])
+        );
+
+        // Several at once, in a collection
+        let before = Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: 0., y: 0.).into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)].into(),
+        ]));
+        let after = Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: 5., y: 5.).into(),
+            line_string![(x: 0., y: 0.), (x: 7., y: 7.), (x: 3., y: 0.)].into(),
+        ]));
+        assert_round_trip(&before, &after);
+        assert_round_trip(&after, &before);
+    }
+
+    #[test]
+    fn replaced() {
+        // The same number of rings, but in different polygons
+        let square = |x: f64| polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.)];
+        let donut = |x: f64| {
+            Polygon::new(
+                square(x).exterior().clone(),
+                vec![
+                    line_string![(x: x + 0.6, y: 0.2), (x: x + 0.8, y: 0.2), (x: x + 0.8, y: 0.4)],
+                ],
+            )
+        };
+        let before: Geometry<f64> = MultiPolygon(vec![donut(0.), square(2.)]).into();
+        let after: Geometry<f64> = MultiPolygon(vec![square(0.), donut(2.)]).into();
+        assert_eq!(
+            assert_round_trip(&before, &after),
+            GeometryDiff::Replace(after.clone())
+        );
+
+        let line_string: Geometry<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+        let polygon: Geometry<f64> =
+            polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.)].into();
+        assert_eq!(
+            assert_round_trip(&line_string, &polygon),
+            GeometryDiff::Replace(polygon.clone())
+        );
+
+        // The same type, but with another interior
+        let with_hole: Geometry<f64> = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.)],
+            interiors: [[(x: 0.6, y: 0.2), (x: 0.8, y: 0.2), (x: 0.8, y: 0.4)]],
+        )
+        .into();
+        assert_eq!(
+            assert_round_trip(&polygon, &with_hole),
+            GeometryDiff::Replace(with_hole.clone())
+        );
+    }
+}
@@ -40,7 +40,13 @@ pub trait HasDimensions {
     /// Some geometries, like a `MultiPoint`, can have zero coordinates - we call these `empty`.
     ///
     /// Types like `Point` and `Rect`, which have at least one coordinate by construction, can
-    /// never be considered empty.
+    /// never be considered empty. This is the case for `Point`, `Line`, `Rect` and `Triangle`.
+    ///
+    /// A `Polygon` is empty if its exterior is. A multi-geometry or `GeometryCollection` is
+    /// empty if it has no members, or if all of its members are empty. Note that
+    /// `GeometryCollection` also has an inherent `is_empty` method, which only checks whether it
+    /// has no members, and takes precedence in method call syntax: use
+    /// `HasDimensions::is_empty(&collection)` to check whether its members are empty too.
     /// ```
     /// use geo_types::{Point, Coordinate, LineString};
     /// use geo::algorithm::dimensions::HasDimensions;
@@ -442,3 +448,74 @@ impl<C: CoordNum> HasDimensions for Triangle<C> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Coordinate};
+
+    #[test]
+    fn is_empty() {
+        let empty_line_string: LineString<f64> = line_string![];
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert!(empty_line_string.is_empty());
+        assert!(!line_string.is_empty());
+
+        let empty_polygon = Polygon::new(empty_line_string.clone(), vec![]);
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+        assert!(empty_polygon.is_empty());
+        assert!(!polygon.is_empty());
+
+        assert!(MultiPoint::<f64>(vec![]).is_empty());
+        assert!(!MultiPoint(vec![point!(x: 0., y: 0.)]).is_empty());
+
+        assert!(MultiLineString::<f64>(vec![]).is_empty());
+        assert!(MultiLineString(vec![empty_line_string.clone()]).is_empty());
+        assert!(!MultiLineString(vec![empty_line_string.clone(), line_string.clone()]).is_empty());
+
+        assert!(MultiPolygon::<f64>(vec![]).is_empty());
+        assert!(MultiPolygon(vec![empty_polygon.clone()]).is_empty());
+        assert!(!MultiPolygon(vec![empty_polygon.clone(), polygon.clone()]).is_empty());
+
+        // Called through the trait, rather than the inherent method
+        let collection = GeometryCollection::<f64>(vec![]);
+        assert!(HasDimensions::is_empty(&collection));
+        let collection = GeometryCollection(vec![Geometry::LineString(empty_line_string.clone())]);
+        assert!(HasDimensions::is_empty(&collection));
+        assert!(!collection.is_empty());
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(empty_polygon),
+            Geometry::Polygon(polygon),
+        ]);
+        assert!(!HasDimensions::is_empty(&collection));
+
+        assert!(Geometry::LineString(empty_line_string).is_empty());
+        assert!(!Geometry::LineString(line_string).is_empty());
+    }
+
+    #[test]
+    fn never_empty() {
+        let origin = Coordinate { x: 0., y: 0. };
+        assert!(!point!(x: 0., y: 0.).is_empty());
+        assert!(!Line::new(origin, origin).is_empty());
+        assert!(!Rect::new(origin, origin).is_empty());
+        assert!(!Triangle(origin, origin, origin).is_empty());
+        assert!(!Geometry::Rect(Rect::new(origin, origin)).is_empty());
+    }
+
+    #[test]
+    fn defaults_are_empty() {
+        assert!(LineString::<f64>::default().is_empty());
+        assert!(MultiPoint::<f64>::default().is_empty());
+        assert!(MultiLineString::<f64>::default().is_empty());
+        assert!(MultiPolygon::<f64>::default().is_empty());
+        assert!(GeometryCollection::<f64>::default().is_empty());
+        assert!(HasDimensions::is_empty(
+            &GeometryCollection::<f64>::default()
+        ));
+        assert_eq!(
+            MultiPolygon::<i32>::default().dimensions(),
+            Dimensions::Empty
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::algorithm::bounding_rect::BoundingRect;
 use crate::algorithm::contains::Contains;
 use crate::algorithm::euclidean_length::EuclideanLength;
 use crate::algorithm::intersects::Intersects;
@@ -5,12 +6,14 @@ use crate::algorithm::polygon_distance_fast_path::*;
 use crate::utils::{coord_pos_relative_to_ring, CoordPos};
 use crate::{
     Coordinate, GeoFloat, GeoNum, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Point, Polygon, Triangle,
+    Point, Polygon, Rect, Triangle,
 };
 use num_traits::{float::FloatConst, Bounded, Float, Signed};
 
 use rstar::RTree;
 use rstar::RTreeNum;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
 /// Returns the distance between two geometries.
 
@@ -216,15 +219,47 @@ where
     T: GeoFloat,
 {
     /// Minimum distance from a Point to a MultiPolygon
+    ///
+    /// This is zero if any of the polygons contains the point. The polygons
+    /// are checked in order of the distance to their bounding rectangles,
+    /// which can't be more than the distance to the polygons themselves, so
+    /// those further away than the nearest polygon found so far are skipped.
     fn euclidean_distance(&self, mpolygon: &MultiPolygon<T>) -> T {
-        mpolygon
+        let mut candidates: Vec<(T, &Polygon<T>)> = mpolygon
             .0
             .iter()
-            .map(|p| self.euclidean_distance(p))
-            .fold(T::max_value(), |accum, val| accum.min(val))
+            .map(|polygon| {
+                let lower_bound = polygon
+                    .bounding_rect()
+                    .map_or(T::zero(), |rect| point_rect_distance(self.0, rect));
+                (lower_bound, polygon)
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let mut nearest = T::max_value();
+        for (lower_bound, polygon) in candidates {
+            if lower_bound >= nearest {
+                break;
+            }
+            nearest = nearest.min(self.euclidean_distance(polygon));
+            if nearest == T::zero() {
+                break;
+            }
+        }
+        nearest
     }
 }
 
+/// The distance from a coordinate to the nearest point of a `Rect`, which is
+/// zero if the `Rect` contains it.
+fn point_rect_distance<T: GeoFloat>(coord: Coordinate<T>, rect: Rect<T>) -> T {
+    let (min, max) = (rect.min(), rect.max());
+    let dx = (min.x - coord.x).max(coord.x - max.x).max(T::zero());
+    let dy = (min.y - coord.y).max(coord.y - max.y).max(T::zero());
+    dx.hypot(dy)
+}
+
 // ┌────────────────────────────────┐
 // │ Implementations for MultiPoint │
 // └────────────────────────────────┘
@@ -574,6 +609,140 @@ where
         )
 }
 
+/// Find the candidate geometry nearest to `query`, by Euclidean distance.
+///
+/// `candidates` may be a slice, or any other iterator over references to
+/// geometries whose distance from a `Point` can be measured. Each one is
+/// measured in turn, so for many queries against the same candidates, an
+/// [`RTree`] is faster. If several are equally near, the first of them is
+/// returned. Candidates at a distance of `NaN` are ignored.
+///
+/// `None` is returned if there are no candidates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::euclidean_distance::nearest;
+/// use geo::{line_string, point};
+///
+/// let roads = vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+/// ];
+///
+/// let nearest_road = nearest(&point!(x: 3., y: 4.), &roads);
+/// assert_eq!(nearest_road, Some(&roads[1]));
+/// ```
+pub fn nearest<'a, T, G, I>(query: &Point<T>, candidates: I) -> Option<&'a G>
+where
+    T: GeoFloat,
+    G: 'a,
+    Point<T>: EuclideanDistance<T, G>,
+    I: IntoIterator<Item = &'a G>,
+{
+    candidates
+        .into_iter()
+        .map(|candidate| (query.euclidean_distance(candidate), candidate))
+        .filter(|(distance, _)| !distance.is_nan())
+        .fold(None, |nearest, (distance, candidate)| match nearest {
+            Some((nearest_distance, _)) if nearest_distance <= distance => nearest,
+            _ => Some((distance, candidate)),
+        })
+        .map(|(_, candidate)| candidate)
+}
+
+/// Find up to `k` of the candidate geometries nearest to `query`, by
+/// Euclidean distance, nearest first.
+///
+/// As for [`nearest`], each candidate is measured in turn, and candidates
+/// at a distance of `NaN` are ignored. Only the `k` nearest so far are
+/// kept, so this takes `O(n log k)` time for `n` candidates. Equally near
+/// candidates are in the order they were given. If there are fewer than
+/// `k` candidates, all of them are returned.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::euclidean_distance::k_nearest;
+/// use geo::point;
+///
+/// let stations = vec![
+///     point!(x: 0., y: 10.),
+///     point!(x: 1., y: 1.),
+///     point!(x: -3., y: 0.),
+///     point!(x: 0., y: -2.),
+/// ];
+///
+/// let found = k_nearest(&point!(x: 0., y: 0.), &stations, 2);
+/// assert_eq!(found, vec![&stations[1], &stations[3]]);
+/// ```
+pub fn k_nearest<'a, T, G, I>(query: &Point<T>, candidates: I, k: usize) -> Vec<&'a G>
+where
+    T: GeoFloat,
+    G: 'a,
+    Point<T>: EuclideanDistance<T, G>,
+    I: IntoIterator<Item = &'a G>,
+{
+    if k == 0 {
+        return vec![];
+    }
+    let mut heap: BinaryHeap<Neighbour<'a, T, G>> = BinaryHeap::new();
+    for (order, candidate) in candidates.into_iter().enumerate() {
+        let distance = query.euclidean_distance(candidate);
+        if distance.is_nan() {
+            continue;
+        }
+        let neighbour = Neighbour {
+            distance,
+            order,
+            candidate,
+        };
+        if heap.len() < k {
+            heap.push(neighbour);
+        } else if let Some(mut farthest) = heap.peek_mut() {
+            if neighbour < *farthest {
+                *farthest = neighbour;
+            }
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|neighbour| neighbour.candidate)
+        .collect()
+}
+
+/// A candidate of `k_nearest`, ordered by its distance, and then by its
+/// position among the candidates, so that the heap pops the farthest.
+struct Neighbour<'a, T, G> {
+    distance: T,
+    order: usize,
+    candidate: &'a G,
+}
+
+impl<'a, T: GeoFloat, G> Ord for Neighbour<'a, T, G> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Distances are never NaN
+        self.distance
+            .partial_cmp(&other.distance)
+            .unwrap()
+            .then(self.order.cmp(&other.order))
+    }
+}
+
+impl<'a, T: GeoFloat, G> PartialOrd for Neighbour<'a, T, G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, T: GeoFloat, G> PartialEq for Neighbour<'a, T, G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, T: GeoFloat, G> Eq for Neighbour<'a, T, G> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -776,6 +945,36 @@ mod test {
         assert_relative_eq!(p.euclidean_distance(&mp), 60.959002616512684);
     }
     #[test]
+    fn point_distance_multipolygon_members_test() {
+        let square = |x: f64, y: f64, size: f64| {
+            polygon![
+                (x: x, y: y),
+                (x: x + size, y: y),
+                (x: x + size, y: y + size),
+                (x: x, y: y + size),
+            ]
+        };
+        let donut = Polygon::new(
+            square(20., 0., 10.).exterior().clone(),
+            vec![square(22., 2., 6.).exterior().clone()],
+        );
+        let mp = MultiPolygon(vec![square(0., 0., 4.), square(100., 100., 1.), donut]);
+
+        // Inside a member
+        assert_eq!(Point::new(1.0, 2.0).euclidean_distance(&mp), 0.);
+        assert_eq!(Point::new(100.5, 100.5).euclidean_distance(&mp), 0.);
+        // Outside all of them, nearest to the first
+        assert_relative_eq!(Point::new(7.0, 8.0).euclidean_distance(&mp), 5.);
+        // Just below the second member, far from the others
+        assert_relative_eq!(Point::new(100.5, 98.).euclidean_distance(&mp), 2.);
+        // In the hole of the donut, nearest its inner ring
+        assert_relative_eq!(Point::new(24.0, 5.0).euclidean_distance(&mp), 2.);
+        assert_relative_eq!(Point::new(25.0, 5.0).euclidean_distance(&mp), 3.);
+
+        let empty: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert_eq!(Point::new(1.0, 2.0).euclidean_distance(&empty), f64::MAX);
+    }
+    #[test]
     // Point to LineString
     fn point_linestring_distance_test() {
         // like an octagon, but missing the lowest horizontal segment
@@ -1134,4 +1333,85 @@ mod test {
         let point = Point::new(1.0, 0.5);
         assert_relative_eq!(triangle.euclidean_distance(&point), 0.0);
     }
+
+    #[test]
+    fn nearest_test() {
+        let query = Point::new(0., 0.);
+        let points = vec![
+            Point::new(3., 4.),
+            Point::new(-1., 2.),
+            Point::new(2., -2.),
+            Point::new(-2., 1.),
+            Point::new(10., 0.),
+        ];
+        let found = nearest(&query, &points).unwrap();
+        // The first of the two at a distance of √5
+        assert!(std::ptr::eq(found, &points[1]));
+        for point in &points {
+            assert!(query.euclidean_distance(found) <= query.euclidean_distance(point));
+        }
+
+        let lines = vec![
+            LineString::from(vec![(5., -5.), (5., 5.)]),
+            LineString::from(vec![(-3., 1.), (3., 1.), (3., 10.)]),
+            LineString::from(vec![(-4., -4.), (-4., 4.)]),
+        ];
+        assert!(std::ptr::eq(nearest(&query, &lines).unwrap(), &lines[1]));
+        // Any iterator of references will do
+        assert!(std::ptr::eq(
+            nearest(&query, lines.iter().filter(|line| line.0.len() == 2)).unwrap(),
+            &lines[2]
+        ));
+
+        let none: Vec<Polygon<f64>> = vec![];
+        assert!(nearest(&query, &none).is_none());
+    }
+
+    #[test]
+    fn k_nearest_test() {
+        let query = Point::new(0., 0.);
+        let points = vec![
+            Point::new(3., 4.),
+            Point::new(-1., 2.),
+            Point::new(2., -2.),
+            Point::new(-2., 1.),
+            Point::new(10., 0.),
+            Point::new(f64::NAN, 0.),
+            Point::new(0., 0.5),
+        ];
+        let found = k_nearest(&query, &points, 4);
+        assert_eq!(found.len(), 4);
+        // Ties stay in their original order
+        let expected = [&points[6], &points[1], &points[3], &points[2]];
+        for (found, expected) in found.iter().zip(&expected) {
+            assert!(std::ptr::eq(*found, *expected));
+        }
+        assert!(std::ptr::eq(
+            k_nearest(&query, &points, 1)[0],
+            nearest(&query, &points).unwrap()
+        ));
+        assert!(k_nearest(&query, &points, 0).is_empty());
+
+        // Fewer than k, leaving out NaN
+        let found = k_nearest(&query, &points, 10);
+        assert_eq!(found.len(), 6);
+        let distances: Vec<f64> = found
+            .iter()
+            .map(|point| query.euclidean_distance(*point))
+            .collect();
+        assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(std::ptr::eq(found[5], &points[4]));
+
+        let lines = [
+            LineString::from(vec![(5., -5.), (5., 5.)]),
+            LineString::from(vec![(-3., 1.), (3., 1.), (3., 10.)]),
+            LineString::from(vec![(-4., -4.), (-4., 4.)]),
+        ];
+        let found = k_nearest(&query, lines.iter().rev(), 2);
+        assert!(std::ptr::eq(found[0], &lines[1]));
+        assert!(std::ptr::eq(found[1], &lines[2]));
+
+        let none: Vec<Polygon<f64>> = vec![];
+        assert!(k_nearest(&query, &none, 3).is_empty());
+    }
 }
@@ -1,6 +1,6 @@
 use std::iter::Sum;
 
-use crate::{CoordFloat, Line, LineString, MultiLineString};
+use crate::{CoordFloat, CoordNum, Line, LineString, MultiLineString};
 
 /// Calculation of the length
 
@@ -55,11 +55,44 @@ where
     }
 }
 
+/// Add up the (Euclidean) lengths of the geometries of an iterator, such as
+/// the results of a query.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::euclidean_length::TotalLength;
+/// use geo::{line_string, LineString};
+///
+/// let line_strings: Vec<LineString<f64>> = vec![
+///     line_string![(x: 0., y: 0.), (x: 3., y: 4.)],
+///     line_string![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)],
+/// ];
+///
+/// assert_eq!(line_strings.iter().total_length(), 7.);
+/// ```
+pub trait TotalLength<T> {
+    fn total_length(self) -> T;
+}
+
+impl<'a, T, G, I> TotalLength<T> for I
+where
+    T: CoordNum,
+    G: 'a + EuclideanLength<T>,
+    I: IntoIterator<Item = &'a G>,
+{
+    fn total_length(self) -> T {
+        self.into_iter().fold(T::zero(), |total, geometry| {
+            total + geometry.euclidean_length()
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::algorithm::euclidean_length::{EuclideanLength, TotalLength};
     use crate::line_string;
-    use crate::{Coordinate, Line, MultiLineString};
+    use crate::{Coordinate, Line, LineString, MultiLineString};
 
     #[test]
     fn empty_linestring_test() {
@@ -108,4 +141,24 @@ mod test {
         assert_relative_eq!(line0.euclidean_length(), 1.);
         assert_relative_eq!(line1.euclidean_length(), 5.);
     }
+
+    #[test]
+    fn total_length_test() {
+        let line_strings: Vec<LineString<f64>> = (0..10)
+            .map(|idx| line_string![(x: 0., y: 0.), (x: idx as f64 * 0.3, y: 0.), (x: 0., y: 1.)])
+            .collect();
+        let folded = line_strings.iter().fold(0., |total, line_string| {
+            total + line_string.euclidean_length()
+        });
+        assert_eq!(line_strings.iter().total_length(), folded);
+
+        let lines = [
+            Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 3., y: 4. }),
+            Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 1., y: 3. }),
+        ];
+        assert_relative_eq!(lines.iter().total_length(), 7.);
+
+        let empty: Vec<MultiLineString<f64>> = vec![];
+        assert_eq!(empty.iter().total_length(), 0.);
+    }
 }
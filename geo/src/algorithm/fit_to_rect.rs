@@ -0,0 +1,193 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::map_coords::MapCoords;
+use crate::{CoordFloat, Rect};
+
+pub trait FitToRect<T: CoordFloat> {
+    /// Scale and translate a Geometry so that its bounding rectangle fits a
+    /// target `Rect`, such as to draw it in a viewport.
+    ///
+    /// If `preserve_aspect` is `false`, the geometry is stretched along each
+    /// axis separately, so its bounding rectangle becomes the target. If it
+    /// is `true`, it is scaled by the same factor along both axes, as much
+    /// as it can be while still fitting, and centered in the target, leaving
+    /// margins either side along one axis (letterboxing).
+    ///
+    /// A geometry with no width or no height, such as a horizontal or
+    /// vertical line, is centered along that axis, and a single point is
+    /// moved to the center of the target. Empty geometries are returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::fit_to_rect::FitToRect;
+    /// use geo::{line_string, Coordinate, Rect};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 4.0, y: 1.0),
+    /// ];
+    /// let target = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 10.0, y: 10.0 });
+    ///
+    /// assert_eq!(
+    ///     line_string.fit_to_rect(target, true),
+    ///     line_string![
+    ///         (x: 0.0, y: 3.75),
+    ///         (x: 10.0, y: 6.25),
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     line_string.fit_to_rect(target, false),
+    ///     line_string![
+    ///         (x: 0.0, y: 0.0),
+    ///         (x: 10.0, y: 10.0),
+    ///     ]
+    /// );
+    /// ```
+    fn fit_to_rect(&self, target: Rect<T>, preserve_aspect: bool) -> Self;
+}
+
+impl<T, G> FitToRect<T> for G
+where
+    T: CoordFloat,
+    G: Clone + MapCoords<T, T, Output = G> + BoundingRect<T>,
+    <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+{
+    fn fit_to_rect(&self, target: Rect<T>, preserve_aspect: bool) -> Self {
+        let source: Rect<T> = match self.bounding_rect().into() {
+            Some(source) => source,
+            None => return self.clone(),
+        };
+        // Zero for an axis along which the geometry has no extent, where
+        // every coordinate is at the center anyway
+        let factor = |target: T, source: T| {
+            if source > T::zero() {
+                target / source
+            } else {
+                T::zero()
+            }
+        };
+        let mut x_factor = factor(target.width(), source.width());
+        let mut y_factor = factor(target.height(), source.height());
+        if preserve_aspect {
+            let common = match (source.width() > T::zero(), source.height() > T::zero()) {
+                (true, true) => x_factor.min(y_factor),
+                (true, false) => x_factor,
+                (false, _) => y_factor,
+            };
+            x_factor = common;
+            y_factor = common;
+        }
+
+        let (from, to) = (source.center(), target.center());
+        self.map_coords(|&(x, y)| {
+            (
+                to.x + (x - from.x) * x_factor,
+                to.y + (y - from.y) * y_factor,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Coordinate, Geometry, Polygon};
+
+    fn wide() -> Polygon<f64> {
+        polygon![
+            (x: 10.0, y: 10.0),
+            (x: 30.0, y: 10.0),
+            (x: 30.0, y: 15.0),
+            (x: 10.0, y: 15.0),
+        ]
+    }
+
+    fn square() -> Rect<f64> {
+        Rect::new(
+            Coordinate { x: -1.0, y: -1.0 },
+            Coordinate { x: 1.0, y: 1.0 },
+        )
+    }
+
+    #[test]
+    fn preserving_aspect() {
+        let fitted = wide().fit_to_rect(square(), true);
+        // Four times as wide as it is high, centered vertically
+        assert_relative_eq!(
+            *fitted.exterior(),
+            line_string![
+                (x: -1.0, y: -0.25),
+                (x: 1.0, y: -0.25),
+                (x: 1.0, y: 0.25),
+                (x: -1.0, y: 0.25),
+                (x: -1.0, y: -0.25),
+            ]
+        );
+
+        // A tall polygon is centered horizontally instead
+        let tall = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 4.0),
+            (x: 0.0, y: 4.0),
+        ];
+        let fitted = tall.fit_to_rect(square(), true);
+        assert_relative_eq!(
+            fitted.bounding_rect().unwrap().min(),
+            Coordinate { x: -0.25, y: -1.0 }
+        );
+        assert_relative_eq!(
+            fitted.bounding_rect().unwrap().max(),
+            Coordinate { x: 0.25, y: 1.0 }
+        );
+    }
+
+    #[test]
+    fn stretching() {
+        let fitted = wide().fit_to_rect(square(), false);
+        assert_relative_eq!(
+            *fitted.exterior(),
+            line_string![
+                (x: -1.0, y: -1.0),
+                (x: 1.0, y: -1.0),
+                (x: 1.0, y: 1.0),
+                (x: -1.0, y: 1.0),
+                (x: -1.0, y: -1.0),
+            ]
+        );
+        let bounds = fitted.bounding_rect().unwrap();
+        assert_relative_eq!(bounds.min(), square().min());
+        assert_relative_eq!(bounds.max(), square().max());
+
+        let geometry = Geometry::Polygon(wide());
+        assert_eq!(
+            geometry.fit_to_rect(square(), false),
+            Geometry::Polygon(fitted)
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let horizontal = line_string![(x: 0.0, y: 5.0), (x: 4.0, y: 5.0)];
+        let target = Rect::new(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 2.0, y: 10.0 },
+        );
+        let expected = line_string![(x: 0.0, y: 5.0), (x: 2.0, y: 5.0)];
+        assert_eq!(horizontal.fit_to_rect(target, true), expected);
+        assert_eq!(horizontal.fit_to_rect(target, false), expected);
+
+        let vertical = line_string![(x: 3.0, y: 0.0), (x: 3.0, y: 1.0)];
+        assert_eq!(
+            vertical.fit_to_rect(target, true),
+            line_string![(x: 1.0, y: 0.0), (x: 1.0, y: 10.0)]
+        );
+
+        let point = point!(x: 7.0, y: -3.0);
+        assert_eq!(point.fit_to_rect(target, true), point!(x: 1.0, y: 5.0));
+
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(empty.fit_to_rect(target, true), empty);
+    }
+}
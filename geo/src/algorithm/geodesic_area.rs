@@ -0,0 +1,239 @@
+use crate::{LineString, MultiPolygon, Polygon};
+use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+
+/// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
+///
+/// This uses the geodesic measurement methods given by [Karney (2013)], on the WGS84 ellipsoid
+/// (equatorial radius of 6,378,137 meters, flattening of 1/298.257223563). As opposed to the
+/// spherical approximation of [`ChamberlainDuquetteArea`], which can be off by up to ~0.3%, this
+/// method is accurate to within a few square meters, even for large polygons.
+///
+/// The coordinates are interpreted as longitude/latitude, in degrees. Edges are geodesics, and
+/// may cross the antimeridian. Each ring is expected to enclose less than half of the earth.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+/// [`ChamberlainDuquetteArea`]: crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea
+pub trait GeodesicArea<T> {
+    /// Determine the perimeter of a geometry on the WGS84 ellipsoid. The perimeter of a
+    /// `Polygon` includes the length of its interior rings.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::prelude::*;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 0., y: 1.),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     443_771., // meters
+    ///     polygon.geodesic_perimeter().round()
+    /// );
+    /// ```
+    fn geodesic_perimeter(&self) -> T;
+
+    /// Determine the area of a geometry on the WGS84 ellipsoid. The area of any interior
+    /// rings is subtracted, regardless of their orientation.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters²
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::prelude::*;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 0., y: 1.),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     12_308_778_361., // meters²
+    ///     polygon.geodesic_area().round()
+    /// );
+    /// ```
+    fn geodesic_area(&self) -> T;
+
+    /// Determine the signed area of a geometry on the WGS84 ellipsoid. The sign is that of
+    /// the exterior ring: positive if it is oriented counter-clockwise, negative otherwise.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters²
+    fn geodesic_area_signed(&self) -> T;
+}
+
+impl GeodesicArea<f64> for Polygon<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        let geoid = Geodesic::wgs84();
+        self.interiors().iter().fold(
+            ring_perimeter_area(&geoid, self.exterior()).0,
+            |total, next| total + ring_perimeter_area(&geoid, next).0,
+        )
+    }
+
+    fn geodesic_area(&self) -> f64 {
+        self.geodesic_area_signed().abs()
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        let geoid = Geodesic::wgs84();
+        let area = ring_perimeter_area(&geoid, self.exterior()).1;
+        let is_negative = area < 0.;
+
+        let area = self.interiors().iter().fold(area.abs(), |total, next| {
+            total - ring_perimeter_area(&geoid, next).1.abs()
+        });
+
+        if is_negative {
+            -area
+        } else {
+            area
+        }
+    }
+}
+
+impl GeodesicArea<f64> for MultiPolygon<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.iter()
+            .map(|polygon| polygon.geodesic_perimeter())
+            .sum()
+    }
+
+    fn geodesic_area(&self) -> f64 {
+        self.iter().map(|polygon| polygon.geodesic_area()).sum()
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.iter()
+            .map(|polygon| polygon.geodesic_area_signed())
+            .sum()
+    }
+}
+
+/// The perimeter and signed area of a ring, positive if it is oriented counter-clockwise.
+fn ring_perimeter_area(geoid: &Geodesic, ring: &LineString<f64>) -> (f64, f64) {
+    // Polygon rings are closed, so the last coordinate repeats the first
+    let coords = &ring.0[..ring.0.len().saturating_sub(1)];
+    if coords.is_empty() {
+        return (0., 0.);
+    }
+
+    let mut polygon_area = PolygonArea::new(geoid, Winding::CounterClockwise);
+    for coord in coords {
+        polygon_area.add_point(coord.y, coord.x);
+    }
+    let (perimeter, area, _) = polygon_area.compute(true);
+    (perimeter, area)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn test_reference_values() {
+        // Reference values from GeographicLib's test suite
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        assert_relative_eq!(square.geodesic_perimeter(), 443770.917, epsilon = 1.0e-3);
+        assert_relative_eq!(square.geodesic_area(), 12308778361.469, epsilon = 1.0e-3);
+        assert_relative_eq!(
+            square.geodesic_area_signed(),
+            12308778361.469,
+            epsilon = 1.0e-3
+        );
+
+        let diamond = polygon![
+            (x: -1., y: 0.),
+            (x: 0., y: -1.),
+            (x: 1., y: 0.),
+            (x: 0., y: 1.),
+        ];
+        assert_relative_eq!(diamond.geodesic_perimeter(), 627598.2731, epsilon = 1.0e-4);
+        assert_relative_eq!(diamond.geodesic_area(), 24619419146., epsilon = 1.);
+
+        let octant = polygon![
+            (x: 0., y: 90.),
+            (x: 0., y: 0.),
+            (x: 90., y: 0.),
+        ];
+        assert_relative_eq!(octant.geodesic_perimeter(), 30022685., epsilon = 1.);
+        assert_relative_eq!(octant.geodesic_area(), 63758202715511., epsilon = 1.);
+    }
+
+    #[test]
+    fn test_orientation() {
+        let mut square = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        square.exterior_mut(|exterior| exterior.0.reverse());
+        assert_relative_eq!(
+            square.geodesic_area_signed(),
+            -12308778361.469,
+            epsilon = 1.0e-3
+        );
+        assert_relative_eq!(square.geodesic_area(), 12308778361.469, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn test_holes() {
+        let exterior = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let hole = polygon![
+            (x: 0.5, y: 0.5),
+            (x: 0.5, y: 1.5),
+            (x: 1.5, y: 1.5),
+            (x: 1.5, y: 0.5),
+        ];
+        let donut = Polygon::new(exterior.exterior().clone(), vec![hole.exterior().clone()]);
+
+        assert_relative_eq!(
+            donut.geodesic_area(),
+            exterior.geodesic_area() - hole.geodesic_area(),
+            epsilon = 1.0e-3
+        );
+        assert_relative_eq!(
+            donut.geodesic_perimeter(),
+            exterior.geodesic_perimeter() + hole.geodesic_perimeter(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn test_antimeridian() {
+        let spanning = polygon![
+            (x: 179.5, y: 0.),
+            (x: -179.5, y: 0.),
+            (x: -179.5, y: 1.),
+            (x: 179.5, y: 1.),
+        ];
+        assert_relative_eq!(spanning.geodesic_area(), 12308778361.469, epsilon = 1.0e-3);
+    }
+}
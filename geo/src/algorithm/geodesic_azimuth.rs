@@ -0,0 +1,95 @@
+use crate::Line;
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+
+/// Determine the forward azimuth of a `Line` on an ellipsoidal model of the
+/// earth, with its coordinates as longitude and latitude in degrees.
+///
+/// This is the direction in which the geodesic (the shortest path on the
+/// WGS84 ellipsoid) from `start` to `end` sets off, measured clockwise from
+/// north, as given by [Karney (2013)]. It is what survey software reports,
+/// and unlike the planar slope of the line, it accounts for the meridians
+/// converging towards the poles: a line due east of its start at 45°N sets
+/// off slightly north of east.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicAzimuth<T> {
+    /// Determine the forward azimuth of a `Line` on an ellipsoidal model of
+    /// the earth.
+    ///
+    /// # Units
+    ///
+    /// - return value: degrees, in the range `[0, 360)`, where north is 0°
+    ///   and east is 90°. A line whose ends are the same has an azimuth of
+    ///   0°.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo::algorithm::geodesic_azimuth::GeodesicAzimuth;
+    /// use geo::{Coordinate, Line};
+    ///
+    /// // New York City to London
+    /// let line = Line::new(
+    ///     Coordinate { x: -74.006, y: 40.7128 },
+    ///     Coordinate { x: -0.1278, y: 51.5074 },
+    /// );
+    ///
+    /// assert_eq!(51., line.geodesic_azimuth().round());
+    /// ```
+    fn geodesic_azimuth(&self) -> T;
+}
+
+impl GeodesicAzimuth<f64> for Line<f64> {
+    fn geodesic_azimuth(&self) -> f64 {
+        if self.start == self.end {
+            return 0.;
+        }
+        let (_s12, azi1, _azi2, _a12): (f64, f64, f64, f64) =
+            Geodesic::wgs84().inverse(self.start.y, self.start.x, self.end.y, self.end.x);
+        // From the range [-180, 180], which includes -0
+        (azi1 + 360.) % 360.
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::bearing::Bearing;
+    use crate::Coordinate;
+
+    fn line(start: (f64, f64), end: (f64, f64)) -> Line<f64> {
+        Line::new(Coordinate::from(start), Coordinate::from(end))
+    }
+
+    #[test]
+    fn cardinal_directions() {
+        assert_relative_eq!(line((10., 20.), (10., 21.)).geodesic_azimuth(), 0.);
+        assert_relative_eq!(line((10., 20.), (10., 19.)).geodesic_azimuth(), 180.);
+        assert_relative_eq!(line((10., 20.), (10., -20.)).geodesic_azimuth(), 180.);
+        // Along the equator, where the geodesics are parallels
+        assert_relative_eq!(line((10., 0.), (11., 0.)).geodesic_azimuth(), 90.);
+        assert_relative_eq!(line((10., 0.), (9., 0.)).geodesic_azimuth(), 270.);
+        // Across the antimeridian
+        assert_relative_eq!(line((179.5, 0.), (-179.5, 0.)).geodesic_azimuth(), 90.);
+    }
+
+    #[test]
+    fn converging_meridians() {
+        // A line to a point due east at 45°N sets off a little north of
+        // east, close to the spherical bearing, and the one back to it a
+        // little north of west
+        let east = line((0., 45.), (1., 45.));
+        let azimuth = east.geodesic_azimuth();
+        assert!(azimuth > 89. && azimuth < 90.);
+        let bearing = east.start_point().bearing(east.end_point());
+        assert_relative_eq!(azimuth, bearing, epsilon = 0.01);
+
+        let west = line((1., 45.), (0., 45.));
+        assert_relative_eq!(west.geodesic_azimuth(), 360. - azimuth, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn same_point() {
+        assert_eq!(line((10., 20.), (10., 20.)).geodesic_azimuth(), 0.);
+    }
+}
@@ -31,6 +31,37 @@ pub trait HaversineIntermediate<T: CoordFloat> {
     /// ```
 
     fn haversine_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
+
+    /// Returns evenly spaced Points along the great circle route between two
+    /// existing points, such that no two consecutive points are more than
+    /// `max_dist` (in meters) apart.
+    ///
+    /// If `include_ends` is `true`, the result starts with `self` and ends
+    /// with `other`; otherwise only the intermediate points are returned.
+    /// `max_dist` must be positive.
+    ///
+    /// A route crossing the antimeridian is followed the short way around:
+    /// the longitudes of the intermediate points are normalized to
+    /// `[-180, 180]`, so they jump from one end of that range to the other
+    /// where the route crosses it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::algorithm::haversine_intermediate::HaversineIntermediate;
+    /// use geo::Point;
+    ///
+    /// // A quarter of the way around the equator, about 10,000 km
+    /// let p1 = Point::<f64>::new(0.0, 0.0);
+    /// let p2 = Point::<f64>::new(90.0, 0.0);
+    /// let route = p1.haversine_intermediate_fill(&p2, 3_000_000.0, false);
+    /// assert_eq!(route.len(), 3);
+    /// assert_relative_eq!(route[0].x(), 22.5, epsilon = 1.0e-6);
+    /// assert_relative_eq!(route[1].x(), 45.0, epsilon = 1.0e-6);
+    /// assert_relative_eq!(route[2].x(), 67.5, epsilon = 1.0e-6);
+    /// ```
     fn haversine_intermediate_fill(
         &self,
         other: &Point<T>,
@@ -70,13 +101,14 @@ where
         let number_of_points = (total_distance / max_dist).ceil();
         let interval = T::one() / number_of_points;
 
-        let mut current_step = interval;
         let mut points = if include_ends { vec![*self] } else { vec![] };
 
-        while current_step < T::one() {
-            let point = get_point(&params, current_step);
+        // Count the steps rather than accumulating the interval, so that
+        // rounding can't add a point at (or skip the one before) the end
+        let steps = number_of_points.to_usize().unwrap();
+        for step in 1..steps {
+            let point = get_point(&params, interval * T::from(step).unwrap());
             points.push(point);
-            current_step = current_step + interval;
         }
 
         if include_ends {
@@ -240,4 +272,77 @@ mod test {
         let route = p1.haversine_intermediate_fill(&p2, max_dist, include_ends);
         assert_eq!(route, vec![p1, i25, i50, i75, p2]);
     }
+
+    #[test]
+    fn should_not_exceed_max_dist_test() {
+        use crate::algorithm::haversine_distance::HaversineDistance;
+
+        // London to Tokyo
+        let p1 = Point::<f64>::new(-0.1278, 51.5074);
+        let p2 = Point::<f64>::new(139.6917, 35.6895);
+        let max_dist = 100_000.0; // meters
+        let route = p1.haversine_intermediate_fill(&p2, max_dist, true);
+        let total = p1.haversine_distance(&p2);
+        assert_eq!(route.len(), (total / max_dist).ceil() as usize + 1);
+        let gaps: Vec<f64> = route
+            .windows(2)
+            .map(|pair| pair[0].haversine_distance(&pair[1]))
+            .collect();
+        for gap in &gaps {
+            assert!(*gap <= max_dist);
+            assert_relative_eq!(*gap, gaps[0], epsilon = 1.0e-3);
+        }
+        assert_relative_eq!(gaps.iter().sum::<f64>(), total, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn known_waypoints_test() {
+        // Along a meridian, over the north pole
+        let p1 = Point::<f64>::new(0.0, 60.0);
+        let p2 = Point::<f64>::new(180.0, 60.0);
+        let route = p1.haversine_intermediate_fill(&p2, 2_000_000.0, false);
+        let expected = [(0.0, 75.0), (90.0, 90.0), (180.0, 75.0)];
+        assert_eq!(route.len(), expected.len());
+        for (point, (x, y)) in route.iter().zip(expected.iter()) {
+            assert_relative_eq!(point.y(), *y, epsilon = 1.0e-6);
+            if *y < 90.0 {
+                assert_relative_eq!(point.x(), *x, epsilon = 1.0e-6);
+            }
+        }
+
+        // Split into fifths, with the waypoints of the spherical great circle
+        let p1 = Point::<f64>::new(10.0, 20.0);
+        let p2 = Point::<f64>::new(125.0, 25.0);
+        let route = p1.haversine_intermediate_fill(&p2, 2_500_000.0, false);
+        let expected = [
+            (29.84958, 29.88509),
+            (53.16010, 36.26155),
+            (78.81322, 37.57931),
+            (103.54271, 33.44227),
+        ];
+        assert_eq!(route.len(), expected.len());
+        for (point, (x, y)) in route.iter().zip(expected.iter()) {
+            assert_relative_eq!(point.x(), *x, epsilon = 1.0e-4);
+            assert_relative_eq!(point.y(), *y, epsilon = 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn antimeridian_test() {
+        let p1 = Point::<f64>::new(170.0, 0.0);
+        let p2 = Point::<f64>::new(-170.0, 0.0);
+        let route = p1.haversine_intermediate_fill(&p2, 600_000.0, true);
+        // The short way around, about 2,200 km, rather than 37,800 km
+        assert_eq!(route.len(), 5);
+        let expected = [170.0, 175.0, 180.0, -175.0, -170.0];
+        for (point, x) in route.iter().zip(expected.iter()) {
+            assert_relative_eq!(point.y(), 0.0, epsilon = 1.0e-6);
+            // ±180 are the same meridian
+            assert_relative_eq!(
+                (point.x() - x + 180.0).rem_euclid(360.0) - 180.0,
+                0.0,
+                epsilon = 1.0e-6
+            );
+        }
+    }
 }
@@ -0,0 +1,244 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::clip_to_rect::ClipToRect;
+use crate::{Coordinate, GeoFloat, LineString, Polygon, Rect};
+
+/// Which way up the hexagons of a [`HexGrid`] are.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// With a vertex at the top and bottom, in rows along the `x` axis
+    PointyTop,
+    /// With an edge at the top and bottom, in columns along the `y` axis
+    FlatTop,
+}
+
+/// Cover a `Rect` with a grid of regular hexagons, such as to bin points.
+///
+/// Each hexagon has a circumradius, and so a side length, of `cell_size`.
+/// The grid is anchored at the minimum corner of the `Rect`: the first
+/// hexagon is centered on it. With `PointyTop` hexagons, the rows are
+/// `1.5 * cell_size` apart in `y`, and every other row is shifted by half
+/// a hexagon in `x`; `FlatTop` hexagons are laid out the same way with
+/// `x` and `y` swapped.
+///
+/// Only the hexagons which overlap the interior of the `Rect` are kept,
+/// so adjacent hexagons share their edges exactly, and hexagons merely
+/// touching its boundary are left out. With `clip`, they are clipped to
+/// the `Rect`, so that they cover it exactly. The hexagons are closed and
+/// counter-clockwise, ordered row by row (or column by column for
+/// `FlatTop`) away from the minimum corner. If `cell_size` is not a
+/// positive, finite number, or the `Rect` has no area, there are no
+/// hexagons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::hex_grid::{HexGrid, HexOrientation};
+/// use geo::{Coordinate, Rect};
+///
+/// let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 1. });
+///
+/// let hexagons = rect.hex_grid(1., HexOrientation::FlatTop, false);
+/// assert_eq!(hexagons.len(), 3);
+/// assert_eq!(hexagons[0].exterior().0.len(), 7);
+/// ```
+pub trait HexGrid<T: GeoFloat> {
+    fn hex_grid(&self, cell_size: T, orientation: HexOrientation, clip: bool) -> Vec<Polygon<T>>;
+}
+
+/// The vertices of a `PointyTop` hexagon around the origin, counter-clockwise
+/// from the bottom, in half widths across and half sides up.
+const POINTY_TOP: [(i64, i64); 6] = [(0, -2), (1, -1), (1, 1), (0, 2), (-1, 1), (-1, -1)];
+
+impl<T: GeoFloat> HexGrid<T> for Rect<T> {
+    fn hex_grid(&self, cell_size: T, orientation: HexOrientation, clip: bool) -> Vec<Polygon<T>> {
+        if !(cell_size > T::zero() && cell_size.is_finite()) {
+            return vec![];
+        }
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        // Every vertex is an integer number of half widths across the rows
+        // and half sides along them from the minimum corner, so that the
+        // hexagons share their vertices exactly
+        let half_width = cell_size * three.sqrt() / two;
+        let half_side = cell_size / two;
+        let min = self.min();
+        let (along, across) = match orientation {
+            HexOrientation::PointyTop => (self.width(), self.height()),
+            HexOrientation::FlatTop => (self.height(), self.width()),
+        };
+        let coord = |a: i64, b: i64| {
+            let (a, b) = (
+                T::from(a).unwrap() * half_width,
+                T::from(b).unwrap() * half_side,
+            );
+            match orientation {
+                HexOrientation::PointyTop => Coordinate {
+                    x: min.x + a,
+                    y: min.y + b,
+                },
+                HexOrientation::FlatTop => Coordinate {
+                    x: min.x + b,
+                    y: min.y + a,
+                },
+            }
+        };
+
+        let mut hexagons = vec![];
+        let mut row = 0;
+        while T::from(3 * row - 2).unwrap() * half_side < across {
+            let mut column = row % 2;
+            while T::from(column - 1).unwrap() * half_width < along {
+                let mut ring: Vec<_> = POINTY_TOP
+                    .iter()
+                    .map(|&(a, b)| coord(column + a, 3 * row + b))
+                    .collect();
+                if orientation == HexOrientation::FlatTop {
+                    // Swapping the axes reverses the winding
+                    ring.reverse();
+                }
+                let hexagon = Polygon::new(LineString(ring), vec![]);
+                let clipped = hexagon.clip_to_rect(*self);
+                if clipped.unsigned_area() > T::zero() {
+                    if clip {
+                        hexagons.extend(
+                            clipped
+                                .into_iter()
+                                .filter(|part| part.unsigned_area() > T::zero()),
+                        );
+                    } else {
+                        hexagons.push(hexagon);
+                    }
+                }
+                column += 2;
+            }
+            row += 1;
+        }
+        hexagons
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::winding_order::Winding;
+    use crate::Line;
+    use std::collections::HashSet;
+
+    fn edges(hexagon: &Polygon<f64>) -> Vec<Line<f64>> {
+        hexagon.exterior().lines().collect()
+    }
+
+    fn shares_edge(a: &Polygon<f64>, b: &Polygon<f64>) -> bool {
+        edges(a).iter().any(|edge| {
+            edges(b)
+                .iter()
+                .any(|other| other.start == edge.end && other.end == edge.start)
+        })
+    }
+
+    #[test]
+    fn count() {
+        let width = 3f64.sqrt();
+        let rect = Rect::new(
+            Coordinate { x: 0., y: 0. },
+            Coordinate {
+                x: 2. * width,
+                y: 1.5,
+            },
+        );
+        let hexagons = rect.hex_grid(1., HexOrientation::PointyTop, false);
+        // Three in the first row, centered on y = 0, and two between them
+        // in the second, centered on y = 1.5
+        assert_eq!(hexagons.len(), 5);
+        for hexagon in &hexagons {
+            assert!(hexagon.exterior().is_closed());
+            assert!(hexagon.exterior().is_ccw());
+            assert_eq!(hexagon.exterior().0.len(), 7);
+            assert_relative_eq!(hexagon.unsigned_area(), 1.5 * width, epsilon = 1e-9);
+        }
+        assert_eq!(hexagons[0].exterior().0[0], Coordinate { x: 0., y: -1. });
+        assert_relative_eq!(
+            hexagons[3].exterior().0[0],
+            Coordinate {
+                x: width / 2.,
+                y: 0.5
+            }
+        );
+
+        let clipped = rect.hex_grid(1., HexOrientation::PointyTop, true);
+        assert_eq!(clipped.len(), 5);
+        let area: f64 = clipped.iter().map(|hexagon| hexagon.unsigned_area()).sum();
+        assert_relative_eq!(area, rect.to_polygon().unsigned_area());
+    }
+
+    #[test]
+    fn adjacent_hexagons_share_edges() {
+        let rect = Rect::new(Coordinate { x: -3., y: 2. }, Coordinate { x: 7., y: 9. });
+        for &orientation in &[HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let hexagons = rect.hex_grid(0.7, orientation, false);
+            let centers: Vec<Coordinate<f64>> = hexagons
+                .iter()
+                .map(|hexagon| {
+                    let coords = &hexagon.exterior().0[..6];
+                    let sum = coords
+                        .iter()
+                        .fold(Coordinate { x: 0., y: 0. }, |sum, &c| sum + c);
+                    sum / 6.
+                })
+                .collect();
+            for (idx, a) in hexagons.iter().enumerate() {
+                assert!(a.exterior().is_ccw());
+                for (other, b) in hexagons.iter().enumerate().skip(idx + 1) {
+                    let (ca, cb) = (centers[idx], centers[other]);
+                    let distance = ((ca.x - cb.x).powi(2) + (ca.y - cb.y).powi(2)).sqrt();
+                    // Neighbours are a hexagon's width apart
+                    let neighbours = (distance - 0.7 * 3f64.sqrt()).abs() < 1e-9;
+                    assert_eq!(shares_edge(a, b), neighbours);
+                }
+            }
+
+            // Clipped, they cover the rect exactly
+            let clipped = rect.hex_grid(0.7, orientation, true);
+            assert_eq!(clipped.len(), hexagons.len());
+            let area: f64 = clipped.iter().map(|hexagon| hexagon.unsigned_area()).sum();
+            assert_relative_eq!(area, 70., epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn orientations() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 5. });
+        let pointy = rect.hex_grid(1., HexOrientation::PointyTop, false);
+        let flat = rect.hex_grid(1., HexOrientation::FlatTop, false);
+        assert_eq!(pointy.len(), flat.len());
+        // The vertices of the first hexagon, above and beside the corner
+        let vertices = |hexagon: &Polygon<f64>| -> HashSet<(i64, i64)> {
+            hexagon
+                .exterior()
+                .points_iter()
+                .map(|point| {
+                    (
+                        (point.x() * 1e6).round() as i64,
+                        (point.y() * 1e6).round() as i64,
+                    )
+                })
+                .collect()
+        };
+        assert!(vertices(&pointy[0]).contains(&(0, 1_000_000)));
+        assert!(vertices(&flat[0]).contains(&(1_000_000, 0)));
+    }
+
+    #[test]
+    fn degenerate() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 5. });
+        for &cell_size in &[0., -1., f64::NAN, f64::INFINITY] {
+            assert!(rect
+                .hex_grid(cell_size, HexOrientation::PointyTop, false)
+                .is_empty());
+        }
+        let line = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 0. });
+        assert!(line
+            .hex_grid(1., HexOrientation::PointyTop, true)
+            .is_empty());
+    }
+}
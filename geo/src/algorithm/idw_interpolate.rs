@@ -0,0 +1,139 @@
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{GeoFloat, Point};
+
+/// Estimate a value at `query` by inverse distance weighting (IDW) of
+/// `samples`, each of which is a location and the value measured there.
+///
+/// The estimate is the mean of the sampled values, each weighted by the
+/// inverse of its Euclidean distance from `query` raised to `power`, so a
+/// greater `power` favours nearer samples more; `2` is a common choice. If
+/// `query` is at the location of a sample, that sample's value is
+/// returned, or the first such sample's if there are several.
+///
+/// Samples at a distance of `NaN` are ignored, and `None` is returned if
+/// there are no others.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::idw_interpolate::idw_interpolate;
+/// use geo::point;
+///
+/// let samples = vec![(point!(x: 0., y: 0.), 10.), (point!(x: 3., y: 0.), 40.)];
+///
+/// // Twice as near to the first sample, so with four times its weight
+/// assert_eq!(idw_interpolate(&samples, point!(x: 1., y: 0.), 2.), Some(16.));
+/// assert_eq!(idw_interpolate(&samples, point!(x: 3., y: 0.), 2.), Some(40.));
+/// ```
+pub fn idw_interpolate<T: GeoFloat>(
+    samples: &[(Point<T>, T)],
+    query: Point<T>,
+    power: T,
+) -> Option<T> {
+    let distances: Vec<T> = samples
+        .iter()
+        .map(|(location, _)| location.euclidean_distance(&query))
+        .collect();
+    let (nearest, &nearest_distance) = distances
+        .iter()
+        .enumerate()
+        .filter(|(_, distance)| !distance.is_nan())
+        .fold(
+            None,
+            |nearest: Option<(usize, &T)>, (idx, distance)| match nearest {
+                Some((_, nearest_distance)) if nearest_distance <= distance => nearest,
+                _ => Some((idx, distance)),
+            },
+        )?;
+    if nearest_distance == T::zero() {
+        return Some(samples[nearest].1);
+    }
+
+    // Weighting by the ratio to the nearest distance, rather than by the
+    // inverse distance itself, gives the same estimate without overflowing
+    // for very near samples
+    let (total, total_weight) = samples
+        .iter()
+        .zip(&distances)
+        .filter(|(_, distance)| !distance.is_nan())
+        .fold(
+            (T::zero(), T::zero()),
+            |(total, total_weight), ((_, value), &distance)| {
+                let weight = (nearest_distance / distance).powf(power);
+                (total + *value * weight, total_weight + weight)
+            },
+        );
+    Some(total / total_weight)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn two_samples() {
+        let samples = vec![(point!(x: 0., y: 0.), 10.), (point!(x: 4., y: 0.), 20.)];
+
+        // Halfway, equally weighted
+        assert_relative_eq!(
+            idw_interpolate(&samples, point!(x: 2., y: 0.), 2.).unwrap(),
+            15.
+        );
+        assert_relative_eq!(
+            idw_interpolate(&samples, point!(x: 2., y: 7.), 1.).unwrap(),
+            15.
+        );
+
+        // Three times as near to the first sample
+        let query = point!(x: 1., y: 0.);
+        assert_relative_eq!(
+            idw_interpolate(&samples, query, 1.).unwrap(),
+            (10. * 3. + 20.) / 4.
+        );
+        assert_relative_eq!(
+            idw_interpolate(&samples, query, 2.).unwrap(),
+            (10. * 9. + 20.) / 10.
+        );
+        // With no power, all samples are weighted equally
+        assert_relative_eq!(idw_interpolate(&samples, query, 0.).unwrap(), 15.);
+
+        // Very near a sample, the estimate tends to its value
+        let near = idw_interpolate(&samples, point!(x: 1e-200, y: 0.), 2.).unwrap();
+        assert_relative_eq!(near, 10.);
+    }
+
+    #[test]
+    fn exact_hit() {
+        let samples = vec![
+            (point!(x: 0., y: 0.), 10.),
+            (point!(x: 4., y: 0.), 20.),
+            (point!(x: 4., y: 0.), 30.),
+        ];
+        assert_eq!(
+            idw_interpolate(&samples, point!(x: 0., y: 0.), 2.),
+            Some(10.)
+        );
+        assert_eq!(
+            idw_interpolate(&samples, point!(x: 4., y: 0.), 2.),
+            Some(20.)
+        );
+    }
+
+    #[test]
+    fn no_samples() {
+        let samples: Vec<(Point<f64>, f64)> = vec![];
+        assert_eq!(idw_interpolate(&samples, point!(x: 0., y: 0.), 2.), None);
+
+        let samples = vec![(point!(x: f64::NAN, y: 0.), 10.)];
+        assert_eq!(idw_interpolate(&samples, point!(x: 0., y: 0.), 2.), None);
+        let samples = vec![
+            (point!(x: f64::NAN, y: 0.), 10.),
+            (point!(x: 1., y: 0.), 5.),
+        ];
+        assert_eq!(
+            idw_interpolate(&samples, point!(x: 0., y: 0.), 2.),
+            Some(5.)
+        );
+    }
+}
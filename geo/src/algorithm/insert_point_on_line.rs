@@ -0,0 +1,156 @@
+use crate::{CoordFloat, Coordinate, LineString, Point};
+
+/// Insert a vertex into a `LineString` at the closest location on it to a
+/// `Point`, such as where it was clicked on in an editor.
+///
+/// The closest location is the foot of the perpendicular from the point to
+/// the nearest segment, or an end of that segment if the foot would lie
+/// beyond it. If several segments are equally close, the first of them is
+/// used. The shape of the `LineString` is unchanged.
+///
+/// The index of the new vertex is returned. If the closest location is
+/// already a vertex, nothing is inserted, and the index of that vertex is
+/// returned instead. `None` is returned if the `LineString` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::insert_point_on_line::InsertPointOnLine;
+/// use geo::{line_string, point};
+///
+/// let mut line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+/// ];
+///
+/// assert_eq!(line_string.insert_point_on_line(point!(x: 1., y: 0.5)), Some(1));
+/// assert_eq!(
+///     line_string,
+///     line_string![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 4., y: 0.),
+///         (x: 4., y: 4.),
+///     ]
+/// );
+/// ```
+pub trait InsertPointOnLine<T: CoordFloat> {
+    fn insert_point_on_line(&mut self, p: Point<T>) -> Option<usize>;
+}
+
+impl<T: CoordFloat> InsertPointOnLine<T> for LineString<T> {
+    fn insert_point_on_line(&mut self, p: Point<T>) -> Option<usize> {
+        let first = *self.0.first()?;
+        let distance = |coord: Coordinate<T>| (p.0.x - coord.x).hypot(p.0.y - coord.y);
+
+        // The closest location found, as the index of the segment it is on,
+        // the location, and its distance from `p`
+        let mut closest = (0, first, distance(first));
+        for (index, line) in self.lines().enumerate() {
+            let delta = line.delta();
+            let length_squared = delta.x * delta.x + delta.y * delta.y;
+            if length_squared == T::zero() {
+                continue;
+            }
+            let offset = p.0 - line.start;
+            let fraction = (delta.x * offset.x + delta.y * offset.y) / length_squared;
+            // Use the ends exactly, so that they are recognised below
+            let foot = if fraction <= T::zero() {
+                line.start
+            } else if fraction >= T::one() {
+                line.end
+            } else {
+                line.start + delta * fraction
+            };
+            let foot_distance = distance(foot);
+            if foot_distance < closest.2 {
+                closest = (index, foot, foot_distance);
+            }
+        }
+
+        let (index, foot, _) = closest;
+        if foot == self.0[index] {
+            return Some(index);
+        }
+        if foot == self.0[index + 1] {
+            return Some(index + 1);
+        }
+        self.0.insert(index + 1, foot);
+        Some(index + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    fn line_string() -> LineString<f64> {
+        line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 8., y: 4.),
+        ]
+    }
+
+    #[test]
+    fn middle_of_segment() {
+        let mut line = line_string();
+        assert_eq!(line.insert_point_on_line(point!(x: 3., y: 2.)), Some(2));
+        assert_eq!(
+            line,
+            line_string![
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 2.),
+                (x: 4., y: 4.),
+                (x: 8., y: 4.),
+            ]
+        );
+
+        // On the line itself
+        let mut line = line_string();
+        assert_eq!(line.insert_point_on_line(point!(x: 6., y: 4.)), Some(3));
+        assert_eq!(line.0[3], Coordinate { x: 6., y: 4. });
+        assert_eq!(line.0.len(), 5);
+    }
+
+    #[test]
+    fn near_vertex() {
+        // Just along the segment from a vertex, which gets a new vertex
+        // close beside it
+        let mut line = line_string();
+        assert_eq!(line.insert_point_on_line(point!(x: 3.9, y: 0.1)), Some(1));
+        assert_eq!(line.0[1], Coordinate { x: 3.9, y: 0. });
+        assert_eq!(line.0.len(), 5);
+
+        // Outside the corner, where the closest location is the vertex
+        // itself
+        let mut line = line_string();
+        assert_eq!(line.insert_point_on_line(point!(x: 4.5, y: -0.5)), Some(1));
+        assert_eq!(line, line_string());
+
+        // Beyond either end
+        assert_eq!(line.insert_point_on_line(point!(x: -1., y: -1.)), Some(0));
+        assert_eq!(line.insert_point_on_line(point!(x: 9., y: 5.)), Some(3));
+        assert_eq!(line, line_string());
+    }
+
+    #[test]
+    fn degenerate() {
+        let mut empty: LineString<f64> = line_string![];
+        assert_eq!(empty.insert_point_on_line(point!(x: 1., y: 1.)), None);
+        assert!(empty.0.is_empty());
+
+        let mut single = line_string![(x: 0., y: 0.)];
+        assert_eq!(single.insert_point_on_line(point!(x: 1., y: 1.)), Some(0));
+        assert_eq!(single, line_string![(x: 0., y: 0.)]);
+
+        // Repeated coordinates are skipped over
+        let mut repeated = line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(repeated.insert_point_on_line(point!(x: 1., y: 1.)), Some(2));
+        assert_eq!(repeated.0[2], Coordinate { x: 1., y: 0. });
+    }
+}
@@ -0,0 +1,147 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::GeoFloat;
+
+/// Calculate the Intersection over Union (also known as the Jaccard index)
+/// of two areal geometries: the area of their intersection divided by the
+/// area of their union.
+///
+/// The result is in the range `[0, 1]`. It is `0` for geometries which
+/// don't overlap (including those which only touch), and `1` for geometries
+/// covering the same region, regardless of their vertices. If neither
+/// geometry has any area, the result is `0`.
+///
+/// The union's area is found from the areas of the two geometries and of
+/// their [intersection](BooleanOps::intersection), so the inputs are
+/// expected to be valid, as for [`BooleanOps`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::intersection_over_union::IntersectionOverUnion;
+/// use geo::polygon;
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+/// let shifted = polygon![
+///     (x: 2., y: 2.),
+///     (x: 6., y: 2.),
+///     (x: 6., y: 6.),
+///     (x: 2., y: 6.),
+/// ];
+///
+/// // An intersection of 4, and a union of 16 + 16 - 4 = 28
+/// assert_eq!(square.iou(&shifted), 4. / 28.);
+/// ```
+pub trait IntersectionOverUnion<T: GeoFloat, Rhs = Self> {
+    fn iou(&self, rhs: &Rhs) -> T;
+}
+
+impl<T, G> IntersectionOverUnion<T> for G
+where
+    T: GeoFloat,
+    G: BooleanOps<Scalar = T> + Area<T>,
+{
+    fn iou(&self, rhs: &Self) -> T {
+        let intersection = self.intersection(rhs).unsigned_area();
+        let union = self.unsigned_area() + rhs.unsigned_area() - intersection;
+        if union > T::zero() {
+            // Rounding can take the ratio of identical regions just over one
+            (intersection / union).min(T::one())
+        } else {
+            T::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, MultiPolygon, Polygon};
+
+    fn square() -> Polygon<f64> {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ]
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(square().iou(&square()), 1.);
+        // The same region, from a different start and in the other direction
+        let reordered = polygon![
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+            (x: 0., y: 0.),
+            (x: 0., y: 4.),
+        ];
+        assert_eq!(square().iou(&reordered), 1.);
+    }
+
+    #[test]
+    fn half_overlapping() {
+        let shifted = polygon![
+            (x: 2., y: 0.),
+            (x: 6., y: 0.),
+            (x: 6., y: 4.),
+            (x: 2., y: 4.),
+        ];
+        // An intersection of 8, and a union of 24
+        assert_relative_eq!(square().iou(&shifted), 1. / 3.);
+        assert_relative_eq!(shifted.iou(&square()), 1. / 3.);
+
+        // One inside the other, with half its area
+        let half = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_relative_eq!(square().iou(&half), 0.5);
+    }
+
+    #[test]
+    fn disjoint() {
+        let apart = polygon![
+            (x: 10., y: 0.),
+            (x: 14., y: 0.),
+            (x: 14., y: 4.),
+            (x: 10., y: 4.),
+        ];
+        assert_eq!(square().iou(&apart), 0.);
+        // Sharing an edge
+        let beside = polygon![
+            (x: 4., y: 0.),
+            (x: 8., y: 0.),
+            (x: 8., y: 4.),
+            (x: 4., y: 4.),
+        ];
+        assert_eq!(square().iou(&beside), 0.);
+
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(empty.iou(&empty), 0.);
+        assert_eq!(square().iou(&empty), 0.);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let left = MultiPolygon(vec![square()]);
+        let right = MultiPolygon(vec![
+            square(),
+            polygon![
+                (x: 10., y: 0.),
+                (x: 14., y: 0.),
+                (x: 14., y: 4.),
+                (x: 10., y: 4.),
+            ],
+        ]);
+        assert_relative_eq!(left.iou(&right), 0.5);
+    }
+}
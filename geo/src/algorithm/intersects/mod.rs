@@ -575,4 +575,18 @@ mod test {
         let geom: Geometry<_> = Line::from([(0.5, 0.5), (2., 1.)]).into();
         assert!(geom.intersects(&geom));
     }
+
+    #[test]
+    fn integer_intersects() {
+        let square: Polygon<i64> = Rect::new((0, 0), (10, 10)).into();
+        assert!(square.intersects(&Point::new(5, 5)));
+        assert!(square.intersects(&Point::new(10, 3)));
+        assert!(!square.intersects(&Point::new(11, 3)));
+        assert!(square.intersects(&Line::from([(-5, 5), (5, 15)])));
+        assert!(!square.intersects(&Line::from([(-5, 6), (4, 15)])));
+
+        let line_string: LineString<i64> = vec![(20, 0), (30, 10), (40, 0)].into();
+        assert!(line_string.intersects(&Line::from([(25, 0), (35, 10)])));
+        assert!(!line_string.intersects(&square));
+    }
 }
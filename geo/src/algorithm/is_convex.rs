@@ -1,7 +1,8 @@
 use crate::kernels::*;
-use crate::{Coordinate, LineString};
+use crate::{Coordinate, LineString, Polygon};
 
-/// Predicates to test the convexity of a [ `LineString` ].
+/// Predicates to test the convexity of a [ `LineString` ] or a
+/// [ `Polygon` ].
 /// A closed `LineString` is said to be _convex_ if it
 /// encloses a [convex set]. It is said to be _strictly
 /// convex_ if in addition, no three consecutive vertices
@@ -16,7 +17,10 @@ use crate::{Coordinate, LineString};
 /// - This definition is closely related to the notion
 /// of [convexity of polygons][convex set]. In particular, a
 /// [`Polygon`](crate::Polygon) is convex, if and only if its `exterior` is
-/// convex, and `interiors` is empty.
+/// convex, and `interiors` is empty, which is how it is
+/// tested here. Call it as `IsConvex::is_convex(&polygon)`,
+/// since the deprecated `Polygon::is_convex` method takes
+/// precedence otherwise.
 ///
 /// - The [`ConvexHull`] algorithm always returns a strictly
 /// convex `LineString` unless the input is empty or
@@ -127,6 +131,25 @@ impl<T: HasKernel> IsConvex for LineString<T> {
     }
 }
 
+impl<T: HasKernel> IsConvex for Polygon<T> {
+    fn convex_orientation(
+        &self,
+        allow_collinear: bool,
+        specific_orientation: Option<Orientation>,
+    ) -> Option<Orientation> {
+        if self.interiors().is_empty() {
+            self.exterior()
+                .convex_orientation(allow_collinear, specific_orientation)
+        } else {
+            None
+        }
+    }
+
+    fn is_collinear(&self) -> bool {
+        self.exterior().is_collinear() && self.interiors().iter().all(|ring| ring.is_collinear())
+    }
+}
+
 /// A utility that tests convexity of a sequence of
 /// coordinates. It verifies that for all `0 <= i < n`, the
 /// vertices at positions `i`, `i+1`, `i+2` (mod `n`) have
@@ -246,4 +269,46 @@ mod tests {
         assert!(!two.is_strictly_ccw_convex());
         assert!(!two.is_strictly_cw_convex());
     }
+
+    #[test]
+    fn test_polygon() {
+        use geo_types::polygon;
+
+        // Convex, with a collinear vertex on one side
+        let pentagon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 4., y: 0.),
+            (x: 5., y: 3.),
+            (x: 2., y: 5.),
+            (x: -1., y: 3.),
+        ];
+        // The deprecated inherent `Polygon::is_convex` takes precedence over
+        // the trait method
+        assert!(IsConvex::is_convex(&pentagon));
+        assert!(pentagon.is_ccw_convex());
+        assert!(!pentagon.is_cw_convex());
+        assert!(!pentagon.is_strictly_convex());
+
+        // Pointing right, with a notch in the tail
+        let arrow = polygon![
+            (x: 0., y: 0.),
+            (x: 3., y: 0.),
+            (x: 3., y: -1.),
+            (x: 5., y: 1.),
+            (x: 3., y: 3.),
+            (x: 3., y: 2.),
+            (x: 0., y: 2.),
+            (x: 1., y: 1.),
+        ];
+        assert!(!IsConvex::is_convex(&arrow));
+
+        let donut = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+        );
+        assert!(donut.exterior().is_convex());
+        assert!(!IsConvex::is_convex(&donut));
+        assert!(!donut.is_collinear());
+    }
 }
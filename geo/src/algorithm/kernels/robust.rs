@@ -42,3 +42,74 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::kernels::SimpleKernel;
+    use crate::algorithm::side_of::{Side, SideOf};
+    use crate::{point, Line};
+
+    #[test]
+    fn naive_sign_flips() {
+        // Nearly collinear, where rounding makes the naive cross product
+        // negative, but it is exactly positive
+        let p = Coordinate { x: 0.1, y: 0.3 };
+        let q = Coordinate {
+            x: 27.099850790067727,
+            y: 44.023735698401545,
+        };
+        let r = Coordinate {
+            x: 258.44867545816714,
+            y: 418.6715418131273,
+        };
+        assert_eq!(
+            <SimpleKernel as Kernel<f64>>::orient2d(p, q, r),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            RobustKernel::orient2d(p, q, r),
+            Orientation::CounterClockwise
+        );
+        // Consistently, for every ordering of the three points
+        assert_eq!(
+            RobustKernel::orient2d(q, r, p),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            RobustKernel::orient2d(r, p, q),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(RobustKernel::orient2d(q, p, r), Orientation::Clockwise);
+        assert_eq!(Line::new(p, q).side_of(point!(x: r.x, y: r.y)), Side::Left);
+    }
+
+    #[test]
+    fn naive_collinear() {
+        // The naive cross product rounds to zero, but `p` is just off the
+        // line through the others
+        let p = Coordinate {
+            x: 0.5000000000000001,
+            y: 0.5,
+        };
+        let (q, r) = (Coordinate { x: 12., y: 12. }, Coordinate { x: 24., y: 24. });
+        assert_eq!(
+            <SimpleKernel as Kernel<f64>>::orient2d(p, q, r),
+            Orientation::Collinear
+        );
+        assert_eq!(RobustKernel::orient2d(p, q, r), Orientation::Clockwise);
+
+        // Exactly collinear
+        let p = Coordinate { x: 0.5, y: 0.5 };
+        assert_eq!(RobustKernel::orient2d(p, q, r), Orientation::Collinear);
+        let (p, q, r): (Coordinate<f32>, _, _) = (
+            Coordinate { x: 0.25, y: 0.5 },
+            Coordinate { x: 1.25, y: 2.5 },
+            Coordinate {
+                x: 1e6 + 0.25,
+                y: 2e6 + 0.5,
+            },
+        );
+        assert_eq!(RobustKernel::orient2d(p, q, r), Orientation::Collinear);
+    }
+}
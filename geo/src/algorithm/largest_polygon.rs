@@ -0,0 +1,139 @@
+use std::cmp::Ordering;
+
+use crate::algorithm::area::Area;
+use crate::{CoordFloat, MultiPolygon, Polygon};
+
+/// Find the largest polygons of a `MultiPolygon` by (unsigned) area, such
+/// as to pick out a mainland from its islands for labelling.
+///
+/// Polygons with equal areas keep their order in the `MultiPolygon`, so
+/// the first of several equally large polygons counts as the largest.
+pub trait LargestPolygon<T: CoordFloat> {
+    /// The polygon with the greatest area, or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::largest_polygon::LargestPolygon;
+    /// use geo::{polygon, MultiPolygon};
+    ///
+    /// let island = polygon![(x: 10., y: 0.), (x: 11., y: 0.), (x: 11., y: 1.)];
+    /// let mainland = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.), (x: 0., y: 5.)];
+    /// let multi_polygon = MultiPolygon(vec![island, mainland.clone()]);
+    ///
+    /// assert_eq!(multi_polygon.largest(), Some(&mainland));
+    /// ```
+    fn largest(&self) -> Option<&Polygon<T>>;
+
+    /// The polygons, sorted by decreasing area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::largest_polygon::LargestPolygon;
+    /// use geo::{polygon, MultiPolygon};
+    ///
+    /// let small = polygon![(x: 10., y: 0.), (x: 11., y: 0.), (x: 11., y: 1.)];
+    /// let large = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.), (x: 0., y: 5.)];
+    /// let medium = polygon![(x: 0., y: 10.), (x: 2., y: 10.), (x: 2., y: 12.)];
+    /// let multi_polygon = MultiPolygon(vec![small.clone(), large.clone(), medium.clone()]);
+    ///
+    /// assert_eq!(multi_polygon.sorted_by_area(), vec![&large, &medium, &small]);
+    /// ```
+    fn sorted_by_area(&self) -> Vec<&Polygon<T>>;
+}
+
+impl<T: CoordFloat> LargestPolygon<T> for MultiPolygon<T> {
+    fn largest(&self) -> Option<&Polygon<T>> {
+        let mut largest: Option<(&Polygon<T>, T)> = None;
+        for polygon in &self.0 {
+            let area = polygon.unsigned_area();
+            match largest {
+                Some((_, largest_area)) if area <= largest_area => {}
+                _ => largest = Some((polygon, area)),
+            }
+        }
+        largest.map(|(polygon, _)| polygon)
+    }
+
+    fn sorted_by_area(&self) -> Vec<&Polygon<T>> {
+        let mut polygons: Vec<(&Polygon<T>, T)> = self
+            .0
+            .iter()
+            .map(|polygon| (polygon, polygon.unsigned_area()))
+            .collect();
+        polygons.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        polygons.into_iter().map(|(polygon, _)| polygon).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    fn square(x: f64, size: f64) -> Polygon<f64> {
+        polygon![
+            (x: x, y: 0.),
+            (x: x + size, y: 0.),
+            (x: x + size, y: size),
+            (x: x, y: size),
+        ]
+    }
+
+    #[test]
+    fn largest() {
+        let multi_polygon = MultiPolygon(vec![
+            square(0., 2.),
+            square(10., 5.),
+            square(20., 1.),
+            square(30., 3.),
+        ]);
+        assert_eq!(multi_polygon.largest(), Some(&multi_polygon.0[1]));
+
+        // A larger polygon, but with a hole bigger than the difference
+        let donut = Polygon::new(
+            square(40., 6.).exterior().clone(),
+            vec![
+                line_string![(x: 41., y: 1.), (x: 41., y: 5.), (x: 45., y: 5.), (x: 45., y: 1.), (x: 41., y: 1.)],
+            ],
+        );
+        let multi_polygon = MultiPolygon(vec![square(0., 5.), donut]);
+        assert_eq!(multi_polygon.largest(), Some(&multi_polygon.0[0]));
+
+        // Equal areas, and a clockwise polygon
+        let clockwise = polygon![(x: 0., y: 0.), (x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 0.)];
+        let multi_polygon = MultiPolygon(vec![square(0., 1.), clockwise, square(10., 2.)]);
+        assert_eq!(multi_polygon.largest(), Some(&multi_polygon.0[1]));
+
+        let empty: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert_eq!(empty.largest(), None);
+    }
+
+    #[test]
+    fn sorted_by_area() {
+        let multi_polygon = MultiPolygon(vec![
+            square(0., 2.),
+            square(10., 5.),
+            square(20., 1.),
+            square(30., 3.),
+            square(40., 2.),
+        ]);
+        let sorted = multi_polygon.sorted_by_area();
+        let polygons = &multi_polygon.0;
+        assert_eq!(
+            sorted,
+            vec![
+                &polygons[1],
+                &polygons[3],
+                &polygons[0],
+                &polygons[4],
+                &polygons[2]
+            ]
+        );
+        assert_eq!(multi_polygon.largest(), Some(sorted[0]));
+
+        let empty: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert!(empty.sorted_by_area().is_empty());
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{CoordFloat, LineString, MultiLineString, OrderedCoord};
+use std::collections::HashMap;
+
+/// Merge the `LineString`s of a `MultiLineString` which meet end to end
+/// into the longest possible continuous `LineString`s, like PostGIS's
+/// `ST_LineMerge`.
+///
+/// Each `LineString` joins the others at its first and last coordinates,
+/// which are the _nodes_ of a network. Two `LineString`s are merged where
+/// they are the only ones ending at a node, reversing one of them if they
+/// run in opposite directions. Where three or more end at the same node, as
+/// at a junction, none of them are merged across it, since there is no
+/// single way to continue. The ends of two `LineString`s must be exactly
+/// equal to be joined, and interior coordinates are never joined to
+/// anything.
+///
+/// Each merged `LineString` runs in the direction of the first of its parts
+/// in the input, and the results are in the order of their first parts. A
+/// chain of parts which forms a loop is merged into a closed `LineString`.
+/// `LineString`s with fewer than two coordinates are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_merge::LineMerge;
+/// use geo::{line_string, MultiLineString};
+///
+/// let fragments = MultiLineString(vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 1.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 1.), (x: 3., y: 1.)],
+/// ]);
+///
+/// assert_eq!(
+///     fragments.line_merge(),
+///     MultiLineString(vec![line_string![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 2., y: 1.),
+///         (x: 3., y: 1.),
+///     ]])
+/// );
+/// ```
+pub trait LineMerge<T: CoordFloat> {
+    fn line_merge(&self) -> MultiLineString<T>;
+}
+
+impl<T: CoordFloat> LineMerge<T> for MultiLineString<T> {
+    fn line_merge(&self) -> MultiLineString<T> {
+        let edges: Vec<&LineString<T>> = self.iter().filter(|ls| ls.0.len() >= 2).collect();
+        let first = |edge: usize| OrderedCoord(edges[edge].0[0]);
+        let last = |edge: usize| OrderedCoord(*edges[edge].0.last().unwrap());
+
+        // The edges ending at each node
+        let mut nodes: HashMap<OrderedCoord<T>, Vec<usize>> = HashMap::new();
+        for edge in 0..edges.len() {
+            nodes.entry(first(edge)).or_default().push(edge);
+            nodes.entry(last(edge)).or_default().push(edge);
+        }
+        // The other edge ending at `node`, if it is the only other one
+        let next_edge = |node: OrderedCoord<T>, edge: usize| match nodes[&node][..] {
+            [a, b] if a == edge => Some(b),
+            [a, b] if b == edge => Some(a),
+            _ => None,
+        };
+
+        let mut visited = vec![false; edges.len()];
+        let mut merged = vec![];
+        for start in 0..edges.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            // The edges of the chain, and whether each is reversed
+            let mut chain = vec![(start, false)];
+
+            // Extend forwards from the end of the starting edge
+            let mut node = last(start);
+            let mut edge = start;
+            while let Some(next) = next_edge(node, edge) {
+                if visited[next] {
+                    break;
+                }
+                visited[next] = true;
+                let reversed = first(next) != node;
+                node = if reversed { first(next) } else { last(next) };
+                chain.push((next, reversed));
+                edge = next;
+            }
+
+            // Then backwards from its start, unless the chain is a loop
+            let mut node = first(start);
+            let mut edge = start;
+            let mut before = vec![];
+            while let Some(previous) = next_edge(node, edge) {
+                if visited[previous] {
+                    break;
+                }
+                visited[previous] = true;
+                let reversed = last(previous) != node;
+                node = if reversed {
+                    last(previous)
+                } else {
+                    first(previous)
+                };
+                before.push((previous, reversed));
+                edge = previous;
+            }
+            before.reverse();
+            before.extend(chain);
+
+            let mut coords = vec![];
+            for (edge, reversed) in before {
+                let edge_coords = &edges[edge].0;
+                let skip = if coords.is_empty() { 0 } else { 1 };
+                if reversed {
+                    coords.extend(edge_coords.iter().rev().skip(skip));
+                } else {
+                    coords.extend(edge_coords.iter().skip(skip));
+                }
+            }
+            merged.push(LineString(coords));
+        }
+        MultiLineString(merged)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn colinear_fragments() {
+        // Out of order, and one of them reversed
+        let fragments = MultiLineString(vec![
+            line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 0.5, y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 2., y: 0.), (x: 1., y: 0.)],
+        ]);
+        assert_eq!(
+            fragments.line_merge(),
+            MultiLineString(vec![line_string![
+                (x: 0., y: 0.),
+                (x: 0.5, y: 0.),
+                (x: 1., y: 0.),
+                (x: 2., y: 0.),
+                (x: 3., y: 0.),
+            ]])
+        );
+    }
+
+    #[test]
+    fn y_junction() {
+        // Three branches meeting at the origin, one of them in two parts
+        let fragments = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 0., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: -1.)],
+            line_string![(x: -1., y: -1.), (x: 0., y: 0.)],
+            line_string![(x: 0., y: 1.), (x: 0., y: 2.)],
+        ]);
+        assert_eq!(
+            fragments.line_merge(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 0., y: 1.), (x: 0., y: 2.)],
+                line_string![(x: 0., y: 0.), (x: 1., y: -1.)],
+                line_string![(x: -1., y: -1.), (x: 0., y: 0.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn loops() {
+        // A triangle, which starts where its first part in the input does
+        let fragments = MultiLineString(vec![
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 1.), (x: 0., y: 0.)],
+        ]);
+        let merged = fragments.line_merge();
+        assert_eq!(
+            merged,
+            MultiLineString(vec![line_string![
+                (x: 1., y: 0.),
+                (x: 1., y: 1.),
+                (x: 0., y: 0.),
+                (x: 1., y: 0.),
+            ]])
+        );
+        assert!(merged.0[0].is_closed());
+
+        // A closed LineString stays as it is
+        let ring = MultiLineString(vec![line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 0.),
+        ]]);
+        assert_eq!(ring.line_merge(), ring);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiLineString<f64> = MultiLineString(vec![]);
+        assert_eq!(empty.line_merge(), empty);
+
+        let fragments = MultiLineString(vec![
+            line_string![],
+            line_string![(x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+        ]);
+        assert_eq!(
+            fragments.line_merge(),
+            MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]])
+        );
+    }
+}
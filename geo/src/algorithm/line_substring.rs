@@ -0,0 +1,192 @@
+use crate::{CoordFloat, Coordinate, LineString};
+
+/// Extract the part of a `LineString` between two _measures_: distances
+/// along it, from its start.
+///
+/// The result starts at the location `start_measure` along the
+/// `LineString`, follows it through every vertex in between, and ends at
+/// the location `end_measure` along it. New coordinates are interpolated
+/// for the ends, unless they fall on existing vertices.
+///
+/// If `start_measure` is greater than `end_measure`, the result runs
+/// backwards, from `start_measure` to `end_measure`. Measures beyond either
+/// end of the `LineString` are clamped to that end. If both measures fall
+/// at the same location, the result is the single coordinate there.
+///
+/// `None` is returned if the `LineString` is empty, or if either measure
+/// is `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_substring::LineSubstring;
+/// use geo::line_string;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.line_substring(2., 5.),
+///     Some(line_string![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.)])
+/// );
+/// ```
+pub trait LineSubstring<T: CoordFloat> {
+    fn line_substring(&self, start_measure: T, end_measure: T) -> Option<LineString<T>>;
+}
+
+impl<T: CoordFloat> LineSubstring<T> for LineString<T> {
+    fn line_substring(&self, start_measure: T, end_measure: T) -> Option<LineString<T>> {
+        if self.0.is_empty() || start_measure.is_nan() || end_measure.is_nan() {
+            return None;
+        }
+        let (from, to) = if start_measure <= end_measure {
+            (start_measure, end_measure)
+        } else {
+            (end_measure, start_measure)
+        };
+
+        let mut coords: Vec<Coordinate<T>> = vec![];
+        let mut push = |coord: Coordinate<T>| {
+            if coords.last() != Some(&coord) {
+                coords.push(coord);
+            }
+        };
+        if from <= T::zero() {
+            push(self.0[0]);
+        }
+        let mut start_measure_of_line = T::zero();
+        for line in self.lines() {
+            let delta = line.delta();
+            let length = delta.x.hypot(delta.y);
+            let end_measure_of_line = start_measure_of_line + length;
+            let at = |measure: T| {
+                if measure >= end_measure_of_line {
+                    line.end
+                } else {
+                    line.start + delta * ((measure - start_measure_of_line) / length)
+                }
+            };
+            if from > start_measure_of_line && from < end_measure_of_line {
+                push(at(from));
+            }
+            if to > start_measure_of_line && to < end_measure_of_line {
+                push(at(to));
+                break;
+            }
+            if from <= end_measure_of_line && to >= end_measure_of_line {
+                push(line.end);
+            }
+            start_measure_of_line = end_measure_of_line;
+        }
+        if coords.is_empty() {
+            // Both measures are beyond the end
+            coords.push(*self.0.last().unwrap());
+        }
+
+        if start_measure > end_measure {
+            coords.reverse();
+        }
+        Some(LineString(coords))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::line_string;
+
+    fn line_string() -> LineString<f64> {
+        line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 4.),
+            (x: 4., y: 4.),
+        ]
+    }
+
+    #[test]
+    fn middle_half() {
+        let line_string = line_string();
+        let length = line_string.euclidean_length();
+        assert_eq!(length, 8.);
+        let middle = line_string
+            .line_substring(length / 4., length * 3. / 4.)
+            .unwrap();
+        assert_eq!(middle, line_string![(x: 2., y: 0.), (x: 2., y: 4.)]);
+
+        let middle = line_string.line_substring(1., 7.).unwrap();
+        assert_eq!(
+            middle,
+            line_string![
+                (x: 1., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 4.),
+                (x: 3., y: 4.),
+            ]
+        );
+        assert_eq!(middle.euclidean_length(), 6.);
+    }
+
+    #[test]
+    fn reversed() {
+        let line_string = line_string();
+        assert_eq!(
+            line_string.line_substring(7., 1.).unwrap(),
+            line_string![
+                (x: 3., y: 4.),
+                (x: 2., y: 4.),
+                (x: 2., y: 0.),
+                (x: 1., y: 0.),
+            ]
+        );
+    }
+
+    #[test]
+    fn clamped() {
+        let line_string = line_string();
+        assert_eq!(line_string.line_substring(-5., 20.).unwrap(), line_string);
+        assert_eq!(
+            line_string.line_substring(-5., 1.).unwrap(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)]
+        );
+        assert_eq!(
+            line_string.line_substring(7.5, f64::INFINITY).unwrap(),
+            line_string![(x: 3.5, y: 4.), (x: 4., y: 4.)]
+        );
+    }
+
+    #[test]
+    fn zero_length() {
+        let line_string = line_string();
+        assert_eq!(
+            line_string.line_substring(3., 3.).unwrap(),
+            line_string![(x: 2., y: 1.)]
+        );
+        assert_eq!(
+            line_string.line_substring(2., 2.).unwrap(),
+            line_string![(x: 2., y: 0.)]
+        );
+        assert_eq!(
+            line_string.line_substring(0., 0.).unwrap(),
+            line_string![(x: 0., y: 0.)]
+        );
+        assert_eq!(
+            line_string.line_substring(9., 10.).unwrap(),
+            line_string![(x: 4., y: 4.)]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.line_substring(0., 1.), None);
+        assert_eq!(line_string().line_substring(f64::NAN, 1.), None);
+
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.line_substring(0., 1.), Some(single));
+    }
+}
@@ -0,0 +1,383 @@
+use crate::algorithm::bool_ops::overlay::{
+    assemble_rings, build_polygons, edge_key, self_node_edges,
+};
+use crate::algorithm::winding_number::crossing;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, MultiPolygon, Point, Polygon};
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+/// Repair an invalid areal geometry, similarly to PostGIS's
+/// [`ST_MakeValid`].
+///
+/// The rings of each `Polygon` are interpreted with the _even-odd_ rule: a
+/// point is inside the polygon if it lies inside an odd number of its
+/// rings, regardless of whether each ring is an exterior or an interior,
+/// and of its orientation. The polygons of a `MultiPolygon` are then
+/// merged where they overlap or share an edge.
+///
+/// This repairs, amongst others:
+///
+/// - self-intersecting rings, such as "bowties", which are split into
+///   pieces at their crossings
+/// - rings with the wrong orientation
+/// - interiors which cross or touch the exterior, or each other
+/// - zero-area spikes and repeated coordinates
+///
+/// The output is valid, with exteriors oriented counter-clockwise and
+/// interiors clockwise. Its point set approximates that of the input, but
+/// its topology may differ: a single polygon may become several, and
+/// rings are split or joined where they meet. Vertex counts may change
+/// too, as vertices are added at crossings and the rings may start at a
+/// different coordinate. Collinear vertices are not removed.
+///
+/// [`ST_MakeValid`]: https://postgis.net/docs/ST_MakeValid.html
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::make_valid::MakeValid;
+/// use geo::{polygon, MultiPolygon};
+///
+/// // A "bowtie", whose exterior crosses itself at (1, 1)
+/// let bowtie = MultiPolygon(vec![polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 0.),
+///     (x: 0., y: 2.),
+/// ]]);
+/// assert_eq!(bowtie.signed_area(), 0.);
+///
+/// let valid = bowtie.make_valid();
+/// assert_eq!(valid.0.len(), 2);
+/// assert_eq!(valid.signed_area(), 2.);
+/// ```
+pub trait MakeValid<T: GeoFloat> {
+    fn make_valid(&self) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid<T> for Polygon<T> {
+    fn make_valid(&self) -> MultiPolygon<T> {
+        resolve(ring_edges(self), |winding| winding % 2 != 0)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> MakeValid<T> for MultiPolygon<T> {
+    fn make_valid(&self) -> MultiPolygon<T> {
+        let mut edges = vec![];
+        for polygon in self {
+            // The repaired pieces are oriented consistently, so that
+            // overlaps have a winding number greater than one
+            let pieces = resolve(ring_edges(polygon), |winding| winding % 2 != 0);
+            for piece in pieces {
+                edges.extend(ring_edges(&piece));
+            }
+        }
+        resolve(edges, |winding| winding > 0)
+    }
+}
+
+/// The non-degenerate edges of all the rings of `polygon`, as they are.
+fn ring_edges<T: GeoFloat>(polygon: &Polygon<T>) -> Vec<Line<T>> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.lines())
+        .filter(|line| line.start != line.end)
+        .collect()
+}
+
+/// A merged edge, with the net number of times it is traversed and its
+/// index, to be stored in an `RTree`.
+struct IndexedEdge<T: GeoFloat + RTreeNum> {
+    edge: Line<T>,
+    count: i32,
+    index: usize,
+}
+
+impl<T: GeoFloat + RTreeNum> RTreeObject for IndexedEdge<T> {
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(Point(self.edge.start), Point(self.edge.end))
+    }
+}
+
+/// Build the polygons covering the points whose winding number, with
+/// respect to the closed paths formed by `edges`, is accepted by `inside`.
+fn resolve<T: GeoFloat + RTreeNum>(
+    mut edges: Vec<Line<T>>,
+    inside: impl Fn(i32) -> bool,
+) -> MultiPolygon<T> {
+    self_node_edges(&mut edges);
+
+    // Merge identical edges: each undirected edge, from the lesser to the
+    // greater of its end points, with the net number of times it is
+    // traversed in that direction
+    edges.sort_by(|a, b| {
+        let (a, b) = (edge_key(a), edge_key(b));
+        lex_cmp(&a.0, &b.0).then_with(|| lex_cmp(&a.1, &b.1))
+    });
+    let mut merged: Vec<(Line<T>, i32)> = vec![];
+    for edge in edges {
+        let (start, end) = edge_key(&edge);
+        let count = if start == edge.start { 1 } else { -1 };
+        match merged.last_mut() {
+            Some((last, total)) if last.start == start && last.end == end => *total += count,
+            _ => merged.push((Line::new(start, end), count)),
+        }
+    }
+    // Edges traversed equally often in both directions bound nothing
+    merged.retain(|&(_, count)| count != 0);
+
+    // Winding numbers are found by casting a ray towards positive `x`,
+    // which misses horizontal edges; these are handled by rotating all
+    // the edges a quarter turn.
+    let rotate = |coord: Coordinate<T>| Coordinate {
+        x: -coord.y,
+        y: coord.x,
+    };
+    let rotated: Vec<(Line<T>, i32)> = merged
+        .iter()
+        .map(|&(edge, count)| (Line::new(rotate(edge.start), rotate(edge.end)), count))
+        .collect();
+
+    // Only the edges whose bounding rects meet a ray can cross it, so the
+    // edges of each frame are indexed to find them
+    let index = |frame: &[(Line<T>, i32)]| {
+        let max_x = frame.iter().fold(T::neg_infinity(), |max_x, (edge, _)| {
+            max_x.max(edge.start.x).max(edge.end.x)
+        });
+        let edges = frame
+            .iter()
+            .enumerate()
+            .map(|(index, &(edge, count))| IndexedEdge { edge, count, index })
+            .collect();
+        (RTree::bulk_load(edges), max_x)
+    };
+    let (merged_tree, merged_max_x) = index(&merged);
+    let (rotated_tree, rotated_max_x) = index(&rotated);
+
+    let two = T::one() + T::one();
+    let mut selected = vec![];
+    for (idx, &(edge, _)) in merged.iter().enumerate() {
+        let (frame, tree, max_x) = if edge.start.y == edge.end.y {
+            (&rotated, &rotated_tree, rotated_max_x)
+        } else {
+            (&merged, &merged_tree, merged_max_x)
+        };
+        let (frame_edge, count) = frame[idx];
+        let midpoint = (frame_edge.start + frame_edge.end) / two;
+        let ray = AABB::from_corners(Point(midpoint), Point::new(max_x, midpoint.y));
+        let beyond: i32 = tree
+            .locate_in_envelope_intersecting(&ray)
+            .filter(|other| other.index != idx)
+            .map(|other| other.count * crossing(&other.edge, midpoint))
+            .sum();
+        // Crossing an edge from its right to its left increases the
+        // winding number by its count
+        let (left, right) = if frame_edge.end.y > frame_edge.start.y {
+            (beyond + count, beyond)
+        } else {
+            (beyond, beyond - count)
+        };
+        match (inside(left), inside(right)) {
+            (true, false) => selected.push(edge),
+            (false, true) => selected.push(Line::new(edge.end, edge.start)),
+            _ => {}
+        }
+    }
+
+    build_polygons(assemble_rings(selected))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::winding_number::{FillRule, WindingNumber};
+    use crate::algorithm::winding_order::Winding;
+    use crate::{point, polygon, LineString};
+
+    fn assert_oriented(multi_polygon: &MultiPolygon<f64>) {
+        for polygon in multi_polygon {
+            assert!(polygon.exterior().is_ccw());
+            for interior in polygon.interiors() {
+                assert!(interior.is_cw());
+            }
+        }
+    }
+
+    #[test]
+    fn bowtie() {
+        let bowtie = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+        ];
+        let valid = MultiPolygon(vec![bowtie]).make_valid();
+        assert_oriented(&valid);
+        assert_eq!(valid.0.len(), 2);
+        for triangle in &valid {
+            assert_eq!(triangle.exterior().0.len(), 4);
+            assert!(triangle.interiors().is_empty());
+            assert_eq!(triangle.unsigned_area(), 1.);
+            assert!(triangle.exterior().0.contains(&Coordinate { x: 1., y: 1. }));
+        }
+        assert!(valid.contains(&point!(x: 0.5, y: 1.)));
+        assert!(valid.contains(&point!(x: 1.5, y: 1.)));
+        assert!(!valid.contains(&point!(x: 1., y: 0.5)));
+    }
+
+    #[test]
+    fn inverted_hole() {
+        // Both rings are counter-clockwise
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (1., 1.),
+                (3., 1.),
+                (3., 3.),
+                (1., 3.),
+                (1., 1.),
+            ])],
+        );
+        let valid = MultiPolygon(vec![polygon]).make_valid();
+        assert_oriented(&valid);
+        assert_eq!(valid.0.len(), 1);
+        assert_eq!(valid.0[0].interiors().len(), 1);
+        assert_eq!(valid.signed_area(), 12.);
+        assert!(!valid.contains(&point!(x: 2., y: 2.)));
+    }
+
+    #[test]
+    fn orientation() {
+        let clockwise = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+        ];
+        let valid = clockwise.make_valid();
+        assert_oriented(&valid);
+        assert_eq!(valid.signed_area(), 4.);
+    }
+
+    #[test]
+    fn spike() {
+        // A zero-area spike out of the top edge
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 1., y: 2.),
+            (x: 1., y: 5.),
+            (x: 1., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let valid = polygon.make_valid();
+        assert_eq!(valid.0.len(), 1);
+        assert_eq!(valid.signed_area(), 4.);
+        assert!(valid.0[0]
+            .exterior()
+            .points_iter()
+            .all(|point| point.y() <= 2.));
+    }
+
+    #[test]
+    fn retraced_spike_crossing() {
+        // The edges to and from (2, 4) are retraced in opposite directions,
+        // and cross the closing edges at inexact points
+        let polygon = polygon![
+            (x: 2., y: 5.),
+            (x: 7., y: 9.),
+            (x: 2., y: 4.),
+            (x: 0., y: 0.),
+            (x: 2., y: 4.),
+            (x: 1., y: 0.),
+            (x: 0., y: 1.),
+        ];
+        let valid = polygon.make_valid();
+        assert_oriented(&valid);
+        assert_relative_eq!(valid.unsigned_area(), 6., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn overlapping_edges_crossing() {
+        // The edges along y = 4 partly overlap, and are both crossed at an
+        // inexact point by the first edge
+        let polygon = polygon![
+            (x: 1., y: 0.),
+            (x: 5., y: 6.),
+            (x: 6., y: 6.),
+            (x: 5., y: 2.),
+            (x: 0., y: 4.),
+            (x: 6., y: 4.),
+            (x: 3., y: 4.),
+        ];
+        let valid = polygon.make_valid();
+        assert_oriented(&valid);
+        for i in 0..70 {
+            for j in 0..70 {
+                let point = point!(x: 0.0137 + i as f64 * 0.1, y: 0.0071 + j as f64 * 0.1);
+                assert_eq!(
+                    valid.contains(&point),
+                    polygon.contains_with_fill_rule(point, FillRule::EvenOdd)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn overlapping_polygons() {
+        let multi_polygon = MultiPolygon(vec![
+            polygon![
+                (x: 0., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 2.),
+                (x: 0., y: 2.),
+            ],
+            polygon![
+                (x: 1., y: 1.),
+                (x: 3., y: 1.),
+                (x: 3., y: 3.),
+                (x: 1., y: 3.),
+            ],
+            // Shares an edge with the first
+            polygon![
+                (x: 0., y: 0.),
+                (x: 0., y: -1.),
+                (x: 2., y: -1.),
+                (x: 2., y: 0.),
+            ],
+        ]);
+        let valid = multi_polygon.make_valid();
+        assert_oriented(&valid);
+        assert_eq!(valid.0.len(), 1);
+        assert!(valid.0[0].interiors().is_empty());
+        assert_eq!(valid.signed_area(), 9.);
+    }
+
+    #[test]
+    fn valid_unchanged() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+            ],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)],
+            ],
+        ];
+        let valid = polygon.make_valid();
+        assert_oriented(&valid);
+        assert_eq!(valid.0.len(), 1);
+        assert_eq!(valid.signed_area(), polygon.signed_area());
+
+        let empty: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert!(empty.make_valid().0.is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+use crate::algorithm::convex_hull::quick_hull;
+use crate::utils::least_index;
+use crate::{Coordinate, GeoNum, LineString, Polygon};
+
+/// Calculate the Minkowski sum of two `Polygon`s: the shape swept out by
+/// one as its origin is moved over every point of the other, such as the
+/// space a robot of one shape can't enter around an obstacle.
+///
+/// Both polygons should be convex. The sum is taken of their convex hulls,
+/// ignoring any interiors, so a concave polygon is treated as its hull
+/// and should be split into convex pieces first, whose sums can then be
+/// joined. The result is convex too, with no interiors, and a closed
+/// exterior wound counter-clockwise from its leftmost (then lowest)
+/// vertex. If either polygon is empty, so is the result.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::minkowski_sum::MinkowskiSum;
+/// use geo::{line_string, polygon};
+///
+/// let square = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2)];
+/// let diamond = polygon![(x: 0, y: -1), (x: 1, y: 0), (x: 0, y: 1), (x: -1, y: 0)];
+///
+/// // The square, with its corners cut off
+/// let sum = square.minkowski_sum(&diamond);
+/// assert_eq!(
+///     *sum.exterior(),
+///     line_string![
+///         (x: -1, y: 0),
+///         (x: 0, y: -1),
+///         (x: 2, y: -1),
+///         (x: 3, y: 0),
+///         (x: 3, y: 2),
+///         (x: 2, y: 3),
+///         (x: 0, y: 3),
+///         (x: -1, y: 2),
+///         (x: -1, y: 0),
+///     ]
+/// );
+/// ```
+pub trait MinkowskiSum<T: GeoNum> {
+    fn minkowski_sum(&self, other: &Polygon<T>) -> Polygon<T>;
+}
+
+impl<T: GeoNum> MinkowskiSum<T> for Polygon<T> {
+    fn minkowski_sum(&self, other: &Polygon<T>) -> Polygon<T> {
+        let (a, b) = (hull(self), hull(other));
+        if a.is_empty() || b.is_empty() {
+            return Polygon::new(LineString(vec![]), vec![]);
+        }
+        if a.len() < 3 || b.len() < 3 {
+            // A point or segment has edges in opposite directions, which
+            // can't be merged by angle, but the sum is small enough to
+            // take the hull of every pair
+            let mut sums: Vec<_> = a
+                .iter()
+                .flat_map(|&p| b.iter().map(move |&q| p + q))
+                .collect();
+            let mut exterior = quick_hull(&mut sums).0;
+            exterior.pop();
+            let start = least_index(&exterior);
+            exterior.rotate_left(start);
+            exterior.push(exterior[0]);
+            return Polygon::new(LineString(exterior), vec![]);
+        }
+
+        // Starting from the least vertex of each, which add up to the
+        // least vertex of the sum, merge the edges of both in order of
+        // their angles, adding parallel edges into one
+        let (n, m) = (a.len(), b.len());
+        let (a_start, b_start) = (least_index(&a), least_index(&b));
+        let vertex_a = |idx: usize| a[(a_start + idx) % n];
+        let vertex_b = |idx: usize| b[(b_start + idx) % m];
+        let cross = |u: Coordinate<T>, v: Coordinate<T>| u.x * v.y - u.y * v.x;
+        let mut exterior = Vec::with_capacity(n + m + 1);
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            exterior.push(vertex_a(i) + vertex_b(j));
+            let turn = cross(vertex_a(i + 1) - vertex_a(i), vertex_b(j + 1) - vertex_b(j));
+            let (next_a, next_b) = (
+                j == m || (i < n && turn >= T::zero()),
+                i == n || (j < m && turn <= T::zero()),
+            );
+            if next_a {
+                i += 1;
+            }
+            if next_b {
+                j += 1;
+            }
+        }
+        let mut exterior = LineString(exterior);
+        exterior.close();
+        Polygon::new(exterior, vec![])
+    }
+}
+
+/// The vertices of the convex hull of a polygon's exterior, counter-clockwise
+/// and unclosed.
+fn hull<T: GeoNum>(polygon: &Polygon<T>) -> Vec<Coordinate<T>> {
+    if polygon.exterior().0.is_empty() {
+        return vec![];
+    }
+    let mut hull = quick_hull(&mut polygon.exterior().0.clone()).0;
+    hull.pop();
+    hull.dedup();
+    hull
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::winding_order::Winding;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn squares() {
+        let small = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 1)];
+        let large = polygon![(x: 5, y: 5), (x: 7, y: 5), (x: 7, y: 7), (x: 5, y: 7)];
+        let expected = line_string![
+            (x: 5, y: 5),
+            (x: 8, y: 5),
+            (x: 8, y: 8),
+            (x: 5, y: 8),
+            (x: 5, y: 5),
+        ];
+        assert_eq!(*small.minkowski_sum(&large).exterior(), expected);
+        assert_eq!(*large.minkowski_sum(&small).exterior(), expected);
+
+        // The winding and starting vertex of the inputs doesn't matter
+        let clockwise = polygon![(x: 1, y: 1), (x: 1, y: 0), (x: 0, y: 0), (x: 0, y: 1)];
+        assert_eq!(*clockwise.minkowski_sum(&large).exterior(), expected);
+    }
+
+    #[test]
+    fn square_and_triangle() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let triangle = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+        let sum = square.minkowski_sum(&triangle);
+        assert_eq!(
+            *sum.exterior(),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 3., y: 0.),
+                (x: 3., y: 2.),
+                (x: 2., y: 3.),
+                (x: 0., y: 3.),
+                (x: 0., y: 0.),
+            ]
+        );
+        assert!(sum.exterior().is_ccw());
+        // The square, grown by the triangle's sides, and its corner
+        assert_relative_eq!(sum.unsigned_area(), 4. + 2. * 2. + 0.5);
+        assert_eq!(sum, triangle.minkowski_sum(&square));
+    }
+
+    #[test]
+    fn concave() {
+        // An L shape is treated as its hull
+        let l_shape = polygon![
+            (x: 0, y: 0),
+            (x: 2, y: 0),
+            (x: 2, y: 1),
+            (x: 1, y: 1),
+            (x: 1, y: 2),
+            (x: 0, y: 2),
+        ];
+        let point = polygon![(x: 10, y: 10)];
+        assert_eq!(
+            *l_shape.minkowski_sum(&point).exterior(),
+            line_string![
+                (x: 10, y: 10),
+                (x: 12, y: 10),
+                (x: 12, y: 11),
+                (x: 11, y: 12),
+                (x: 10, y: 12),
+                (x: 10, y: 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let square = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 1)];
+        let segment = polygon![(x: 0, y: 0), (x: 3, y: 0)];
+        assert_eq!(
+            *square.minkowski_sum(&segment).exterior(),
+            line_string![
+                (x: 0, y: 0),
+                (x: 4, y: 0),
+                (x: 4, y: 1),
+                (x: 0, y: 1),
+                (x: 0, y: 0),
+            ]
+        );
+        let sum = segment.minkowski_sum(&segment);
+        assert!(sum.exterior().points_iter().all(|point| point.y() == 0));
+        let point = polygon![(x: 1, y: 2)];
+        assert_eq!(
+            *point.minkowski_sum(&point).exterior(),
+            line_string![(x: 2, y: 4), (x: 2, y: 4)]
+        );
+
+        let empty = Polygon::new(LineString(vec![]), vec![]);
+        assert!(square.minkowski_sum(&empty).exterior().0.is_empty());
+        assert!(empty.minkowski_sum(&square).exterior().0.is_empty());
+    }
+}
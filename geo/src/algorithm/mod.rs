@@ -5,12 +5,24 @@ pub mod kernels;
 pub mod area;
 /// Calculate the bearing to another `Point`, in degrees.
 pub mod bearing;
+/// Find every intersection among a set of segments with a sweep line.
+pub mod bentley_ottmann;
+/// Boolean operations, such as intersection, on areal `Geometries`.
+pub mod bool_ops;
+/// Calculate the minimum bounding circle of a `Geometry`.
+pub mod bounding_circle;
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
+/// Smooth a `Geometry` by cutting its corners, using Chaikin's algorithm.
+pub mod chaikin_smoothing;
 /// Calculate the signed approximate geodesic area of a `Geometry`.
 pub mod chamberlain_duquette_area;
+/// Clip a `Geometry` to a `Rect`.
+pub mod clip_to_rect;
+/// Find the two closest `Point`s of a `MultiPoint`.
+pub mod closest_pair;
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
 pub mod closest_point;
 /// Calculate the concave hull of a `Geometry`.
@@ -23,6 +35,14 @@ pub mod convex_hull;
 pub mod coordinate_position;
 /// Iterate over geometry coordinates.
 pub mod coords_iter;
+/// Cluster the `Point`s of a `MultiPoint` by density.
+pub mod dbscan;
+/// Compute the Delaunay triangulation of a set of points.
+pub mod delaunay_triangulation;
+/// Find the farthest apart pair of coordinates of a geometry.
+pub mod diameter;
+/// Find the coordinate-level changes between two `Geometries`.
+pub mod diff;
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
 /// Calculate the minimum Euclidean distance between two `Geometries`.
@@ -31,8 +51,14 @@ pub mod euclidean_distance;
 pub mod euclidean_length;
 /// Calculate the extreme coordinates and indices of a geometry.
 pub mod extremes;
+/// Scale and translate a geometry to fit a target `Rect`.
+pub mod fit_to_rect;
 /// Calculate the Frechet distance between two `LineStrings`.
 pub mod frechet_distance;
+/// Calculate the Geodesic perimeter and area of a `Polygon`.
+pub mod geodesic_area;
+/// Calculate the Geodesic azimuth of a `Line`.
+pub mod geodesic_azimuth;
 /// Calculate the Geodesic distance between two `Point`s.
 pub mod geodesic_distance;
 /// Calculate a new `Point` lying on a Geodesic arc between two `Point`s.
@@ -47,34 +73,107 @@ pub mod haversine_distance;
 pub mod haversine_intermediate;
 /// Calculate the Haversine length of a Line.
 pub mod haversine_length;
+/// Cover a `Rect` with a grid of regular hexagons.
+pub mod hex_grid;
+/// Estimate a value at a `Point` by inverse distance weighting of samples.
+pub mod idw_interpolate;
+/// Insert a vertex into a `LineString` at the closest location to a `Point`.
+pub mod insert_point_on_line;
+/// Calculate the Intersection over Union of two areal `Geometries`.
+pub mod intersection_over_union;
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
 /// Determins whether a `LineString` is convex.
 pub mod is_convex;
+/// Find the largest polygons of a `MultiPolygon` by area.
+pub mod largest_polygon;
 /// Interpolate a point along a `Line` or `LineString`.
 pub mod line_interpolate_point;
 /// Locate a point along a `Line` or `LineString`.
 pub mod line_locate_point;
+/// Merge the parts of a `MultiLineString` which meet end to end.
+pub mod line_merge;
+/// Extract the part of a `LineString` between two distances along it.
+pub mod line_substring;
+/// Repair invalid `Polygon`s and `MultiPolygon`s.
+pub mod make_valid;
 /// Apply a function to all `Coordinates` of a `Geometry`.
 pub mod map_coords;
+/// Calculate the Minkowski sum of two convex `Polygon`s.
+pub mod minkowski_sum;
+/// Convert a `Geometry` to a canonical form, for comparing representations.
+pub mod normalize;
+/// Offset a `LineString` to one side, producing a parallel line.
+pub mod offset;
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
+/// Calculate the length of the boundary of a `Polygon`, including its holes.
+pub mod perimeter;
+/// Find the point inside a `Polygon` farthest from its boundary.
+pub mod pole_of_inaccessibility;
+/// Build a `Polygon` ring by ring, optionally validating it.
+pub mod polygon_builder;
 /// Helper functions for the "fast path" variant of the Polygon-Polygon Euclidean distance method.
 pub(crate) mod polygon_distance_fast_path;
+/// Prepare a `Geometry` for repeated `Contains` and `Intersects` queries.
+pub mod prepared_geometry;
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
+/// Project a `Point` onto a `LineString`, finding its distance along it.
+pub mod project_point;
+/// Hash a `Geometry` with its coordinates rounded, for caching.
+pub mod quantized_hash;
+/// Remove narrow spikes from the rings of a `Polygon`.
+pub mod remove_spikes;
+/// Resample a `LineString` at a fixed interval along its path.
+pub mod resample;
 /// Rotate a `Geometry` around either its centroid or a `Point` by an angle given in degrees.
 pub mod rotate;
+/// Round the coordinates of a `Geometry` to a number of decimal places.
+pub mod round_coords;
+/// Round the corners of a `LineString` with circular arcs.
+pub mod round_corners;
+/// Sample the interior of a `Polygon` on a regular grid of points.
+pub mod sample_grid;
+/// Scale a `Geometry` about its center or an arbitrary `Point`.
+pub mod scale;
+/// Find the polygons of a `MultiPolygon` which overlap each other.
+pub mod self_overlaps;
+/// Determine which side of a directed `Line` a `Point` lies on.
+pub mod side_of;
+/// Calculate the signed distance from a `Point` to the boundary of a `Polygon`.
+pub mod signed_distance;
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplifyvw;
+/// Find the pairs of `Geometries` from two sets which satisfy a predicate, using an R-tree.
+pub mod spatial_join;
+/// Split a `Polygon` into pieces along a `LineString`.
+pub mod split;
+/// Split a `LineString` wherever it meets itself, into simple parts.
+pub mod split_at_self_intersections;
+/// Cover a `Rect` with a grid of square cells, and bin points into them.
+pub mod square_grid;
+/// Compute the straight skeleton of a `Polygon`, the paths of its vertices as it shrinks.
+pub mod straight_skeleton;
+/// Approximate a circle or ellipse around a `Point` as a `Polygon`.
+pub mod to_circle;
 /// Translate a `Geometry` along the given offsets.
 pub mod translate;
+/// Triangulate a `Polygon` by ear clipping.
+pub mod triangulate;
+/// Calculate the turn angle at each vertex of a `LineString`.
+pub mod vertex_angles;
 /// Calculate the Vincenty distance between two `Point`s.
 pub mod vincenty_distance;
 /// Calculate the Vincenty length of a `LineString`.
 pub mod vincenty_length;
+/// Compute the Voronoi cells of a set of points, clipped to a `Rect`.
+pub mod voronoi_cells;
+/// Calculate the winding number of a `Point` with respect to a polygon, and
+/// test containment under either fill rule.
+pub mod winding_number;
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
@@ -0,0 +1,438 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::kernels::*;
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, OrderedCoord, Point, Polygon, Rect, Triangle,
+};
+use std::cmp::Ordering;
+
+/// Convert a geometry to a canonical form, so that different
+/// representations of the same geometry become equal.
+///
+/// Coordinates are compared lexicographically, first by `x` then by `y`,
+/// and sequences of coordinates are compared lexicographically, coordinate
+/// by coordinate (as for [`OrderedCoord`]). The canonical form is then:
+///
+/// - A `Line`, or a `LineString` which isn't closed, runs in whichever of
+///   its two directions gives the lesser sequence of coordinates.
+/// - The rings of a `Polygon` start (and end) at their least coordinate.
+///   The exterior is oriented counter-clockwise and the interiors
+///   clockwise, and the interiors are sorted by their coordinates.
+/// - A closed `LineString`, or a ring without a winding order (i.e. with
+///   fewer than three distinct coordinates), also starts at its least
+///   coordinate, and runs in whichever direction gives the lesser
+///   sequence.
+/// - A `Triangle` is oriented counter-clockwise, starting at its least
+///   coordinate.
+/// - The parts of a `MultiPoint`, `MultiLineString` or `MultiPolygon` are
+///   normalized, then sorted by their coordinates. The members of a
+///   `GeometryCollection` are normalized, then sorted by type (in the
+///   order of the variants of [`Geometry`]), then by their coordinates.
+/// - A `Point` or `Rect` is already in canonical form.
+///
+/// Only the order of coordinates and parts is changed: repeated
+/// coordinates, collinear vertices or overlapping parts aren't removed,
+/// so geometries covering the same points with different vertices remain
+/// different.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::normalize::Normalize;
+/// use geo::polygon;
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 1., y: 1.),
+///     (x: 0., y: 1.),
+/// ];
+/// // Starting at another coordinate, and clockwise
+/// let other = polygon![
+///     (x: 1., y: 1.),
+///     (x: 1., y: 0.),
+///     (x: 0., y: 0.),
+///     (x: 0., y: 1.),
+/// ];
+///
+/// assert_ne!(square, other);
+/// assert_eq!(square.normalize(), other.normalize());
+/// assert_eq!(square.normalize(), square);
+/// ```
+pub trait Normalize<T: GeoFloat> {
+    fn normalize(&self) -> Self;
+}
+
+impl<T: GeoFloat> Normalize<T> for Point<T> {
+    fn normalize(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for Line<T> {
+    fn normalize(&self) -> Self {
+        if OrderedCoord::from(self.end) < OrderedCoord::from(self.start) {
+            Line::new(self.end, self.start)
+        } else {
+            *self
+        }
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for LineString<T> {
+    fn normalize(&self) -> Self {
+        if self.0.len() > 1 && self.is_closed() {
+            return normalize_ring(self, None);
+        }
+        let forward = sort_key(self);
+        let mut backward = forward.clone();
+        backward.reverse();
+        LineString(
+            forward
+                .min(backward)
+                .into_iter()
+                .map(Coordinate::from)
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for Polygon<T> {
+    fn normalize(&self) -> Self {
+        let exterior = normalize_ring(self.exterior(), Some(WindingOrder::CounterClockwise));
+        let mut interiors: Vec<_> = self
+            .interiors()
+            .iter()
+            .map(|interior| normalize_ring(interior, Some(WindingOrder::Clockwise)))
+            .collect();
+        interiors.sort_by_cached_key(|part| sort_key(part));
+        Polygon::new(exterior, interiors)
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for MultiPoint<T> {
+    fn normalize(&self) -> Self {
+        let mut points = self.0.clone();
+        points.sort_by_key(|point| OrderedCoord::from(*point));
+        MultiPoint(points)
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for MultiLineString<T> {
+    fn normalize(&self) -> Self {
+        let mut line_strings: Vec<_> = self.iter().map(Normalize::normalize).collect();
+        line_strings.sort_by_cached_key(|part| sort_key(part));
+        MultiLineString(line_strings)
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for MultiPolygon<T> {
+    fn normalize(&self) -> Self {
+        let mut polygons: Vec<_> = self.iter().map(Normalize::normalize).collect();
+        polygons.sort_by_cached_key(|part| sort_key(part));
+        MultiPolygon(polygons)
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for Rect<T> {
+    fn normalize(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for Triangle<T> {
+    fn normalize(&self) -> Self {
+        let mut coords = [self.0, self.1, self.2];
+        if T::Ker::orient2d(coords[0], coords[1], coords[2]) == Orientation::Clockwise {
+            coords.swap(1, 2);
+        }
+        let mut key = least_rotation(&coords);
+        if T::Ker::orient2d(coords[0], coords[1], coords[2]) == Orientation::Collinear {
+            coords.reverse();
+            key = key.min(least_rotation(&coords));
+        }
+        Triangle(key[0].into(), key[1].into(), key[2].into())
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for GeometryCollection<T> {
+    fn normalize(&self) -> Self {
+        let mut geometries: Vec<_> = self.iter().map(Normalize::normalize).collect();
+        geometries.sort_by(|a, b| {
+            variant_index(a)
+                .cmp(&variant_index(b))
+                .then_with(|| cmp_coords(a, b))
+        });
+        GeometryCollection(geometries)
+    }
+}
+
+impl<T: GeoFloat> Normalize<T> for Geometry<T> {
+    fn normalize(&self) -> Self {
+        match self {
+            Geometry::Point(x) => Geometry::Point(x.normalize()),
+            Geometry::Line(x) => Geometry::Line(x.normalize()),
+            Geometry::LineString(x) => Geometry::LineString(x.normalize()),
+            Geometry::Polygon(x) => Geometry::Polygon(x.normalize()),
+            Geometry::MultiPoint(x) => Geometry::MultiPoint(x.normalize()),
+            Geometry::MultiLineString(x) => Geometry::MultiLineString(x.normalize()),
+            Geometry::MultiPolygon(x) => Geometry::MultiPolygon(x.normalize()),
+            Geometry::GeometryCollection(x) => Geometry::GeometryCollection(x.normalize()),
+            Geometry::Rect(x) => Geometry::Rect(x.normalize()),
+            Geometry::Triangle(x) => Geometry::Triangle(x.normalize()),
+        }
+    }
+}
+
+/// Rotate a closed ring to start at its least coordinate, and orient it
+/// to `winding` if it has a winding order, or else in whichever direction
+/// gives the lesser sequence.
+fn normalize_ring<T: GeoFloat>(
+    ring: &LineString<T>,
+    winding: Option<WindingOrder>,
+) -> LineString<T> {
+    if ring.0.len() < 2 {
+        return ring.clone();
+    }
+    let mut ring = ring.clone();
+    ring.close();
+    let mut coords = ring.0[..ring.0.len() - 1].to_vec();
+
+    let key = match (winding, ring.winding_order()) {
+        (Some(winding), Some(current)) => {
+            if winding != current {
+                coords.reverse();
+            }
+            least_rotation(&coords)
+        }
+        _ => {
+            let forward = least_rotation(&coords);
+            coords.reverse();
+            forward.min(least_rotation(&coords))
+        }
+    };
+
+    let mut coords: Vec<Coordinate<T>> = key.into_iter().map(Coordinate::from).collect();
+    coords.push(coords[0]);
+    LineString(coords)
+}
+
+/// The least of the rotations of a sequence of coordinates.
+fn least_rotation<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<OrderedCoord<T>> {
+    let ordered: Vec<OrderedCoord<T>> = coords.iter().copied().map(OrderedCoord::from).collect();
+    let least = match ordered.iter().min() {
+        Some(least) => *least,
+        None => return ordered,
+    };
+    ordered
+        .iter()
+        .enumerate()
+        .filter(|(_, coord)| **coord == least)
+        .map(|(idx, _)| {
+            let mut rotation = ordered.clone();
+            rotation.rotate_left(idx);
+            rotation
+        })
+        .min()
+        .unwrap()
+}
+
+fn sort_key<'a, G>(geometry: &'a G) -> Vec<OrderedCoord<G::Scalar>>
+where
+    G: CoordsIter<'a>,
+    G::Scalar: GeoFloat,
+{
+    geometry.coords_iter().map(OrderedCoord::from).collect()
+}
+
+fn cmp_coords<'a, G>(a: &'a G, b: &'a G) -> Ordering
+where
+    G: CoordsIter<'a>,
+    G::Scalar: GeoFloat,
+{
+    a.coords_iter()
+        .map(OrderedCoord::from)
+        .cmp(b.coords_iter().map(OrderedCoord::from))
+}
+
+fn variant_index<T: GeoFloat>(geometry: &Geometry<T>) -> u8 {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::Line(_) => 1,
+        Geometry::LineString(_) => 2,
+        Geometry::Polygon(_) => 3,
+        Geometry::MultiPoint(_) => 4,
+        Geometry::MultiLineString(_) => 5,
+        Geometry::MultiPolygon(_) => 6,
+        Geometry::GeometryCollection(_) => 7,
+        Geometry::Rect(_) => 8,
+        Geometry::Triangle(_) => 9,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn polygon_start_and_winding() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+            ],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)],
+                [(x: 3., y: 3.), (x: 2.5, y: 3.), (x: 2.5, y: 2.5)],
+            ],
+        ];
+        // The same polygon, with rings starting at other coordinates and
+        // wound the other way, and the interiors swapped
+        let other = polygon![
+            exterior: [
+                (x: 4., y: 4.),
+                (x: 4., y: 0.),
+                (x: 0., y: 0.),
+                (x: 0., y: 4.),
+            ],
+            interiors: [
+                [(x: 2.5, y: 2.5), (x: 3., y: 3.), (x: 2.5, y: 3.)],
+                [(x: 2., y: 2.), (x: 1., y: 2.), (x: 1., y: 1.), (x: 2., y: 1.)],
+            ],
+        ];
+        assert_ne!(polygon, other);
+        assert_eq!(polygon.normalize(), other.normalize());
+        assert_eq!(
+            polygon.normalize(),
+            polygon![
+                exterior: [
+                    (x: 0., y: 0.),
+                    (x: 4., y: 0.),
+                    (x: 4., y: 4.),
+                    (x: 0., y: 4.),
+                ],
+                interiors: [
+                    [(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)],
+                    [(x: 2.5, y: 2.5), (x: 2.5, y: 3.), (x: 3., y: 3.)],
+                ],
+            ]
+        );
+        // Normalizing is idempotent
+        assert_eq!(polygon.normalize().normalize(), polygon.normalize());
+
+        // A different polygon remains different
+        let shifted = polygon![
+            (x: 1., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        assert_ne!(polygon.normalize(), shifted.normalize());
+    }
+
+    #[test]
+    fn ring_touching_its_least_coordinate_twice() {
+        // Two triangles meeting at the origin
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: -1.),
+            (x: 2., y: 1.),
+            (x: 0., y: 0.),
+            (x: 1., y: 2.),
+            (x: 0., y: 3.),
+            (x: 0., y: 0.),
+        ];
+        let rotated = line_string![
+            (x: 2., y: 1.),
+            (x: 0., y: 0.),
+            (x: 1., y: 2.),
+            (x: 0., y: 3.),
+            (x: 0., y: 0.),
+            (x: 2., y: -1.),
+            (x: 2., y: 1.),
+        ];
+        assert_eq!(ring.normalize(), rotated.normalize());
+        assert_eq!(
+            ring.normalize(),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 0., y: 3.),
+                (x: 1., y: 2.),
+                (x: 0., y: 0.),
+                (x: 2., y: 1.),
+                (x: 2., y: -1.),
+                (x: 0., y: 0.),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_strings() {
+        let line_string = line_string![(x: 2., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        let reversed = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        assert_eq!(line_string.normalize(), reversed);
+        assert_eq!(reversed.normalize(), reversed);
+
+        let multi = MultiLineString(vec![
+            line_string,
+            line_string![(x: -1., y: 0.), (x: 0., y: 5.)],
+        ]);
+        assert_eq!(
+            multi.normalize(),
+            MultiLineString(vec![
+                line_string![(x: -1., y: 0.), (x: 0., y: 5.)],
+                reversed,
+            ])
+        );
+
+        let line = Line::new((1., 1.), (0., 2.));
+        assert_eq!(line.normalize(), Line::new((0., 2.), (1., 1.)));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+        let b = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 5., y: 6.)];
+        let b_rotated = polygon![(x: 5., y: 6.), (x: 5., y: 5.), (x: 6., y: 5.)];
+        assert_eq!(
+            MultiPolygon(vec![b.clone(), a.clone()]).normalize(),
+            MultiPolygon(vec![a.clone(), b_rotated]).normalize(),
+        );
+        assert_eq!(
+            MultiPolygon(vec![b.clone(), a.clone()]).normalize(),
+            MultiPolygon(vec![a, b])
+        );
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = Triangle((1., 1.).into(), (0., 0.).into(), (0., 2.).into());
+        assert_eq!(
+            triangle.normalize(),
+            Triangle((0., 0.).into(), (1., 1.).into(), (0., 2.).into())
+        );
+    }
+
+    #[test]
+    fn collection() {
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 0.)]),
+            Geometry::Point(point!(x: 3., y: 3.)),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 1., y: 1.), point!(x: 0., y: 0.)])),
+            Geometry::Point(point!(x: 2., y: 2.)),
+        ]);
+        assert_eq!(
+            collection.normalize(),
+            GeometryCollection(vec![
+                Geometry::Point(point!(x: 2., y: 2.)),
+                Geometry::Point(point!(x: 3., y: 3.)),
+                Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)]),
+                Geometry::MultiPoint(MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)])),
+            ])
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{CoordFloat, Coordinate, LineString};
+
+/// The longest a miter join may be, as a multiple of the offset distance.
+const MITER_LIMIT: f64 = 4.;
+
+/// Offset a geometry to one side by a fixed distance, producing a parallel
+/// line.
+///
+/// A positive `distance` offsets to the left of the direction of travel,
+/// and a negative `distance` to the right. Consecutive offset segments are
+/// connected by miter joins: they are extended until they meet.
+///
+/// # Miter limit
+///
+/// At sharp angles, a miter join becomes a long spike: its length, from the
+/// original vertex to the tip, is `distance / cos(θ / 2)` where `θ` is the
+/// change of direction. If this would exceed four times the `distance`
+/// (i.e. when the direction changes by more than about 151°), the join is
+/// _beveled_ instead, by connecting the two offset segments directly.
+///
+/// Closed `LineString`s are also joined at their first coordinate. The
+/// result is not trimmed where it crosses itself, which can occur on the
+/// inner side of sharp bends, or of bends tighter than the `distance`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::offset::Offset;
+/// use geo::line_string;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.offset(-1.),
+///     line_string![
+///         (x: 0., y: -1.),
+///         (x: 11., y: -1.),
+///         (x: 11., y: 10.),
+///     ]
+/// );
+/// ```
+pub trait Offset<T: CoordFloat> {
+    fn offset(&self, distance: T) -> Self;
+}
+
+impl<T: CoordFloat> Offset<T> for LineString<T> {
+    fn offset(&self, distance: T) -> Self {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 2 {
+            return LineString(coords);
+        }
+
+        // The unit normal to the left of each segment
+        let normals: Vec<Coordinate<T>> = coords
+            .windows(2)
+            .map(|pair| {
+                let delta = pair[1] - pair[0];
+                let length = delta.x.hypot(delta.y);
+                Coordinate {
+                    x: -delta.y / length,
+                    y: delta.x / length,
+                }
+            })
+            .collect();
+        let last = normals.len() - 1;
+
+        let mut offset = Vec::with_capacity(coords.len() + 2);
+        if self.is_closed() {
+            join(&mut offset, coords[0], normals[last], normals[0], distance);
+        } else {
+            offset.push(coords[0] + normals[0] * distance);
+        }
+        for idx in 1..=last {
+            join(
+                &mut offset,
+                coords[idx],
+                normals[idx - 1],
+                normals[idx],
+                distance,
+            );
+        }
+        if self.is_closed() {
+            offset.push(offset[0]);
+        } else {
+            offset.push(coords[last + 1] + normals[last] * distance);
+        }
+        LineString(offset)
+    }
+}
+
+/// Push the join at `vertex` between two offset segments, given by their
+/// unit normals.
+fn join<T: CoordFloat>(
+    offset: &mut Vec<Coordinate<T>>,
+    vertex: Coordinate<T>,
+    before: Coordinate<T>,
+    after: Coordinate<T>,
+    distance: T,
+) {
+    let sum = before + after;
+    // `1 + cos(θ)`, where `θ` is the change of direction
+    let one_plus_cos = T::one() + before.x * after.x + before.y * after.y;
+    let limit = T::from(MITER_LIMIT).unwrap();
+    // The ratio of the length of the miter to the distance is `2 / |sum|`,
+    // and `|sum|² = 2 * (1 + cos(θ))`
+    if one_plus_cos * limit * limit > T::from(2).unwrap() {
+        offset.push(vertex + sum * (distance / one_plus_cos));
+    } else {
+        offset.push(vertex + before * distance);
+        offset.push(vertex + after * distance);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn straight() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 5.), (x: 10., y: 10.)];
+        let offset = line_string.offset(2f64.sqrt());
+        let expected = line_string![(x: -1., y: 1.), (x: 4., y: 6.), (x: 9., y: 11.)];
+        assert_relative_eq!(offset, expected, epsilon = 1e-12);
+
+        let offset = line_string.offset(-(2f64.sqrt()));
+        let expected = line_string![(x: 1., y: -1.), (x: 6., y: 4.), (x: 11., y: 9.)];
+        assert_relative_eq!(offset, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn bend() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        // The inner side of a left turn
+        assert_eq!(
+            line_string.offset(1.),
+            line_string![(x: 0., y: 1.), (x: 9., y: 1.), (x: 9., y: 10.)]
+        );
+        // The outer side
+        assert_eq!(
+            line_string.offset(-1.),
+            line_string![(x: 0., y: -1.), (x: 11., y: -1.), (x: 11., y: 10.)]
+        );
+    }
+
+    #[test]
+    fn miter_limit() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 1.)];
+        let offset = line_string.offset(-1.);
+        // The spike at the sharp turn is beveled
+        assert_eq!(offset.0.len(), 4);
+        assert_relative_eq!(offset.0[1], Coordinate { x: 10., y: -1. });
+        let normal = 101f64.sqrt().recip();
+        assert_relative_eq!(
+            offset.0[2],
+            Coordinate {
+                x: 10. + normal,
+                y: 10. * normal
+            }
+        );
+    }
+
+    #[test]
+    fn closed() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(
+            square.offset(-1.),
+            line_string![
+                (x: -1., y: -1.),
+                (x: 3., y: -1.),
+                (x: 3., y: 3.),
+                (x: -1., y: 3.),
+                (x: -1., y: -1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.offset(1.), empty);
+
+        let repeated = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(repeated.offset(1.), line_string![(x: 1., y: 1.)]);
+    }
+}
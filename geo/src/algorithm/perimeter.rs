@@ -0,0 +1,147 @@
+use std::iter::Sum;
+
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::{CoordFloat, MultiPolygon, Polygon};
+
+/// Calculate the (Euclidean) length of the boundary of an areal geometry.
+///
+/// The boundary of a polygon with holes includes the holes' boundaries, so
+/// its perimeter is the length of its exterior ring plus the lengths of its
+/// interior rings, as needed for fencing all of its edges. The length of
+/// just the exterior ring is given by `exterior_length`.
+pub trait Perimeter<T> {
+    /// The total length of the exterior and interior rings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::perimeter::Perimeter;
+    /// use geo::{line_string, Polygon};
+    ///
+    /// let donut = Polygon::new(
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+    ///     vec![line_string![(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.), (x: 2., y: 2.)]],
+    /// );
+    ///
+    /// assert_eq!(donut.perimeter(), 48.);
+    /// ```
+    fn perimeter(&self) -> T;
+
+    /// The length of the exterior rings, without the interior rings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::perimeter::Perimeter;
+    /// use geo::{line_string, Polygon};
+    ///
+    /// let donut = Polygon::new(
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+    ///     vec![line_string![(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.), (x: 2., y: 2.)]],
+    /// );
+    ///
+    /// assert_eq!(donut.exterior_length(), 40.);
+    /// ```
+    fn exterior_length(&self) -> T;
+}
+
+impl<T> Perimeter<T> for Polygon<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn perimeter(&self) -> T {
+        self.interiors()
+            .iter()
+            .fold(self.exterior_length(), |total, interior| {
+                total + interior.euclidean_length()
+            })
+    }
+
+    fn exterior_length(&self) -> T {
+        self.exterior().euclidean_length()
+    }
+}
+
+impl<T> Perimeter<T> for MultiPolygon<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn perimeter(&self) -> T {
+        self.0
+            .iter()
+            .fold(T::zero(), |total, polygon| total + polygon.perimeter())
+    }
+
+    fn exterior_length(&self) -> T {
+        self.0.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.exterior_length()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn square() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 3., y: 0.),
+            (x: 3., y: 3.),
+            (x: 0., y: 3.),
+        ];
+        assert_relative_eq!(square.perimeter(), 12.);
+        assert_relative_eq!(square.exterior_length(), 12.);
+
+        let empty: Polygon<f64> = polygon![];
+        assert_relative_eq!(empty.perimeter(), 0.);
+    }
+
+    #[test]
+    fn donut() {
+        let donut = polygon!(
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [
+                    (x: 2., y: 2.),
+                    (x: 2., y: 4.),
+                    (x: 4., y: 4.),
+                    (x: 4., y: 2.),
+                ],
+                [
+                    (x: 6., y: 6.),
+                    (x: 6., y: 9.),
+                    (x: 9., y: 6.),
+                ],
+            ],
+        );
+        let hypotenuse = 18f64.sqrt();
+        assert_relative_eq!(donut.perimeter(), 40. + 8. + 6. + hypotenuse);
+        assert_relative_eq!(donut.exterior_length(), 40.);
+
+        let multi = MultiPolygon(vec![
+            donut.clone(),
+            polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.), (x: 20., y: 1.)],
+        ]);
+        assert_relative_eq!(multi.perimeter(), donut.perimeter() + 4.);
+        assert_relative_eq!(multi.exterior_length(), 44.);
+    }
+
+    #[test]
+    fn unclosed_line_string_is_closed() {
+        // The rings of a `Polygon` are closed when it is made, so their
+        // closing segments count
+        let triangle = Polygon::new(
+            line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 0., y: 4.)],
+            vec![],
+        );
+        assert_relative_eq!(triangle.perimeter(), 12.);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::centroid::Centroid;
+use crate::algorithm::signed_distance::SignedDistance;
+use crate::{GeoFloat, Point, Polygon};
+use num_traits::FromPrimitive;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::Sum;
+
+/// Find the pole of inaccessibility of a `Polygon`: the point inside it
+/// which is farthest from its boundary, with that distance.
+///
+/// This is the centre of the largest circle which fits inside the
+/// polygon, which makes it a good anchor for a label. Unlike the centroid,
+/// it is always inside the polygon, and well away from its edges, even for
+/// concave polygons. The interiors are part of the boundary, so the point
+/// keeps away from holes too.
+///
+/// The polygon is searched with the _polylabel_ algorithm: its bounding
+/// rectangle is covered by square cells, and the cells which might hold a
+/// point farther from the boundary than the best found so far are split
+/// into quarters, until the distance is known to within `precision`.
+///
+/// `None` is returned if the polygon is empty, or if `precision` is not a
+/// positive number.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::pole_of_inaccessibility::PoleOfInaccessibility;
+/// use geo::{point, polygon};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// assert_eq!(
+///     square.pole_of_inaccessibility(0.1),
+///     Some((point!(x: 5., y: 5.), 5.))
+/// );
+/// ```
+pub trait PoleOfInaccessibility<T: GeoFloat> {
+    fn pole_of_inaccessibility(&self, precision: T) -> Option<(Point<T>, T)>;
+}
+
+impl<T> PoleOfInaccessibility<T> for Polygon<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    fn pole_of_inaccessibility(&self, precision: T) -> Option<(Point<T>, T)> {
+        if precision.is_nan() || precision <= T::zero() {
+            return None;
+        }
+        let bounding_rect = self.bounding_rect()?;
+        let cell_size = bounding_rect.width().min(bounding_rect.height());
+        if cell_size == T::zero() {
+            // A polygon with no area has no interior to search
+            return Some((self.exterior().0[0].into(), T::zero()));
+        }
+
+        let two = T::one() + T::one();
+        let half = cell_size / two;
+        let mut cells = BinaryHeap::new();
+        let mut y = bounding_rect.min().y;
+        while y < bounding_rect.max().y {
+            let mut x = bounding_rect.min().x;
+            while x < bounding_rect.max().x {
+                cells.push(Cell::new(Point::new(x + half, y + half), half, self));
+                x = x + cell_size;
+            }
+            y = y + cell_size;
+        }
+
+        // Start from the better of the centroid and the centre of the
+        // bounding rectangle, which is the answer for many simple polygons
+        let mut best = Cell::new(bounding_rect.center().into(), T::zero(), self);
+        if let Some(centroid) = self.centroid() {
+            let centroid = Cell::new(centroid, T::zero(), self);
+            if centroid.distance > best.distance {
+                best = centroid;
+            }
+        }
+
+        while let Some(cell) = cells.pop() {
+            if cell.distance > best.distance {
+                best = Cell::new(cell.center, T::zero(), self);
+            }
+            // The cells are in order of their potential, so none of those
+            // left can do better than this one by more than `precision`
+            if cell.potential - best.distance <= precision {
+                break;
+            }
+            let half = cell.half / two;
+            for &(dx, dy) in &[(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                let center = Point::new(
+                    cell.center.x() + half * T::from(dx).unwrap(),
+                    cell.center.y() + half * T::from(dy).unwrap(),
+                );
+                cells.push(Cell::new(center, half, self));
+            }
+        }
+
+        Some((best.center, best.distance))
+    }
+}
+
+/// A square cell, ordered by the greatest distance from the boundary of any
+/// point within it.
+struct Cell<T: GeoFloat> {
+    center: Point<T>,
+    half: T,
+    /// The signed distance from the centre to the boundary, which is
+    /// negative outside the polygon
+    distance: T,
+    /// The greatest possible distance from the boundary of any point in
+    /// the cell
+    potential: T,
+}
+
+impl<T: GeoFloat> Cell<T> {
+    fn new(center: Point<T>, half: T, polygon: &Polygon<T>) -> Self {
+        // Positive inside the polygon, where the pole is sought
+        let distance = -polygon.signed_distance(center);
+        Cell {
+            center,
+            half,
+            distance,
+            potential: distance + half * T::from(std::f64::consts::SQRT_2).unwrap(),
+        }
+    }
+}
+
+impl<T: GeoFloat> Ord for Cell<T> {
+    fn cmp(&self, other: &Cell<T>) -> Ordering {
+        self.potential.partial_cmp(&other.potential).unwrap()
+    }
+}
+
+impl<T: GeoFloat> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Cell<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Eq for Cell<T> {}
+
+impl<T: GeoFloat> PartialEq for Cell<T> {
+    fn eq(&self, other: &Cell<T>) -> bool {
+        self.potential == other.potential
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn square() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        assert_eq!(
+            square.pole_of_inaccessibility(0.01),
+            Some((point!(x: 2., y: 2.), 2.))
+        );
+    }
+
+    #[test]
+    fn l_shape() {
+        // Two arms, 2 wide, with the short one 6 long and the long one 10
+        let l_shape = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 6.),
+            (x: 0., y: 6.),
+        ];
+        let centroid = l_shape.centroid().unwrap();
+        // The centroid is only just inside, close to the inner edge
+        assert!(-l_shape.signed_distance(centroid) < 0.6);
+
+        let precision = 0.01;
+        let (pole, distance) = l_shape.pole_of_inaccessibility(precision).unwrap();
+        // The widest part is in the corner, on the diagonal, where it is
+        // equidistant from the outer edges and the inner corner:
+        // (2 - d) * √2 = d
+        let expected = 2. / (1. + std::f64::consts::FRAC_1_SQRT_2);
+        assert!(distance > expected - precision && distance <= expected + 1e-9);
+        assert_relative_eq!(-l_shape.signed_distance(pole), distance);
+        assert_relative_eq!(pole.x(), pole.y(), epsilon = 0.1);
+        assert!(pole.euclidean_distance(&centroid) > 2.);
+    }
+
+    #[test]
+    fn hole() {
+        // A square with a hole in the middle, leaving the widest part in
+        // the corners
+        let polygon = Polygon::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            vec![line_string![
+                (x: 3., y: 3.),
+                (x: 7., y: 3.),
+                (x: 7., y: 7.),
+                (x: 3., y: 7.),
+                (x: 3., y: 3.),
+            ]],
+        );
+        let precision = 0.001;
+        let (pole, distance) = polygon.pole_of_inaccessibility(precision).unwrap();
+        assert!(!Polygon::new(polygon.interiors()[0].clone(), vec![]).contains(&pole));
+        // Equidistant from a corner of the hole and the two outer edges
+        // beside it, on the diagonal: (3 - d) * √2 = d
+        let expected = 3. / (1. + std::f64::consts::FRAC_1_SQRT_2);
+        assert!(distance > expected - precision && distance <= expected + 1e-9);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(empty.pole_of_inaccessibility(0.1), None);
+
+        let flat = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            flat.pole_of_inaccessibility(0.1),
+            Some((point!(x: 0., y: 0.), 0.))
+        );
+
+        let triangle = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(triangle.pole_of_inaccessibility(0.), None);
+        assert_eq!(triangle.pole_of_inaccessibility(f64::NAN), None);
+    }
+}
@@ -0,0 +1,409 @@
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::algorithm::map_coords::MapCoords;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoNum, Line, LineString, Polygon};
+use std::cmp::Ordering;
+
+/// Build a `Polygon` from its exterior and holes, added one at a time, such
+/// as when assembling it from a stream of parsed rings.
+///
+/// By default, [`build`](PolygonBuilder::build) accepts the rings as they
+/// are, closing any which are open, as `Polygon::new` does. With
+/// [`validate`](PolygonBuilder::validate), it checks them instead: the
+/// exterior and each hole must be a [ring](LineString::is_ring), closed
+/// and with at least four coordinates, and each hole must lie within the
+/// exterior. A hole lies within the exterior if no part of it is outside;
+/// it may touch the exterior. Holes aren't checked against each other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::polygon_builder::{PolygonBuilder, ValidationError};
+/// use geo::line_string;
+///
+/// let exterior = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)];
+/// let hole = line_string![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 2.)];
+/// let outside = line_string![(x: 12., y: 2.), (x: 14., y: 2.), (x: 14., y: 4.), (x: 12., y: 2.)];
+///
+/// let polygon = PolygonBuilder::new()
+///     .exterior(exterior.clone())
+///     .add_hole(hole.clone())
+///     .validate(true)
+///     .build()
+///     .unwrap();
+/// assert_eq!(polygon.interiors(), &[hole]);
+///
+/// let invalid = PolygonBuilder::new()
+///     .exterior(exterior)
+///     .add_hole(outside)
+///     .validate(true)
+///     .build();
+/// assert_eq!(invalid, Err(ValidationError::HoleOutsideExterior(0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonBuilder<T: GeoNum> {
+    exterior: Option<LineString<T>>,
+    interiors: Vec<LineString<T>>,
+    validate: bool,
+}
+
+impl<T: GeoNum> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GeoNum> PolygonBuilder<T> {
+    /// A builder with no rings, which doesn't validate them.
+    pub fn new() -> Self {
+        PolygonBuilder {
+            exterior: None,
+            interiors: vec![],
+            validate: false,
+        }
+    }
+
+    /// Set the exterior ring, replacing any set before.
+    pub fn exterior(mut self, ring: LineString<T>) -> Self {
+        self.exterior = Some(ring);
+        self
+    }
+
+    /// Add a hole, after any added before.
+    pub fn add_hole(mut self, ring: LineString<T>) -> Self {
+        self.interiors.push(ring);
+        self
+    }
+
+    /// Whether [`build`](PolygonBuilder::build) validates the rings.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Build the `Polygon`.
+    ///
+    /// # Errors
+    ///
+    /// If validating, the first problem found with the rings is returned,
+    /// checking the exterior, then each hole in turn. Without validation,
+    /// there are no errors, and a missing exterior is taken to be empty.
+    pub fn build(self) -> Result<Polygon<T>, ValidationError> {
+        if !self.validate {
+            return Ok(Polygon::new(
+                self.exterior.unwrap_or_default(),
+                self.interiors,
+            ));
+        }
+
+        let exterior = self.exterior.ok_or(ValidationError::MissingExterior)?;
+        if !exterior.is_ring() {
+            return Err(ValidationError::InvalidExterior);
+        }
+        let shell = Polygon::new(exterior, vec![]);
+        for (idx, hole) in self.interiors.iter().enumerate() {
+            if !hole.is_ring() {
+                return Err(ValidationError::InvalidHole(idx));
+            }
+            if !lies_within(hole, &shell) {
+                return Err(ValidationError::HoleOutsideExterior(idx));
+            }
+        }
+        let (exterior, _) = shell.into_inner();
+        Ok(Polygon::new(exterior, self.interiors))
+    }
+}
+
+/// Whether `ring` lies within the closed area of `shell`, which has no holes.
+///
+/// None of its coordinates may be outside the shell, and none of its edges
+/// may cross the shell's. An edge can still pass outside between two points
+/// on the boundary, such as across a notch in a concave shell, so each edge
+/// is also split at the shell's vertices lying on it, and the midpoint of
+/// each piece must not be outside.
+fn lies_within<T: GeoNum>(ring: &LineString<T>, shell: &Polygon<T>) -> bool {
+    // Midpoints are tested against the shell scaled by two, so that they
+    // are exact, even for integer coordinates
+    let doubled = shell.map_coords(|&(x, y)| (x + x, y + y));
+    ring.0
+        .iter()
+        .all(|coord| shell.coordinate_position(coord) != CoordPos::Outside)
+        && !ring.lines().any(|edge| {
+            shell
+                .exterior()
+                .lines()
+                .any(|shell_edge| crosses(edge, shell_edge))
+        })
+        && ring.lines().all(|edge| {
+            let mut stops: Vec<Coordinate<T>> = shell
+                .exterior()
+                .0
+                .iter()
+                .copied()
+                .filter(|&vertex| in_edge_interior(edge, vertex))
+                .collect();
+            stops.sort_by(|a, b| {
+                let order = lex_cmp(a, b);
+                if lex_cmp(&edge.start, &edge.end) == Ordering::Greater {
+                    order.reverse()
+                } else {
+                    order
+                }
+            });
+            std::iter::once(edge.start)
+                .chain(stops)
+                .chain(std::iter::once(edge.end))
+                .collect::<Vec<_>>()
+                .windows(2)
+                .all(|piece| {
+                    doubled.coordinate_position(&(piece[0] + piece[1])) != CoordPos::Outside
+                })
+        })
+}
+
+/// Whether `coord` lies on `edge`, other than at its end points.
+fn in_edge_interior<T: GeoNum>(edge: Line<T>, coord: Coordinate<T>) -> bool {
+    let within = |value: T, a: T, b: T| (a <= value && value <= b) || (b <= value && value <= a);
+    coord != edge.start
+        && coord != edge.end
+        && T::Ker::orient2d(edge.start, edge.end, coord) == Orientation::Collinear
+        && within(coord.x, edge.start.x, edge.end.x)
+        && within(coord.y, edge.start.y, edge.end.y)
+}
+
+/// Whether `a` and `b` cross at a single point in the interior of both.
+fn crosses<T: GeoNum>(a: Line<T>, b: Line<T>) -> bool {
+    let opposite = |p: Orientation, q: Orientation| {
+        matches!(
+            (p, q),
+            (Orientation::Clockwise, Orientation::CounterClockwise)
+                | (Orientation::CounterClockwise, Orientation::Clockwise)
+        )
+    };
+    opposite(
+        T::Ker::orient2d(a.start, a.end, b.start),
+        T::Ker::orient2d(a.start, a.end, b.end),
+    ) && opposite(
+        T::Ker::orient2d(b.start, b.end, a.start),
+        T::Ker::orient2d(b.start, b.end, a.end),
+    )
+}
+
+/// The reason a [`PolygonBuilder`] failed to build a valid `Polygon`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ValidationError {
+    /// No exterior ring was set
+    MissingExterior,
+    /// The exterior isn't closed, or has fewer than four coordinates
+    InvalidExterior,
+    /// The hole at this index isn't closed, or has fewer than four coordinates
+    InvalidHole(usize),
+    /// The hole at this index doesn't lie within the exterior
+    HoleOutsideExterior(usize),
+}
+
+impl std::error::Error for ValidationError {}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::MissingExterior => write!(f, "Polygon has no exterior ring"),
+            ValidationError::InvalidExterior => write!(
+                f,
+                "Polygon exterior must be closed, with at least 4 coordinates"
+            ),
+            ValidationError::InvalidHole(idx) => write!(
+                f,
+                "Polygon hole {} must be closed, with at least 4 coordinates",
+                idx
+            ),
+            ValidationError::HoleOutsideExterior(idx) => {
+                write!(f, "Polygon hole {} doesn't lie within the exterior", idx)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn square(min: f64, max: f64) -> LineString<f64> {
+        LineString::from(vec![
+            (min, min),
+            (max, min),
+            (max, max),
+            (min, max),
+            (min, min),
+        ])
+    }
+
+    #[test]
+    fn two_holes() {
+        let hole = line_string![(x: 6., y: 6.), (x: 8., y: 6.), (x: 7., y: 8.), (x: 6., y: 6.)];
+        let polygon = PolygonBuilder::new()
+            .exterior(square(0., 10.))
+            .add_hole(square(1., 3.))
+            .add_hole(hole.clone())
+            .validate(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            polygon,
+            Polygon::new(square(0., 10.), vec![square(1., 3.), hole])
+        );
+    }
+
+    #[test]
+    fn hole_outside_exterior() {
+        let builder = PolygonBuilder::new()
+            .exterior(square(0., 10.))
+            .add_hole(square(1., 3.))
+            .add_hole(square(11., 13.));
+        assert_eq!(
+            builder.clone().validate(true).build(),
+            Err(ValidationError::HoleOutsideExterior(1))
+        );
+        // Without validation, it's accepted as it is
+        let polygon = builder.build().unwrap();
+        assert_eq!(polygon.interiors().len(), 2);
+
+        // Touching a concave exterior is allowed
+        let notched = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5., y: 5.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let touching = line_string![(x: 5., y: 5.), (x: 4., y: 2.), (x: 6., y: 2.), (x: 5., y: 5.)];
+        assert!(PolygonBuilder::new()
+            .exterior(notched)
+            .add_hole(touching)
+            .validate(true)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn hole_across_notch() {
+        // A hole with its vertices on the boundary of a concave shell, but
+        // an edge spanning the notch, outside the shell
+        let notched = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5., y: 5.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let across =
+            line_string![(x: 10., y: 10.), (x: 5., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.)];
+        let builder = PolygonBuilder::new().exterior(notched).validate(true);
+        assert_eq!(
+            builder.clone().add_hole(across).build(),
+            Err(ValidationError::HoleOutsideExterior(0))
+        );
+
+        // An edge across two notches, through the shell vertex between
+        // them, so its midpoint is on the boundary
+        let two_notches = line_string![
+            (x: 0., y: 0.),
+            (x: 12., y: 0.),
+            (x: 12., y: 10.),
+            (x: 9., y: 5.),
+            (x: 6., y: 10.),
+            (x: 3., y: 5.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let through_vertex = line_string![
+            (x: 0., y: 10.),
+            (x: 1., y: 1.),
+            (x: 11., y: 1.),
+            (x: 12., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        assert_eq!(
+            PolygonBuilder::new()
+                .exterior(two_notches)
+                .add_hole(through_vertex)
+                .validate(true)
+                .build(),
+            Err(ValidationError::HoleOutsideExterior(0))
+        );
+
+        // Along the boundary is allowed
+        let along = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 3.), (x: 0., y: 0.)];
+        assert!(builder.clone().add_hole(along).build().is_ok());
+
+        let integer_notched: LineString<i64> =
+            vec![(0, 0), (10, 0), (10, 10), (5, 5), (0, 10), (0, 0)].into();
+        let integer_across: LineString<i64> = vec![(10, 10), (5, 0), (0, 10), (10, 10)].into();
+        assert_eq!(
+            PolygonBuilder::new()
+                .exterior(integer_notched)
+                .add_hole(integer_across)
+                .validate(true)
+                .build(),
+            Err(ValidationError::HoleOutsideExterior(0))
+        );
+    }
+
+    #[test]
+    fn invalid_rings() {
+        let open = line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)];
+        assert_eq!(
+            PolygonBuilder::<f64>::new().validate(true).build(),
+            Err(ValidationError::MissingExterior)
+        );
+        assert_eq!(
+            PolygonBuilder::new()
+                .exterior(open.clone())
+                .validate(true)
+                .build(),
+            Err(ValidationError::InvalidExterior)
+        );
+        assert_eq!(
+            PolygonBuilder::new()
+                .exterior(square(0., 10.))
+                .add_hole(open.clone())
+                .validate(true)
+                .build(),
+            Err(ValidationError::InvalidHole(0))
+        );
+
+        // Without validation, open rings are closed
+        let polygon = PolygonBuilder::new()
+            .exterior(square(0., 10.))
+            .add_hole(open)
+            .build()
+            .unwrap();
+        assert!(polygon.interiors()[0].is_closed());
+        assert_eq!(
+            PolygonBuilder::<f64>::new()
+                .build()
+                .unwrap()
+                .exterior()
+                .0
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        let exterior: LineString<i64> = vec![(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)].into();
+        let builder = PolygonBuilder::new().exterior(exterior).validate(true);
+        let inside: LineString<i64> = vec![(0, 0), (5, 1), (1, 5), (0, 0)].into();
+        let crossing: LineString<i64> = vec![(5, 5), (15, 5), (5, 8), (5, 5)].into();
+        assert!(builder.clone().add_hole(inside).build().is_ok());
+        assert_eq!(
+            builder.add_hole(crossing).build(),
+            Err(ValidationError::HoleOutsideExterior(0))
+        );
+    }
+}
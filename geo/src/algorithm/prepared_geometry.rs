@@ -0,0 +1,414 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::contains::Contains;
+use crate::algorithm::intersects::Intersects;
+use crate::{Coordinate, GeoFloat, Geometry, Line, LineString, Point, Polygon, Rect};
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+/// A geometry prepared for repeated predicate queries.
+///
+/// Preparing a geometry indexes its edges in an [R* tree], once. Testing
+/// whether it contains or intersects another geometry then only considers
+/// the edges near that geometry, rather than all of them. This is
+/// worthwhile when one geometry, such as a large polygon, is tested against
+/// many others, as in a spatial join.
+///
+/// The results are the same as those of the [`Contains`] and [`Intersects`]
+/// implementations of the `Geometry` itself, provided that it is valid: in
+/// particular, the polygons of a `MultiPolygon` (or `GeometryCollection`)
+/// are expected not to overlap.
+///
+/// [R* tree]: https://docs.rs/rstar
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::contains::Contains;
+/// use geo::algorithm::intersects::Intersects;
+/// use geo::algorithm::prepared_geometry::PreparedGeometry;
+/// use geo::{line_string, point, polygon, Geometry};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+/// let prepared = PreparedGeometry::from(Geometry::Polygon(polygon));
+///
+/// assert!(prepared.contains(&point!(x: 5., y: 5.)));
+/// assert!(!prepared.contains(&point!(x: 15., y: 5.)));
+///
+/// let line_string = line_string![(x: 5., y: 5.), (x: 15., y: 5.)];
+/// assert!(prepared.intersects(&Geometry::LineString(line_string)));
+/// ```
+pub struct PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    geometry: Geometry<T>,
+    bounding_rect: Option<Rect<T>>,
+    /// The edges of the rings of areal components
+    rings: RTree<Line<T>>,
+    /// The edges of lineal components
+    lines: RTree<Line<T>>,
+    /// The puntal components
+    points: RTree<Point<T>>,
+    /// A coordinate of each component, including each point
+    representatives: Vec<Coordinate<T>>,
+}
+
+impl<T> From<Geometry<T>> for PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn from(geometry: Geometry<T>) -> Self {
+        let components = Components::new(&geometry);
+        PreparedGeometry {
+            bounding_rect: geometry.bounding_rect(),
+            rings: RTree::bulk_load(components.rings),
+            lines: RTree::bulk_load(components.lines),
+            points: RTree::bulk_load(components.points.into_iter().map(Point).collect()),
+            representatives: components.representatives,
+            geometry,
+        }
+    }
+}
+
+impl<T> PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    /// The geometry which was prepared.
+    pub fn geometry(&self) -> &Geometry<T> {
+        &self.geometry
+    }
+
+    /// Whether the geometry only has areal components, which may be located
+    /// by counting ring crossings.
+    fn is_areal(&self) -> bool {
+        self.lines.size() == 0 && self.points.size() == 0 && self.rings.size() > 0
+    }
+
+    fn intersects_line(&self, line: &Line<T>) -> bool {
+        let envelope = line.envelope();
+        self.rings
+            .locate_in_envelope_intersecting(&envelope)
+            .chain(self.lines.locate_in_envelope_intersecting(&envelope))
+            .any(|edge| edge.intersects(line))
+    }
+
+    fn on_edge(&self, coord: Coordinate<T>) -> bool {
+        let envelope = AABB::from_point(Point(coord));
+        self.rings
+            .locate_in_envelope_intersecting(&envelope)
+            .chain(self.lines.locate_in_envelope_intersecting(&envelope))
+            .any(|edge| edge.intersects(&coord))
+    }
+
+    /// Whether `coord` is within the rings, by casting a ray from it in the
+    /// positive `x` direction, and counting the ring edges it crosses. The
+    /// crossing rules are those of `coord_pos_relative_to_ring`.
+    fn within_rings(&self, coord: Coordinate<T>) -> bool {
+        let max_x = match self.bounding_rect {
+            Some(rect) => rect.max().x,
+            None => return false,
+        };
+        let envelope = AABB::from_corners(Point(coord), Point::new(max_x, coord.y));
+        let crossings = self
+            .rings
+            .locate_in_envelope_intersecting(&envelope)
+            .filter(|edge| {
+                // Horizontal edges, and edges touching the ray from below
+                // at a vertex, are not crossed
+                if edge.start.y == edge.end.y
+                    || (edge.start.y == coord.y && edge.end.y < coord.y)
+                    || (edge.end.y == coord.y && edge.start.y < coord.y)
+                {
+                    return false;
+                }
+                let ray = Line::new(
+                    coord,
+                    Coordinate {
+                        x: edge.start.x.max(edge.end.x),
+                        y: coord.y,
+                    },
+                );
+                ray.intersects(*edge)
+            })
+            .count();
+        crossings % 2 == 1
+    }
+
+    fn intersects_coordinate(&self, coord: Coordinate<T>) -> bool {
+        match self.bounding_rect {
+            Some(rect) if rect.intersects(&coord) => {}
+            _ => return false,
+        }
+        self.on_edge(coord)
+            || self.points.locate_at_point(&Point(coord)).is_some()
+            || self.within_rings(coord)
+    }
+}
+
+impl<T> Contains<Coordinate<T>> for PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn contains(&self, coord: &Coordinate<T>) -> bool {
+        if !self.is_areal() {
+            return self.geometry.contains(coord);
+        }
+        match self.bounding_rect {
+            Some(rect) if rect.intersects(coord) => {}
+            _ => return false,
+        }
+        // Areal geometries don't contain their boundary
+        !self.on_edge(*coord) && self.within_rings(*coord)
+    }
+}
+
+impl<T> Contains<Point<T>> for PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.contains(&point.0)
+    }
+}
+
+impl<T> Intersects<Coordinate<T>> for PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn intersects(&self, coord: &Coordinate<T>) -> bool {
+        self.intersects_coordinate(*coord)
+    }
+}
+
+impl<T> Intersects<Point<T>> for PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn intersects(&self, point: &Point<T>) -> bool {
+        self.intersects_coordinate(point.0)
+    }
+}
+
+impl<T> Intersects<Geometry<T>> for PreparedGeometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn intersects(&self, other: &Geometry<T>) -> bool {
+        match (self.bounding_rect, other.bounding_rect()) {
+            (Some(rect), Some(other_rect)) if rect.intersects(&other_rect) => {}
+            _ => return false,
+        }
+
+        // If the boundaries cross, the geometries intersect
+        let components = Components::new(other);
+        if components
+            .rings
+            .iter()
+            .chain(&components.lines)
+            .any(|line| self.intersects_line(line))
+        {
+            return true;
+        }
+        // Otherwise, each component of either geometry is entirely inside,
+        // or entirely outside, the other geometry
+        components
+            .representatives
+            .iter()
+            .any(|&coord| self.intersects_coordinate(coord))
+            || self
+                .representatives
+                .iter()
+                .any(|coord| other.intersects(coord))
+    }
+}
+
+/// The parts of a geometry relevant to its predicates.
+struct Components<T: GeoFloat> {
+    rings: Vec<Line<T>>,
+    lines: Vec<Line<T>>,
+    points: Vec<Coordinate<T>>,
+    representatives: Vec<Coordinate<T>>,
+}
+
+impl<T: GeoFloat> Components<T> {
+    fn new(geometry: &Geometry<T>) -> Self {
+        let mut components = Components {
+            rings: vec![],
+            lines: vec![],
+            points: vec![],
+            representatives: vec![],
+        };
+        components.add(geometry);
+        components
+    }
+
+    fn add(&mut self, geometry: &Geometry<T>) {
+        match geometry {
+            Geometry::Point(point) => self.add_point(point.0),
+            Geometry::MultiPoint(multi_point) => {
+                for point in multi_point {
+                    self.add_point(point.0);
+                }
+            }
+            Geometry::Line(line) => {
+                self.lines.push(*line);
+                self.representatives.push(line.start);
+            }
+            Geometry::LineString(line_string) => self.add_line_string(line_string),
+            Geometry::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string {
+                    self.add_line_string(line_string);
+                }
+            }
+            Geometry::Polygon(polygon) => self.add_polygon(polygon),
+            Geometry::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon {
+                    self.add_polygon(polygon);
+                }
+            }
+            Geometry::Rect(rect) => self.add_polygon(&rect.to_polygon()),
+            Geometry::Triangle(triangle) => self.add_polygon(&triangle.to_polygon()),
+            Geometry::GeometryCollection(collection) => {
+                for geometry in collection {
+                    self.add(geometry);
+                }
+            }
+        }
+    }
+
+    fn add_point(&mut self, coord: Coordinate<T>) {
+        self.points.push(coord);
+        self.representatives.push(coord);
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        match line_string.0.len() {
+            0 => {}
+            1 => self.add_point(line_string.0[0]),
+            _ => {
+                self.lines.extend(line_string.lines());
+                self.representatives.push(line_string.0[0]);
+            }
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        if let Some(&first) = polygon.exterior().0.first() {
+            self.representatives.push(first);
+        }
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            self.rings.extend(ring.lines());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, MultiPolygon};
+
+    /// A grid of coordinates, some of which lie on the edges and vertices
+    /// of the test geometries
+    fn coords() -> impl Iterator<Item = Coordinate<f64>> {
+        (-2..=24).flat_map(|x| {
+            (-2..=24).map(move |y| Coordinate {
+                x: x as f64 / 2.,
+                y: y as f64 / 2.,
+            })
+        })
+    }
+
+    fn candidates() -> Vec<Geometry<f64>> {
+        let mut candidates: Vec<Geometry<f64>> = coords()
+            .map(|coord| Geometry::Point(Point(coord)))
+            .collect();
+        for coord in coords().step_by(7) {
+            let delta = Coordinate { x: 1.5, y: 0.5 };
+            candidates.push(Geometry::Line(Line::new(coord, coord + delta)));
+            candidates.push(Geometry::LineString(line_string![
+                coord,
+                coord + delta,
+                coord + Coordinate { x: 0., y: 3. },
+            ]));
+            candidates.push(Geometry::Polygon(polygon![
+                coord,
+                coord + Coordinate { x: 0.5, y: 0. },
+                coord + Coordinate { x: 0.5, y: 0.5 },
+            ]));
+        }
+        // A polygon surrounding all of the test geometries
+        candidates.push(Geometry::Polygon(polygon![
+            (x: -5., y: -5.),
+            (x: 20., y: -5.),
+            (x: 20., y: 20.),
+            (x: -5., y: 20.),
+        ]));
+        candidates
+    }
+
+    fn assert_equivalent(geometry: Geometry<f64>) {
+        let prepared = PreparedGeometry::from(geometry.clone());
+        for coord in coords() {
+            assert_eq!(
+                prepared.contains(&coord),
+                geometry.contains(&coord),
+                "contains {:?}",
+                coord
+            );
+        }
+        for candidate in candidates() {
+            assert_eq!(
+                prepared.intersects(&candidate),
+                geometry.intersects(&candidate),
+                "intersects {:?}",
+                candidate
+            );
+        }
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        assert_equivalent(Geometry::Polygon(polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5., y: 6.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+            ],
+        ]));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        assert_equivalent(Geometry::MultiPolygon(MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)],
+            polygon![(x: 5., y: 5.), (x: 9., y: 5.), (x: 7., y: 9.)],
+        ])));
+    }
+
+    #[test]
+    fn line_string() {
+        assert_equivalent(Geometry::LineString(line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 4.),
+            (x: 8., y: 0.),
+            (x: 8., y: 8.),
+        ]));
+    }
+
+    #[test]
+    fn empty() {
+        let prepared = PreparedGeometry::from(Geometry::LineString(LineString::<f64>(vec![])));
+        assert!(!prepared.contains(&Point::new(0., 0.)));
+        assert!(!prepared.intersects(&Geometry::Point(Point::new(0., 0.))));
+    }
+}
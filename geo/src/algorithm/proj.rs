@@ -1 +1,111 @@
+use crate::algorithm::map_coords::TryMapCoords;
 pub use proj::Proj;
+use proj::ProjError;
+
+/// Reproject a geometry between two coordinate reference systems, using
+/// [PROJ](https://proj.org).
+///
+/// `from` and `to` may be anything accepted by [`Proj::new_known_crs`], such
+/// as an `"AUTHORITY:CODE"` like `"EPSG:4326"`, or a PROJ string. Geographic
+/// coordinates are in `(longitude, latitude)` order, in degrees.
+///
+/// Only the existing coordinates are transformed: no new ones are added, so
+/// long straight edges are not curved to follow the projection. Densify the
+/// geometry first if that is needed.
+///
+/// A `ProjError` is returned if the transformation can't be created, or if
+/// any coordinate fails to transform.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// #
+/// use geo::algorithm::proj::Transform;
+/// use geo::{point, Geometry};
+///
+/// let london = Geometry::Point(point!(x: -0.1278, y: 51.5074));
+/// let mercator = london.transform("EPSG:4326", "EPSG:3857").unwrap();
+///
+/// if let Geometry::Point(point) = mercator {
+///     assert_relative_eq!(point.x(), -14226.631, epsilon = 1e-3);
+///     assert_relative_eq!(point.y(), 6711542.476, epsilon = 1e-3);
+/// }
+/// ```
+pub trait Transform: Sized {
+    fn transform(&self, from: &str, to: &str) -> Result<Self, ProjError>;
+}
+
+impl<G> Transform for G
+where
+    G: TryMapCoords<f64, f64, Output = G>,
+{
+    fn transform(&self, from: &str, to: &str) -> Result<Self, ProjError> {
+        let proj = Proj::new_known_crs(from, to, None).ok_or_else(|| {
+            ProjError::Projection(format!(
+                "Couldn't create a transformation from {} to {}",
+                from, to
+            ))
+        })?;
+        let proj = &proj;
+        self.try_map_coords(|&(x, y)| {
+            let transformed = proj.convert((x, y))?;
+            Ok((transformed.x(), transformed.y()))
+        })
+        // PROJ is the only source of errors
+        .map_err(|error| *error.downcast::<ProjError>().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, Geometry, GeometryCollection};
+
+    #[test]
+    fn wgs84_to_web_mercator() {
+        let geometry = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::Point(point!(x: -0.1278, y: 51.5074)),
+            Geometry::LineString(line_string![(x: 0., y: 0.), (x: 10., y: 20.)]),
+        ]));
+        let transformed = geometry.transform("EPSG:4326", "EPSG:3857").unwrap();
+        let expected = [
+            (-14226.631, 6711542.476),
+            (0., 0.),
+            (1113194.908, 2273030.927),
+        ];
+        let transformed = match transformed {
+            Geometry::GeometryCollection(collection) => collection,
+            _ => unreachable!(),
+        };
+        let coords: Vec<(f64, f64)> = match (&transformed.0[0], &transformed.0[1]) {
+            (Geometry::Point(point), Geometry::LineString(line_string)) => {
+                std::iter::once(point.x_y())
+                    .chain(line_string.points_iter().map(|point| point.x_y()))
+                    .collect()
+            }
+            _ => unreachable!(),
+        };
+        assert_eq!(coords.len(), expected.len());
+        for ((x, y), &(expected_x, expected_y)) in coords.into_iter().zip(expected.iter()) {
+            assert_relative_eq!(x, expected_x, epsilon = 1e-3);
+            assert_relative_eq!(y, expected_y, epsilon = 1e-3);
+        }
+
+        // And back again
+        let point = point!(x: -0.1278, y: 51.5074);
+        let round_trip = point
+            .transform("EPSG:4326", "EPSG:3857")
+            .unwrap()
+            .transform("EPSG:3857", "EPSG:4326")
+            .unwrap();
+        assert_relative_eq!(round_trip.x(), point.x(), epsilon = 1e-9);
+        assert_relative_eq!(round_trip.y(), point.y(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn invalid_crs() {
+        let point = point!(x: 0., y: 0.);
+        assert!(point.transform("EPSG:4326", "NOT A CRS").is_err());
+    }
+}
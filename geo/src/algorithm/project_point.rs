@@ -0,0 +1,158 @@
+use crate::{CoordFloat, LineString, Point};
+
+/// Project a point onto a `LineString`, finding its _measure_: the
+/// (Euclidean) distance along the `LineString`, from its start, of the
+/// closest location to the point.
+///
+/// This is the inverse of interpolating a point at a distance along the
+/// `LineString`, and the basis of linear referencing. Unlike
+/// [`LineLocatePoint`], the measure is an absolute distance rather than a
+/// fraction of the length. A point beyond either end is projected onto
+/// that end. If several locations are equally close, the one with the
+/// least measure is used.
+///
+/// `None` is returned if the `LineString` is empty, or if any of the
+/// coordinates are not finite.
+///
+/// [`LineLocatePoint`]: crate::algorithm::line_locate_point::LineLocatePoint
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::project_point::ProjectPoint;
+/// use geo::{line_string, point};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+/// ];
+///
+/// assert_eq!(line_string.project_point(point!(x: 6., y: 1.)), Some(5.));
+/// // To the right of the path
+/// assert_eq!(
+///     line_string.project_point_with_offset(point!(x: 6., y: 1.)),
+///     Some((5., -2.))
+/// );
+/// ```
+pub trait ProjectPoint<T: CoordFloat> {
+    /// The measure of the closest location to `p`.
+    fn project_point(&self, p: Point<T>) -> Option<T>;
+
+    /// The measure of the closest location to `p`, and the offset of `p`
+    /// from it: the distance between them, which is positive if `p` lies
+    /// to the left of the `LineString` (looking along it), and negative if
+    /// it lies to the right.
+    fn project_point_with_offset(&self, p: Point<T>) -> Option<(T, T)>;
+}
+
+impl<T: CoordFloat> ProjectPoint<T> for LineString<T> {
+    fn project_point(&self, p: Point<T>) -> Option<T> {
+        self.project_point_with_offset(p)
+            .map(|(measure, _)| measure)
+    }
+
+    fn project_point_with_offset(&self, p: Point<T>) -> Option<(T, T)> {
+        let is_finite = |point: Point<T>| point.x().is_finite() && point.y().is_finite();
+        if !is_finite(p) || !self.points_iter().all(is_finite) {
+            return None;
+        }
+        let first = *self.0.first()?;
+
+        let offset = p.0 - first;
+        // The closest location found, as its measure, and the distance and
+        // side of `p` from it
+        let mut closest = (T::zero(), offset.x.hypot(offset.y), T::one());
+        let mut start_measure = T::zero();
+        for line in self.lines() {
+            let delta = line.delta();
+            let length = delta.x.hypot(delta.y);
+            if length == T::zero() {
+                continue;
+            }
+            let offset = p.0 - line.start;
+            let along = ((delta.x * offset.x + delta.y * offset.y) / length)
+                .max(T::zero())
+                .min(length);
+            let gap = p.0 - (line.start + delta * (along / length));
+            let distance = gap.x.hypot(gap.y);
+            if distance < closest.1 {
+                let cross = delta.x * offset.y - delta.y * offset.x;
+                let side = if cross < T::zero() {
+                    -T::one()
+                } else {
+                    T::one()
+                };
+                closest = (start_measure + along, distance, side);
+            }
+            start_measure = start_measure + length;
+        }
+
+        let (measure, distance, side) = closest;
+        Some((measure, distance * side))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    fn line_string() -> LineString<f64> {
+        line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 3.),
+            (x: 8., y: 6.),
+        ]
+    }
+
+    #[test]
+    fn beside_segment() {
+        let line_string = line_string();
+        // Beside the middle of the first segment, on either side
+        assert_eq!(
+            line_string.project_point_with_offset(point!(x: 2., y: 1.)),
+            Some((2., 1.))
+        );
+        assert_eq!(
+            line_string.project_point_with_offset(point!(x: 2., y: -1.)),
+            Some((2., -1.))
+        );
+        // Beside the middle of the last segment
+        let (measure, offset) = line_string
+            .project_point_with_offset(point!(x: 4.5, y: 6.5))
+            .unwrap();
+        assert_relative_eq!(measure, 9.5);
+        assert_relative_eq!(offset, 2.5);
+        // On the line
+        assert_eq!(line_string.project_point(point!(x: 4., y: 2.)), Some(6.));
+    }
+
+    #[test]
+    fn beyond_ends() {
+        let line_string = line_string();
+        assert_eq!(
+            line_string.project_point_with_offset(point!(x: -3., y: 0.)),
+            Some((0., 3.))
+        );
+        assert_eq!(line_string.project_point(point!(x: -3., y: -4.)), Some(0.));
+        assert_eq!(line_string.project_point(point!(x: 12., y: 9.)), Some(12.));
+        assert_eq!(line_string.project_point(point!(x: 20., y: 0.)), Some(12.));
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.project_point(point!(x: 1., y: 1.)), None);
+
+        let single = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(
+            single.project_point_with_offset(point!(x: 4., y: 5.)),
+            Some((0., 5.))
+        );
+
+        let line_string = line_string();
+        assert_eq!(line_string.project_point(point!(x: f64::NAN, y: 1.)), None);
+    }
+}
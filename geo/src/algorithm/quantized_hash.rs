@@ -0,0 +1,363 @@
+use crate::{
+    CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Hash a geometry with its coordinates rounded to a number of decimal
+/// places, so that geometries which are almost the same hash the same, for
+/// use as a key when caching results computed from them.
+///
+/// The hash is lossy, and is meant for caching rather than for telling
+/// whether two geometries are identical: geometries whose coordinates
+/// differ by less than the precision hash the same, and unrelated
+/// geometries can collide. Coordinates are rounded to the nearest multiple
+/// of `10^-precision`, so two coordinates either side of a halfway point
+/// hash differently, however close they are. The precision is limited to
+/// the largest power of ten that `T` can represent, 308 decimal places
+/// for `f64` and 38 for `f32`, and any greater precision is treated as
+/// that.
+///
+/// The hash depends on the type of the geometry and the way its
+/// coordinates are grouped into parts, as well as the coordinates
+/// themselves, but a geometry hashes the same whether or not it is wrapped
+/// in a [`Geometry`]. It is stable, being computed with FNV-1a rather than
+/// the standard library's hasher, so it can be stored between runs.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::quantized_hash::QuantizedHash;
+/// use geo::point;
+///
+/// let point = point!(x: 1.2345, y: 6.789);
+/// let nudged = point!(x: 1.2345001, y: 6.789);
+/// let moved = point!(x: 1.2346, y: 6.789);
+///
+/// assert_eq!(point.quantized_hash(4), nudged.quantized_hash(4));
+/// assert_ne!(point.quantized_hash(4), moved.quantized_hash(4));
+/// // Only to three decimal places
+/// assert_eq!(point.quantized_hash(3), moved.quantized_hash(3));
+/// ```
+pub trait QuantizedHash<T: CoordFloat> {
+    fn quantized_hash(&self, precision: usize) -> u64;
+}
+
+macro_rules! impl_quantized_hash {
+    ($type:ident, $write:ident) => {
+        impl<T: CoordFloat> QuantizedHash<T> for $type<T> {
+            fn quantized_hash(&self, precision: usize) -> u64 {
+                let mut hasher = QuantizedHasher::new(precision);
+                hasher.$write(self);
+                hasher.state
+            }
+        }
+    };
+}
+
+impl_quantized_hash!(Point, write_point);
+impl_quantized_hash!(Line, write_line);
+impl_quantized_hash!(LineString, write_line_string);
+impl_quantized_hash!(Polygon, write_polygon);
+impl_quantized_hash!(MultiPoint, write_multi_point);
+impl_quantized_hash!(MultiLineString, write_multi_line_string);
+impl_quantized_hash!(MultiPolygon, write_multi_polygon);
+impl_quantized_hash!(GeometryCollection, write_geometry_collection);
+impl_quantized_hash!(Rect, write_rect);
+impl_quantized_hash!(Triangle, write_triangle);
+impl_quantized_hash!(Geometry, write_geometry);
+
+// Written before each geometry, so that different types of geometry with
+// the same coordinates hash differently
+const POINT_TAG: u64 = 1;
+const LINE_TAG: u64 = 2;
+const LINE_STRING_TAG: u64 = 3;
+const POLYGON_TAG: u64 = 4;
+const MULTI_POINT_TAG: u64 = 5;
+const MULTI_LINE_STRING_TAG: u64 = 6;
+const MULTI_POLYGON_TAG: u64 = 7;
+const GEOMETRY_COLLECTION_TAG: u64 = 8;
+const RECT_TAG: u64 = 9;
+const TRIANGLE_TAG: u64 = 10;
+
+// Written before each coordinate value
+const NAN_TAG: u8 = 0;
+const FINITE_TAG: u8 = 1;
+
+/// A 64-bit FNV-1a hasher, which rounds the coordinates it is given
+struct QuantizedHasher<T: CoordFloat> {
+    state: u64,
+    scale: T,
+}
+
+impl<T: CoordFloat> QuantizedHasher<T> {
+    fn new(precision: usize) -> Self {
+        let ten = T::from(10).unwrap();
+        // Beyond this, the scale would be infinite, and zero times it NaN
+        let max_precision = T::max_value().log10().floor().to_usize().unwrap();
+        QuantizedHasher {
+            state: 0xcbf2_9ce4_8422_2325,
+            scale: ten.powi(precision.min(max_precision) as i32),
+        }
+    }
+
+    fn write_u8(&mut self, byte: u8) {
+        self.state ^= u64::from(byte);
+        self.state = self.state.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        for byte in value.to_le_bytes().iter() {
+            self.write_u8(*byte);
+        }
+    }
+
+    fn write_value(&mut self, value: T) {
+        // Tagged, so that NaN, which is the same for all NaNs, is distinct
+        // from every rounded value
+        if value.is_nan() {
+            self.write_u8(NAN_TAG);
+            return;
+        }
+        // Saturating, for infinities and values too large to round
+        let rounded = (value * self.scale)
+            .round()
+            .to_i64()
+            .unwrap_or(if value > T::zero() {
+                i64::MAX
+            } else {
+                i64::MIN
+            });
+        self.write_u8(FINITE_TAG);
+        self.write_u64(rounded as u64);
+    }
+
+    fn write_coord(&mut self, coord: &Coordinate<T>) {
+        self.write_value(coord.x);
+        self.write_value(coord.y);
+    }
+
+    fn write_point(&mut self, point: &Point<T>) {
+        self.write_u64(POINT_TAG);
+        self.write_coord(&point.0);
+    }
+
+    fn write_line(&mut self, line: &Line<T>) {
+        self.write_u64(LINE_TAG);
+        self.write_coord(&line.start);
+        self.write_coord(&line.end);
+    }
+
+    fn write_coords(&mut self, coords: &[Coordinate<T>]) {
+        self.write_u64(coords.len() as u64);
+        for coord in coords {
+            self.write_coord(coord);
+        }
+    }
+
+    fn write_line_string(&mut self, line_string: &LineString<T>) {
+        self.write_u64(LINE_STRING_TAG);
+        self.write_coords(&line_string.0);
+    }
+
+    fn write_polygon(&mut self, polygon: &Polygon<T>) {
+        self.write_u64(POLYGON_TAG);
+        self.write_coords(&polygon.exterior().0);
+        self.write_u64(polygon.interiors().len() as u64);
+        for interior in polygon.interiors() {
+            self.write_coords(&interior.0);
+        }
+    }
+
+    fn write_multi_point(&mut self, multi_point: &MultiPoint<T>) {
+        self.write_u64(MULTI_POINT_TAG);
+        self.write_u64(multi_point.0.len() as u64);
+        for point in &multi_point.0 {
+            self.write_coord(&point.0);
+        }
+    }
+
+    fn write_multi_line_string(&mut self, multi_line_string: &MultiLineString<T>) {
+        self.write_u64(MULTI_LINE_STRING_TAG);
+        self.write_u64(multi_line_string.0.len() as u64);
+        for line_string in &multi_line_string.0 {
+            self.write_coords(&line_string.0);
+        }
+    }
+
+    fn write_multi_polygon(&mut self, multi_polygon: &MultiPolygon<T>) {
+        self.write_u64(MULTI_POLYGON_TAG);
+        self.write_u64(multi_polygon.0.len() as u64);
+        for polygon in &multi_polygon.0 {
+            self.write_polygon(polygon);
+        }
+    }
+
+    fn write_geometry_collection(&mut self, collection: &GeometryCollection<T>) {
+        self.write_u64(GEOMETRY_COLLECTION_TAG);
+        self.write_u64(collection.0.len() as u64);
+        for geometry in &collection.0 {
+            self.write_geometry(geometry);
+        }
+    }
+
+    fn write_rect(&mut self, rect: &Rect<T>) {
+        self.write_u64(RECT_TAG);
+        self.write_coord(&rect.min());
+        self.write_coord(&rect.max());
+    }
+
+    fn write_triangle(&mut self, triangle: &Triangle<T>) {
+        self.write_u64(TRIANGLE_TAG);
+        self.write_coord(&triangle.0);
+        self.write_coord(&triangle.1);
+        self.write_coord(&triangle.2);
+    }
+
+    fn write_geometry(&mut self, geometry: &Geometry<T>) {
+        match geometry {
+            Geometry::Point(g) => self.write_point(g),
+            Geometry::Line(g) => self.write_line(g),
+            Geometry::LineString(g) => self.write_line_string(g),
+            Geometry::Polygon(g) => self.write_polygon(g),
+            Geometry::MultiPoint(g) => self.write_multi_point(g),
+            Geometry::MultiLineString(g) => self.write_multi_line_string(g),
+            Geometry::MultiPolygon(g) => self.write_multi_polygon(g),
+            Geometry::GeometryCollection(g) => self.write_geometry_collection(g),
+            Geometry::Rect(g) => self.write_rect(g),
+            Geometry::Triangle(g) => self.write_triangle(g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn below_and_above_precision() {
+        let line_string = line_string![
+            (x: 1.23456, y: 2.),
+            (x: 3., y: 4.56789),
+        ];
+        let nudged = line_string![
+            (x: 1.23456004, y: 2.),
+            (x: 3., y: 4.56788997),
+        ];
+        let moved = line_string![
+            (x: 1.23457, y: 2.),
+            (x: 3., y: 4.56789),
+        ];
+        assert_eq!(line_string.quantized_hash(5), nudged.quantized_hash(5));
+        assert_ne!(line_string.quantized_hash(5), moved.quantized_hash(5));
+        assert_eq!(line_string.quantized_hash(4), moved.quantized_hash(4));
+        assert_ne!(line_string.quantized_hash(8), nudged.quantized_hash(8));
+
+        // Whole numbers, and to the nearest one
+        let point = point!(x: 10., y: -3.);
+        assert_eq!(
+            point.quantized_hash(0),
+            point!(x: 10.4, y: -2.6).quantized_hash(0)
+        );
+        assert_ne!(
+            point.quantized_hash(0),
+            point!(x: 10.6, y: -3.).quantized_hash(0)
+        );
+        // Negative zero is the same as zero
+        assert_eq!(
+            point!(x: 0., y: 0.).quantized_hash(2),
+            point!(x: -0., y: -0.001).quantized_hash(2)
+        );
+    }
+
+    #[test]
+    fn stable() {
+        // Changing how the hash is computed changes these
+        assert_eq!(
+            point!(x: 1., y: 2.).quantized_hash(3),
+            0x3729_11cc_4f2e_c6b4
+        );
+        assert_eq!(
+            Geometry::Point(point!(x: 1., y: 2.)).quantized_hash(3),
+            0x3729_11cc_4f2e_c6b4
+        );
+    }
+
+    #[test]
+    fn structure() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ];
+        let exterior = polygon.exterior().clone();
+        // The same coordinates, as different types of geometry
+        assert_ne!(polygon.quantized_hash(6), exterior.quantized_hash(6));
+        let points: MultiPoint<f64> = exterior.clone().into_points().into();
+        assert_ne!(exterior.quantized_hash(6), points.quantized_hash(6));
+        // And grouped into different parts
+        let split = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 1.), (x: 0., y: 0.)],
+        ]);
+        let joined = MultiLineString(vec![
+            line_string![(x: 0., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)],
+        ]);
+        assert_ne!(split.quantized_hash(6), joined.quantized_hash(6));
+
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(polygon.clone()),
+            Geometry::MultiLineString(split.clone()),
+        ]);
+        let same = GeometryCollection(vec![
+            Geometry::Polygon(polygon),
+            Geometry::MultiLineString(split),
+        ]);
+        assert_eq!(collection.quantized_hash(6), same.quantized_hash(6));
+    }
+
+    #[test]
+    fn non_finite() {
+        let nan = point!(x: f64::NAN, y: 0.);
+        assert_eq!(
+            nan.quantized_hash(3),
+            point!(x: -f64::NAN, y: 0.).quantized_hash(3)
+        );
+        assert_ne!(
+            nan.quantized_hash(3),
+            point!(x: 0., y: 0.).quantized_hash(3)
+        );
+        // Including those which round to -1, whose bits are all set
+        assert_ne!(
+            nan.quantized_hash(3),
+            point!(x: -0.001, y: 0.).quantized_hash(3)
+        );
+        assert_ne!(
+            nan.quantized_hash(0),
+            point!(x: -1., y: 0.).quantized_hash(0)
+        );
+        assert_ne!(
+            point!(x: f64::INFINITY, y: 0.).quantized_hash(3),
+            point!(x: f64::NEG_INFINITY, y: 0.).quantized_hash(3)
+        );
+        // Far too large to round, but still hashed
+        point!(x: 1e300, y: 0.).quantized_hash(usize::MAX);
+    }
+
+    #[test]
+    fn max_precision() {
+        let origin = point!(x: 0., y: 0.);
+        assert_eq!(origin.quantized_hash(400), origin.quantized_hash(308));
+        assert_eq!(
+            origin.quantized_hash(usize::MAX),
+            origin.quantized_hash(308)
+        );
+        assert_ne!(
+            origin.quantized_hash(400),
+            point!(x: f64::NAN, y: 0.).quantized_hash(400)
+        );
+        let origin = point!(x: 0f32, y: 0.);
+        assert_eq!(origin.quantized_hash(39), origin.quantized_hash(38));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::algorithm::vertex_angles::turn_angle;
+use crate::{CoordFloat, Coordinate, LineString, MultiPolygon, Polygon};
+
+/// Remove narrow spikes from the rings of a geometry.
+///
+/// A vertex is a spike if the interior angle between its two edges, in
+/// radians, is smaller than `min_angle`: i.e. the ring doubles back on
+/// itself sharply there, whether outwards or inwards. Such vertices are
+/// removed, which may in turn make their neighbours spikes, so vertices
+/// are removed until none are left. Repeated consecutive coordinates,
+/// including those left after removing a zero-width spike, are collapsed.
+///
+/// The rings remain closed, and start at the same coordinate unless it was
+/// removed. A ring is never reduced to fewer than three distinct vertices,
+/// so a ring which is nothing but spikes (such as a very thin triangle)
+/// may keep some of them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::remove_spikes::RemoveSpikes;
+/// use geo::polygon;
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     // A spike out of the top edge
+///     (x: 5.1, y: 10.),
+///     (x: 5., y: 20.),
+///     (x: 4.9, y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// let cleaned = polygon.remove_spikes(0.1);
+/// assert_eq!(cleaned.exterior().0.len(), polygon.exterior().0.len() - 1);
+/// assert_eq!(cleaned.unsigned_area(), 100.);
+/// ```
+pub trait RemoveSpikes<T: CoordFloat> {
+    fn remove_spikes(&self, min_angle: T) -> Self;
+}
+
+impl<T: CoordFloat> RemoveSpikes<T> for Polygon<T> {
+    fn remove_spikes(&self, min_angle: T) -> Self {
+        Polygon::new(
+            remove_ring_spikes(self.exterior(), min_angle),
+            self.interiors()
+                .iter()
+                .map(|interior| remove_ring_spikes(interior, min_angle))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RemoveSpikes<T> for MultiPolygon<T> {
+    fn remove_spikes(&self, min_angle: T) -> Self {
+        MultiPolygon(
+            self.iter()
+                .map(|polygon| polygon.remove_spikes(min_angle))
+                .collect(),
+        )
+    }
+}
+
+fn remove_ring_spikes<T: CoordFloat>(ring: &LineString<T>, min_angle: T) -> LineString<T> {
+    let mut coords = ring.0.clone();
+    coords.dedup();
+    // Without the closing coordinate
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+
+    let mut changed = true;
+    while changed && coords.len() > 3 {
+        changed = false;
+        let mut idx = 0;
+        while idx < coords.len() && coords.len() > 3 {
+            let len = coords.len();
+            let prev = coords[(idx + len - 1) % len];
+            let next = coords[(idx + 1) % len];
+            if coords[idx] == next || interior_angle(prev, coords[idx], next) < min_angle {
+                coords.remove(idx);
+                changed = true;
+            } else {
+                idx += 1;
+            }
+        }
+    }
+
+    if let Some(&first) = coords.first() {
+        coords.push(first);
+    }
+    LineString(coords)
+}
+
+/// The angle between the edges from `vertex` to `prev` and to `next`.
+fn interior_angle<T: CoordFloat>(
+    prev: Coordinate<T>,
+    vertex: Coordinate<T>,
+    next: Coordinate<T>,
+) -> T {
+    T::from(std::f64::consts::PI).unwrap() - turn_angle(vertex - prev, next - vertex).abs()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn outward_spike() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5.1, y: 10.),
+            (x: 5., y: 20.),
+            (x: 4.9, y: 10.),
+            (x: 0., y: 10.),
+        ];
+        assert_relative_eq!(polygon.unsigned_area(), 101.);
+
+        let cleaned = polygon.remove_spikes(0.1);
+        assert_eq!(
+            cleaned,
+            polygon![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5.1, y: 10.),
+                (x: 4.9, y: 10.),
+                (x: 0., y: 10.),
+            ]
+        );
+        assert_relative_eq!(cleaned.unsigned_area(), 100.);
+
+        // A smaller threshold keeps the spike
+        assert_eq!(polygon.remove_spikes(0.01), polygon);
+    }
+
+    #[test]
+    fn zero_width_spikes() {
+        // A spike into a hole, and one that itself has a spike on its side
+        let polygon = Polygon::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            vec![line_string![
+                (x: 4., y: 4.),
+                (x: 4., y: 6.),
+                (x: 6., y: 6.),
+                (x: 8., y: 8.),
+                (x: 7., y: 8.5),
+                (x: 8., y: 8.),
+                (x: 6., y: 6.),
+                (x: 6., y: 4.),
+                (x: 4., y: 4.),
+            ]],
+        );
+        let cleaned = polygon.remove_spikes(0.1);
+        assert_eq!(
+            cleaned.interiors()[0],
+            line_string![
+                (x: 4., y: 4.),
+                (x: 4., y: 6.),
+                (x: 6., y: 6.),
+                (x: 6., y: 4.),
+                (x: 4., y: 4.),
+            ]
+        );
+        assert_eq!(cleaned.exterior(), polygon.exterior());
+        assert_relative_eq!(cleaned.unsigned_area(), 96.);
+    }
+
+    #[test]
+    fn start_removed() {
+        let polygon = polygon![
+            (x: 5., y: 20.),
+            (x: 4.9, y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5.1, y: 10.),
+        ];
+        let cleaned = polygon.remove_spikes(0.1);
+        assert!(cleaned.exterior().is_closed());
+        assert_eq!(cleaned.exterior().0.len(), 7);
+        assert_relative_eq!(cleaned.unsigned_area(), 100.);
+    }
+
+    #[test]
+    fn thin_triangle() {
+        let triangle = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 0.1)];
+        assert_eq!(triangle.remove_spikes(0.1), triangle);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::{CoordFloat, LineString};
+use std::iter::Sum;
+
+/// Resample a geometry into evenly spaced coordinates along its path.
+///
+/// The coordinates of the result lie on the original path, every `interval`
+/// of (Euclidean) arc length from its start. The true start and end
+/// coordinates are always kept, so the final interval may be shorter than
+/// `interval`. The other original vertices are not kept, unless they happen
+/// to fall on the spacing.
+///
+/// This differs from densification, which keeps the original vertices and
+/// only caps the length of each segment.
+///
+/// A `LineString` with fewer than two coordinates, or an `interval` which is
+/// not a positive, finite number, is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::resample::Resample;
+/// use geo::line_string;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 3., y: 0.),
+///     (x: 3., y: 2.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.resample(2.),
+///     line_string![
+///         (x: 0., y: 0.),
+///         (x: 2., y: 0.),
+///         (x: 3., y: 1.),
+///         (x: 3., y: 2.),
+///     ]
+/// );
+/// ```
+pub trait Resample<T: CoordFloat> {
+    fn resample(&self, interval: T) -> Self;
+}
+
+impl<T> Resample<T> for LineString<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn resample(&self, interval: T) -> Self {
+        if self.0.len() < 2 || !(interval > T::zero() && interval.is_finite()) {
+            return self.clone();
+        }
+
+        let mut resampled = vec![self.0[0]];
+        // The arc length at the start of the current line
+        let mut start_length = T::zero();
+        let mut count = 1;
+        for line in self.lines() {
+            let length = line.euclidean_length();
+            let end_length = start_length + length;
+            loop {
+                // Multiplying, rather than accumulating, avoids drift
+                let target = interval * T::from(count).unwrap();
+                if target >= end_length {
+                    break;
+                }
+                let fraction = (target - start_length) / length;
+                resampled.push(line.start + line.delta() * fraction);
+                count += 1;
+            }
+            start_length = end_length;
+        }
+        resampled.push(*self.0.last().unwrap());
+        LineString(resampled)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::line_string;
+
+    #[test]
+    fn spacing() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.3),
+        ];
+        let resampled = line_string.resample(1.5);
+
+        assert_eq!(resampled.0.first(), line_string.0.first());
+        assert_eq!(resampled.0.last(), line_string.0.last());
+        // 20.3 is 13 intervals of 1.5, and 0.8 to the end
+        assert_eq!(resampled.0.len(), 15);
+        let last = resampled.lines().last().unwrap();
+        assert_relative_eq!(last.euclidean_length(), 0.8, epsilon = 1e-12);
+        // Every resampled coordinate lies on the path, `interval` along it
+        for (idx, point) in resampled.points_iter().enumerate().take(14) {
+            assert!(point.euclidean_distance(&line_string) < 1e-12);
+            let along = if point.y() == 0. {
+                point.x()
+            } else {
+                10. + point.y()
+            };
+            assert_relative_eq!(along, 1.5 * idx as f64, epsilon = 1e-12);
+        }
+        // The vertex at the corner is cut
+        assert!(!resampled.0.contains(&line_string.0[1]));
+    }
+
+    #[test]
+    fn exact_multiple() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(
+            line_string.resample(2.),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)]
+        );
+        // A longer interval keeps only the endpoints
+        assert_eq!(line_string.resample(5.), line_string);
+    }
+
+    #[test]
+    fn invalid() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(line_string.resample(0.), line_string);
+        assert_eq!(line_string.resample(-1.), line_string);
+        assert_eq!(line_string.resample(f64::NAN), line_string);
+
+        let single = line_string![(x: 1., y: 1.)];
+        assert_eq!(single.resample(1.), single);
+    }
+}
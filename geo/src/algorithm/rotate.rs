@@ -1,8 +1,10 @@
 use crate::algorithm::centroid::Centroid;
 use crate::algorithm::map_coords::MapCoords;
 use crate::{
-    CoordFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    CoordFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use geo_types::private_utils::get_bounding_rect;
 use num_traits::FromPrimitive;
 use std::iter::Sum;
 
@@ -121,15 +123,84 @@ pub trait RotatePoint<T> {
         T: CoordFloat;
 }
 
-impl<T, G> RotatePoint<T> for G
+macro_rules! impl_rotate_point_by_map_coords {
+    ($type:ident) => {
+        impl<T: CoordFloat> RotatePoint<T> for $type<T> {
+            fn rotate_around_point(&self, angle: T, point: Point<T>) -> Self {
+                let (sin_theta, cos_theta) = angle.to_radians().sin_cos();
+                let (x0, y0) = point.x_y();
+                self.map_coords(|&(x, y)| rotate_inner(x, y, x0, y0, sin_theta, cos_theta).x_y())
+            }
+        }
+    };
+}
+
+impl_rotate_point_by_map_coords!(Point);
+impl_rotate_point_by_map_coords!(Line);
+impl_rotate_point_by_map_coords!(LineString);
+impl_rotate_point_by_map_coords!(Polygon);
+impl_rotate_point_by_map_coords!(MultiPoint);
+impl_rotate_point_by_map_coords!(MultiLineString);
+impl_rotate_point_by_map_coords!(MultiPolygon);
+impl_rotate_point_by_map_coords!(Triangle);
+
+impl<T> RotatePoint<T> for Rect<T>
 where
     T: CoordFloat,
-    G: MapCoords<T, T, Output = G>,
 {
+    /// Rotate the Rect around a point by the given number of degrees
+    ///
+    /// As a `Rect` is axis-aligned, the result is the bounding rectangle
+    /// of the rotated corners, as for [`Rotate`].
     fn rotate_around_point(&self, angle: T, point: Point<T>) -> Self {
-        let (sin_theta, cos_theta) = angle.to_radians().sin_cos();
-        let (x0, y0) = point.x_y();
-        self.map_coords(|&(x, y)| rotate_inner(x, y, x0, y0, sin_theta, cos_theta).x_y())
+        let (min, max) = (self.min(), self.max());
+        let corners = vec![
+            Point::from(min),
+            Point::new(max.x, min.y),
+            Point::from(max),
+            Point::new(min.x, max.y),
+        ];
+        get_bounding_rect(rotate_many(angle, point, corners.into_iter()).map(|p| p.0)).unwrap()
+    }
+}
+
+impl<T> RotatePoint<T> for Geometry<T>
+where
+    T: CoordFloat,
+{
+    fn rotate_around_point(&self, angle: T, point: Point<T>) -> Self {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.rotate_around_point(angle, point)),
+            Geometry::Line(g) => Geometry::Line(g.rotate_around_point(angle, point)),
+            Geometry::LineString(g) => Geometry::LineString(g.rotate_around_point(angle, point)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.rotate_around_point(angle, point)),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.rotate_around_point(angle, point)),
+            Geometry::MultiLineString(g) => {
+                Geometry::MultiLineString(g.rotate_around_point(angle, point))
+            }
+            Geometry::MultiPolygon(g) => {
+                Geometry::MultiPolygon(g.rotate_around_point(angle, point))
+            }
+            Geometry::GeometryCollection(g) => {
+                Geometry::GeometryCollection(g.rotate_around_point(angle, point))
+            }
+            Geometry::Rect(g) => Geometry::Rect(g.rotate_around_point(angle, point)),
+            Geometry::Triangle(g) => Geometry::Triangle(g.rotate_around_point(angle, point)),
+        }
+    }
+}
+
+impl<T> RotatePoint<T> for GeometryCollection<T>
+where
+    T: CoordFloat,
+{
+    fn rotate_around_point(&self, angle: T, point: Point<T>) -> Self {
+        GeometryCollection(
+            self.0
+                .iter()
+                .map(|geometry| geometry.rotate_around_point(angle, point))
+                .collect(),
+        )
     }
 }
 
@@ -219,9 +290,25 @@ where
     }
 }
 
+impl<T> Rotate<T> for Rect<T>
+where
+    T: CoordFloat,
+{
+    /// Rotate the Rect about its center by the given number of degrees
+    ///
+    /// As a `Rect` is axis-aligned, the result is the bounding rectangle
+    /// of the rotated corners. Thus, only rotation by integer multiples of
+    /// 90 degrees preserves the original shape, and other angles result in
+    /// a larger rectangle enclosing the rotated one.
+    fn rotate(&self, angle: T) -> Self {
+        self.rotate_around_point(angle, Point::from(self.center()))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::algorithm::bounding_rect::BoundingRect;
     use crate::{line_string, point, polygon, Coordinate, Point};
     use approx::assert_relative_eq;
 
@@ -341,6 +428,66 @@ mod test {
         assert_relative_eq!(line0.rotate(90.0), line1);
     }
     #[test]
+    fn test_rotate_rect() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 2. });
+
+        // A quarter turn about the center swaps width and height
+        let rotated = rect.rotate(90.);
+        assert_relative_eq!(rotated.min(), Coordinate { x: 1., y: -1. });
+        assert_relative_eq!(rotated.max(), Coordinate { x: 3., y: 3. });
+
+        let rotated = rect.rotate(180.);
+        assert_relative_eq!(rotated.min(), rect.min());
+        assert_relative_eq!(rotated.max(), rect.max());
+
+        // Other angles result in the enclosing axis-aligned rectangle
+        let square = Rect::new(Coordinate { x: -1., y: -1. }, Coordinate { x: 1., y: 1. });
+        let rotated = square.rotate(45.);
+        let half_diagonal = 2f64.sqrt();
+        assert_relative_eq!(
+            rotated.min(),
+            Coordinate {
+                x: -half_diagonal,
+                y: -half_diagonal
+            }
+        );
+        assert_relative_eq!(
+            rotated.max(),
+            Coordinate {
+                x: half_diagonal,
+                y: half_diagonal
+            }
+        );
+    }
+    #[test]
+    fn test_rotate_rect_around_point() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 2. });
+        let rotated = rect.rotate_around_point(90., Point::new(0., 0.));
+        assert_relative_eq!(rotated.min(), Coordinate { x: -2., y: 0. }, epsilon = 1e-12);
+        assert_relative_eq!(rotated.max(), Coordinate { x: 0., y: 4. }, epsilon = 1e-12);
+    }
+    #[test]
+    fn test_rotate_rect_agrees() {
+        let assert_rects_eq = |a: Rect<f64>, b: Rect<f64>| {
+            assert_relative_eq!(a.min(), b.min());
+            assert_relative_eq!(a.max(), b.max());
+        };
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 2. });
+        let center = Point::from(rect.center());
+        for &angle in &[30., 90., -135., 200.] {
+            let rotated = rect.rotate(angle);
+            assert_rects_eq(rect.rotate_around_point(angle, center), rotated);
+            // The bounding rectangle of the rotated polygon
+            let polygon = rect.to_polygon().rotate_around_point(angle, center);
+            assert_rects_eq(polygon.bounding_rect().unwrap(), rotated);
+            // And within a Geometry
+            match Geometry::Rect(rect).rotate_around_point(angle, center) {
+                Geometry::Rect(in_geometry) => assert_rects_eq(in_geometry, rotated),
+                _ => unreachable!(),
+            }
+        }
+    }
+    #[test]
     fn test_rotate_line_around_point() {
         let line0 = Line::new(Point::new(0., 0.), Point::new(0., 2.));
         let line1 = Line::new(Point::new(0., 0.), Point::new(-2., 0.));
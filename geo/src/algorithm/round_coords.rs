@@ -0,0 +1,224 @@
+use crate::algorithm::map_coords::MapCoords;
+use crate::{
+    CoordFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Round the coordinates of a geometry to a number of decimal places.
+///
+/// Each `x` and `y` value is rounded to the nearest multiple of
+/// `10^-decimals`, with halfway cases rounded away from zero (as by
+/// [`f64::round`]). Since most decimal fractions can't be represented exactly
+/// in binary floating point, the result is the closest representable value,
+/// which prints with at most `decimals` decimal places.
+///
+/// Rounding may make consecutive coordinates equal: these are collapsed into
+/// one in `LineString`s and `Polygon` rings, which therefore remain closed if
+/// they were. Other geometries keep all of their coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::round_coords::RoundCoords;
+/// use geo::{line_string, point};
+///
+/// let point = point!(x: 1.2345, y: -6.789);
+/// assert_eq!(point.round_coords(2), point!(x: 1.23, y: -6.79));
+///
+/// let line_string = line_string![(x: 0.001, y: 0.), (x: 0.002, y: 0.), (x: 1., y: 1.)];
+/// assert_eq!(
+///     line_string.round_coords(1),
+///     line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+/// );
+/// ```
+pub trait RoundCoords<T: CoordFloat> {
+    fn round_coords(&self, decimals: u32) -> Self;
+}
+
+fn round<T: CoordFloat>(value: T, decimals: u32) -> T {
+    let scale = T::from(10)
+        .unwrap()
+        .powi(decimals.min(i32::MAX as u32) as i32);
+    let scaled = value * scale;
+    if scaled.is_finite() {
+        scaled.round() / scale
+    } else {
+        // Too many decimals to make a difference
+        value
+    }
+}
+
+fn round_tuple<T: CoordFloat>(&(x, y): &(T, T), decimals: u32) -> (T, T) {
+    (round(x, decimals), round(y, decimals))
+}
+
+impl<T: CoordFloat> RoundCoords<T> for Point<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        self.map_coords(|coord| round_tuple(coord, decimals))
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for Line<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        self.map_coords(|coord| round_tuple(coord, decimals))
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for LineString<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        let mut rounded = self.map_coords(|coord| round_tuple(coord, decimals));
+        rounded.0.dedup();
+        rounded
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for Polygon<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        Polygon::new(
+            self.exterior().round_coords(decimals),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.round_coords(decimals))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for MultiPoint<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        self.map_coords(|coord| round_tuple(coord, decimals))
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for MultiLineString<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        MultiLineString(
+            self.iter()
+                .map(|line_string| line_string.round_coords(decimals))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for MultiPolygon<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        MultiPolygon(
+            self.iter()
+                .map(|polygon| polygon.round_coords(decimals))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for Rect<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        self.map_coords(|coord| round_tuple(coord, decimals))
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for Triangle<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        self.map_coords(|coord| round_tuple(coord, decimals))
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for GeometryCollection<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        GeometryCollection(
+            self.iter()
+                .map(|geometry| geometry.round_coords(decimals))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RoundCoords<T> for Geometry<T> {
+    fn round_coords(&self, decimals: u32) -> Self {
+        match self {
+            Geometry::Point(x) => Geometry::Point(x.round_coords(decimals)),
+            Geometry::Line(x) => Geometry::Line(x.round_coords(decimals)),
+            Geometry::LineString(x) => Geometry::LineString(x.round_coords(decimals)),
+            Geometry::Polygon(x) => Geometry::Polygon(x.round_coords(decimals)),
+            Geometry::MultiPoint(x) => Geometry::MultiPoint(x.round_coords(decimals)),
+            Geometry::MultiLineString(x) => Geometry::MultiLineString(x.round_coords(decimals)),
+            Geometry::MultiPolygon(x) => Geometry::MultiPolygon(x.round_coords(decimals)),
+            Geometry::GeometryCollection(x) => {
+                Geometry::GeometryCollection(x.round_coords(decimals))
+            }
+            Geometry::Rect(x) => Geometry::Rect(x.round_coords(decimals)),
+            Geometry::Triangle(x) => Geometry::Triangle(x.round_coords(decimals)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    #[test]
+    fn point() {
+        let point = Geometry::Point(point!(x: 12.3456, y: -0.005));
+        assert_eq!(
+            point.round_coords(2),
+            Geometry::Point(point!(x: 12.35, y: -0.01))
+        );
+        assert_eq!(
+            point.round_coords(0),
+            Geometry::Point(point!(x: 12., y: -0.))
+        );
+        // More decimals than can be represented
+        assert_eq!(point.round_coords(400), point);
+    }
+
+    #[test]
+    fn ring_stays_closed() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0.01, y: 0.02),
+                (x: 10.04, y: 0.),
+                (x: 10.03, y: 0.01),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [(x: 4., y: 4.), (x: 6.02, y: 4.), (x: 6., y: 6.), (x: 4.01, y: 4.)],
+            ],
+        ];
+        let rounded = Geometry::Polygon(polygon).round_coords(1);
+        assert_eq!(
+            rounded,
+            Geometry::Polygon(polygon![
+                exterior: [
+                    (x: 0., y: 0.),
+                    (x: 10., y: 0.),
+                    (x: 10., y: 10.),
+                    (x: 0., y: 10.),
+                    (x: 0., y: 0.),
+                ],
+                interiors: [
+                    [(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 4.)],
+                ],
+            ])
+        );
+    }
+
+    #[test]
+    fn collection() {
+        let collection = GeometryCollection(vec![
+            Geometry::MultiPoint(MultiPoint(vec![
+                point!(x: 1.04, y: 1.),
+                point!(x: 1.01, y: 1.),
+            ])),
+            Geometry::Line(Line::new((0.55, 0.), (1., 1.))),
+        ]);
+        assert_eq!(
+            collection.round_coords(1),
+            GeometryCollection(vec![
+                // Points of a `MultiPoint` aren't collapsed
+                Geometry::MultiPoint(MultiPoint(vec![point!(x: 1., y: 1.), point!(x: 1., y: 1.)])),
+                Geometry::Line(Line::new((0.6, 0.), (1., 1.))),
+            ])
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{CoordFloat, Coordinate, LineString};
+
+/// Round the corners of a `LineString`, replacing each of its interior
+/// vertices with a circular arc of a given radius, as in CAD-style
+/// rendering.
+///
+/// Each arc is tangent to the segments on either side of its vertex, and
+/// is approximated by `segments` straight segments, so a single segment
+/// chamfers the corner instead. Where the segments beside a corner are too
+/// short for an arc of the given radius, its radius is reduced, so that
+/// the arc takes up at most half of each of them, and the arcs of
+/// neighbouring corners don't overlap.
+///
+/// The first and last coordinates are kept, even if the `LineString` is
+/// closed. Vertices where the line doesn't turn, or turns straight back on
+/// itself, are kept as they are, as are repeated coordinates. The
+/// `LineString` is returned unchanged if `radius` isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::round_corners::RoundCorners;
+/// use geo::line_string;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+/// ];
+///
+/// // Chamfer the corner
+/// assert_relative_eq!(
+///     line_string.round_corners(2., 1),
+///     line_string![
+///         (x: 0., y: 0.),
+///         (x: 8., y: 0.),
+///         (x: 10., y: 2.),
+///         (x: 10., y: 10.),
+///     ],
+///     epsilon = 1e-9
+/// );
+///
+/// // Round it, with a quarter circle in 8 segments
+/// assert_eq!(line_string.round_corners(2., 8).0.len(), 11);
+/// ```
+pub trait RoundCorners<T: CoordFloat> {
+    fn round_corners(&self, radius: T, segments: usize) -> Self;
+}
+
+impl<T: CoordFloat> RoundCorners<T> for LineString<T> {
+    fn round_corners(&self, radius: T, segments: usize) -> Self {
+        let coords = &self.0;
+        if radius.is_nan() || radius <= T::zero() || coords.len() < 3 {
+            return self.clone();
+        }
+        let segments = segments.max(1);
+        let two = T::one() + T::one();
+
+        let mut rounded = vec![coords[0]];
+        for window in coords.windows(3) {
+            let (prev, corner, next) = (window[0], window[1], window[2]);
+            let (to_prev, to_next) = (prev - corner, next - corner);
+            let (prev_length, next_length) = (length(to_prev), length(to_next));
+            if prev_length == T::zero() || next_length == T::zero() {
+                rounded.push(corner);
+                continue;
+            }
+            let (u, w) = (to_prev / prev_length, to_next / next_length);
+            // The angle between the segments, at the corner
+            let angle = (u.x * w.y - u.y * w.x).abs().atan2(u.x * w.x + u.y * w.y);
+            let half_angle = angle / two;
+            let tan = half_angle.tan();
+            if !tan.is_finite() || tan <= T::epsilon() || half_angle.cos() <= T::epsilon() {
+                rounded.push(corner);
+                continue;
+            }
+
+            // The distance from the corner to where the arc meets each
+            // segment
+            let tangent_length = (radius / tan).min(prev_length / two).min(next_length / two);
+            let radius = tangent_length * tan;
+            let start = corner + u * tangent_length;
+            let end = corner + w * tangent_length;
+            let bisector = u + w;
+            let center = corner + bisector / length(bisector) * (radius / half_angle.sin());
+
+            let start_angle = (start.y - center.y).atan2(start.x - center.x);
+            let end_angle = (end.y - center.y).atan2(end.x - center.x);
+            // The short way round, from `start` to `end`
+            let pi = T::from(std::f64::consts::PI).unwrap();
+            let mut sweep = end_angle - start_angle;
+            if sweep > pi {
+                sweep = sweep - two * pi;
+            } else if sweep < -pi {
+                sweep = sweep + two * pi;
+            }
+
+            // Where a neighbouring arc already ends
+            if rounded.last() != Some(&start) {
+                rounded.push(start);
+            }
+            for i in 1..segments {
+                let angle = start_angle + sweep * T::from(i).unwrap() / T::from(segments).unwrap();
+                rounded.push(Coordinate {
+                    x: center.x + radius * angle.cos(),
+                    y: center.y + radius * angle.sin(),
+                });
+            }
+            rounded.push(end);
+        }
+        rounded.push(coords[coords.len() - 1]);
+        LineString(rounded)
+    }
+}
+
+fn length<T: CoordFloat>(c: Coordinate<T>) -> T {
+    c.x.hypot(c.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn bend() -> LineString<f64> {
+        line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+        ]
+    }
+
+    fn distance(a: Coordinate<f64>, b: Coordinate<f64>) -> f64 {
+        length(a - b)
+    }
+
+    #[test]
+    fn right_angle() {
+        let rounded = bend().round_corners(2., 8);
+        let coords = &rounded.0;
+        assert_eq!(coords.len(), 11);
+        assert_eq!(coords[0], Coordinate { x: 0., y: 0. });
+        assert_relative_eq!(coords[1], Coordinate { x: 8., y: 0. });
+        assert_relative_eq!(coords[9], Coordinate { x: 10., y: 2. });
+        assert_eq!(coords[10], Coordinate { x: 10., y: 10. });
+
+        // The corner is replaced by a quarter circle, evenly divided
+        let center = Coordinate { x: 8., y: 2. };
+        for &coord in &coords[1..10] {
+            assert_relative_eq!(distance(coord, center), 2., epsilon = 1e-9);
+        }
+        let chord = distance(coords[1], coords[2]);
+        for pair in coords[1..10].windows(2) {
+            assert_relative_eq!(distance(pair[0], pair[1]), chord, epsilon = 1e-9);
+        }
+        assert!(!coords.contains(&Coordinate { x: 10., y: 0. }));
+
+        // The other way round the corner
+        let mut reversed = bend();
+        reversed.0.reverse();
+        let mut rounded = reversed.round_corners(2., 8);
+        rounded.0.reverse();
+        for (a, b) in rounded.0.iter().zip(coords) {
+            assert_relative_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn clamped() {
+        // Too short for a radius of 2, so it is reduced to 0.5
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 10.),
+        ];
+        let rounded = line_string.round_corners(2., 4);
+        let center = Coordinate { x: 0.5, y: 0.5 };
+        assert_relative_eq!(rounded.0[1], Coordinate { x: 0.5, y: 0. });
+        assert_relative_eq!(rounded.0[5], Coordinate { x: 1., y: 0.5 });
+        for &coord in &rounded.0[1..6] {
+            assert_relative_eq!(distance(coord, center), 0.5, epsilon = 1e-9);
+        }
+
+        // Neighbouring corners share the segment between them
+        let zigzag = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 8., y: 1.),
+        ];
+        let rounded = zigzag.round_corners(2., 1);
+        assert_eq!(
+            rounded,
+            line_string![
+                (x: 0., y: 0.),
+                (x: 3.5, y: 0.),
+                (x: 4., y: 0.5),
+                (x: 4.5, y: 1.),
+                (x: 8., y: 1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        assert_eq!(bend().round_corners(0., 8), bend());
+        assert_eq!(bend().round_corners(-1., 8), bend());
+
+        let straight = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+        ];
+        assert_eq!(straight.round_corners(1., 8), straight);
+
+        let short = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(short.round_corners(1., 8), short);
+    }
+}
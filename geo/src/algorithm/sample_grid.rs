@@ -0,0 +1,130 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::{Coordinate, GeoFloat, MultiPoint, Point, Polygon};
+
+/// Sample the interior of a geometry at the points of a regular grid.
+///
+/// The grid is anchored at the minimum corner of the geometry's bounding
+/// rectangle: its points are at `(min.x + i * spacing, min.y + j * spacing)`
+/// for non-negative integers `i` and `j`, up to and including the maximum
+/// corner. Only the grid points lying strictly in the interior of the
+/// geometry are kept; in particular, points inside holes or on the
+/// boundary are discarded.
+///
+/// The points are ordered by row (increasing `y`), then by column
+/// (increasing `x`). An empty `MultiPoint` is returned if `spacing` is not
+/// a positive, finite number.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::sample_grid::SampleGrid;
+/// use geo::{point, polygon};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// let sampled = square.sample_grid(1.);
+/// assert_eq!(sampled.0, vec![point!(x: 1., y: 1.)]);
+/// ```
+pub trait SampleGrid<T: GeoFloat> {
+    fn sample_grid(&self, spacing: T) -> MultiPoint<T>;
+}
+
+impl<T: GeoFloat> SampleGrid<T> for Polygon<T> {
+    fn sample_grid(&self, spacing: T) -> MultiPoint<T> {
+        let rect = match self.bounding_rect() {
+            Some(rect) => rect,
+            None => return MultiPoint(vec![]),
+        };
+        if !(spacing > T::zero() && spacing.is_finite()) {
+            return MultiPoint(vec![]);
+        }
+
+        let steps = |extent: T| (extent / spacing).floor().to_usize().unwrap_or(0);
+        let columns = steps(rect.width());
+        let rows = steps(rect.height());
+        let min = rect.min();
+
+        let mut points = vec![];
+        for j in 0..=rows {
+            let y = min.y + T::from(j).unwrap() * spacing;
+            for i in 0..=columns {
+                let coord = Coordinate {
+                    x: min.x + T::from(i).unwrap() * spacing,
+                    y,
+                };
+                if self.coordinate_position(&coord) == CoordPos::Inside {
+                    points.push(Point(coord));
+                }
+            }
+        }
+        MultiPoint(points)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::contains::Contains;
+    use crate::polygon;
+
+    #[test]
+    fn unit_square() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        // A 5x5 grid, of which the outer ring of points lies on the boundary
+        assert_eq!(square.sample_grid(0.25).0.len(), 9);
+        // Grid points need not reach the maximum corner
+        assert_eq!(square.sample_grid(0.3).0.len(), 9);
+        assert_eq!(square.sample_grid(2.).0.len(), 0);
+    }
+
+    #[test]
+    fn skips_holes() {
+        let donut = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [
+                    (x: 2.5, y: 2.5),
+                    (x: 7.5, y: 2.5),
+                    (x: 7.5, y: 7.5),
+                    (x: 2.5, y: 7.5),
+                ],
+            ],
+        ];
+        let hole = Polygon::new(donut.interiors()[0].clone(), vec![]);
+
+        let sampled = donut.sample_grid(1.);
+        // 9x9 interior points, less the 5x5 points within the hole
+        assert_eq!(sampled.0.len(), 81 - 25);
+        assert!(sampled.iter().all(|point| !hole.contains(point)));
+        assert!(sampled.iter().all(|point| donut.contains(point)));
+    }
+
+    #[test]
+    fn invalid_spacing() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        assert!(square.sample_grid(0.).0.is_empty());
+        assert!(square.sample_grid(-1.).0.is_empty());
+        assert!(square.sample_grid(f64::NAN).0.is_empty());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::map_coords::MapCoords;
+use crate::{CoordFloat, Point, Rect};
+
+pub trait Scale<T: CoordFloat> {
+    /// Scale a Geometry by a factor, about the center of its bounding
+    /// rectangle.
+    ///
+    /// A factor greater than one enlarges the geometry, and one between
+    /// zero and one shrinks it. A negative factor also mirrors it through
+    /// the center. Empty geometries are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::scale::Scale;
+    /// use geo::line_string;
+    ///
+    /// let ls = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// let scaled = ls.scale(2.0);
+    ///
+    /// assert_eq!(scaled, line_string![
+    ///     (x: -5.0, y: -5.0),
+    ///     (x: 15.0, y: 15.0),
+    /// ]);
+    /// ```
+    fn scale(&self, factor: T) -> Self;
+
+    /// Scale a Geometry by separate factors along the `x` and `y` axes,
+    /// about an arbitrary origin.
+    ///
+    /// The origin is left in place, and every coordinate's offset from it
+    /// is multiplied by the factors. For a `Rect`, `min` and `max` are
+    /// re-sorted if a negative factor swaps them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::scale::Scale;
+    /// use geo::{point, polygon};
+    ///
+    /// let polygon = polygon![
+    ///     (x: 1.0, y: 1.0),
+    ///     (x: 2.0, y: 1.0),
+    ///     (x: 2.0, y: 2.0),
+    ///     (x: 1.0, y: 2.0),
+    /// ];
+    ///
+    /// let scaled = polygon.scale_around_point(3.0, 0.5, point!(x: 1.0, y: 1.0));
+    ///
+    /// assert_eq!(scaled, polygon![
+    ///     (x: 1.0, y: 1.0),
+    ///     (x: 4.0, y: 1.0),
+    ///     (x: 4.0, y: 1.5),
+    ///     (x: 1.0, y: 1.5),
+    /// ]);
+    /// ```
+    fn scale_around_point(&self, x_factor: T, y_factor: T, origin: Point<T>) -> Self;
+}
+
+impl<T, G> Scale<T> for G
+where
+    T: CoordFloat,
+    G: Clone + MapCoords<T, T, Output = G> + BoundingRect<T>,
+    <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+{
+    fn scale(&self, factor: T) -> Self {
+        match self.bounding_rect().into() {
+            Some(bounding_rect) => {
+                self.scale_around_point(factor, factor, bounding_rect.center().into())
+            }
+            None => self.clone(),
+        }
+    }
+
+    fn scale_around_point(&self, x_factor: T, y_factor: T, origin: Point<T>) -> Self {
+        let (x0, y0) = origin.x_y();
+        self.map_coords(|&(x, y)| (x0 + (x - x0) * x_factor, y0 + (y - y0) * y_factor))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{
+        line_string, point, polygon, Coordinate, Geometry, GeometryCollection, LineString,
+        MultiPoint,
+    };
+
+    #[test]
+    fn test_scale_polygon() {
+        let polygon = polygon![
+            (x: 1.0, y: 1.0),
+            (x: 4.0, y: 1.0),
+            (x: 4.0, y: 3.0),
+            (x: 2.0, y: 5.0),
+        ];
+        let scaled = polygon.scale(2.0);
+        assert_relative_eq!(scaled.unsigned_area(), polygon.unsigned_area() * 4.0);
+        // The bounding rectangle is enlarged about its center
+        let rect = scaled.bounding_rect().unwrap();
+        assert_eq!(rect.center(), polygon.bounding_rect().unwrap().center());
+        assert_eq!(rect.width(), 6.0);
+        assert_eq!(rect.height(), 8.0);
+        assert_eq!(
+            scaled,
+            polygon![
+                (x: -0.5, y: -1.0),
+                (x: 5.5, y: -1.0),
+                (x: 5.5, y: 3.0),
+                (x: 1.5, y: 7.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scale_around_corner() {
+        let polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 2.0, y: 0.0),
+            (x: 2.0, y: 2.0),
+            (x: 0.0, y: 2.0),
+        ];
+        let corner = point!(x: 2.0, y: 2.0);
+        let scaled = polygon.scale_around_point(3.0, 0.5, corner);
+        assert_eq!(
+            scaled,
+            polygon![
+                (x: -4.0, y: 1.0),
+                (x: 2.0, y: 1.0),
+                (x: 2.0, y: 2.0),
+                (x: -4.0, y: 2.0),
+            ]
+        );
+        assert_relative_eq!(scaled.unsigned_area(), polygon.unsigned_area() * 1.5);
+    }
+
+    #[test]
+    fn test_scale_rect() {
+        let rect = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 2.0, y: 1.0 });
+        let mirrored = rect.scale_around_point(-1.0, 2.0, point!(x: 0.0, y: 0.0));
+        assert_eq!(mirrored.min(), Coordinate { x: -2.0, y: 0.0 });
+        assert_eq!(mirrored.max(), Coordinate { x: 0.0, y: 2.0 });
+
+        let shrunk = rect.scale(0.5);
+        assert_eq!(shrunk.min(), Coordinate { x: 0.5, y: 0.25 });
+        assert_eq!(shrunk.max(), Coordinate { x: 1.5, y: 0.75 });
+    }
+
+    #[test]
+    fn test_scale_geometries() {
+        let point = point!(x: 1.0, y: 2.0);
+        assert_eq!(point.scale(10.0), point);
+
+        let multi_point = MultiPoint(vec![point!(x: 0.0, y: 0.0), point!(x: 2.0, y: 4.0)]);
+        let scaled = multi_point.scale(-1.0);
+        assert_eq!(
+            scaled,
+            MultiPoint(vec![point!(x: 2.0, y: 4.0), point!(x: 0.0, y: 0.0)])
+        );
+
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point!(x: 0.0, y: 0.0)),
+            Geometry::LineString(line_string![(x: 2.0, y: 0.0), (x: 2.0, y: 2.0)]),
+        ]);
+        assert_eq!(
+            Geometry::GeometryCollection(collection).scale(3.0),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::Point(point!(x: -2.0, y: -2.0)),
+                Geometry::LineString(line_string![(x: 4.0, y: -2.0), (x: 4.0, y: 4.0)]),
+            ]))
+        );
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.scale(2.0), empty);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::bool_ops::BooleanOps;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::{GeoFloat, MultiPolygon, Rect};
+
+/// Find the polygons of a `MultiPolygon` which overlap each other.
+///
+/// The polygons of a valid `MultiPolygon` may touch, along edges or at
+/// points, but their interiors may not overlap, so this is a check on a
+/// polygon coverage before, for example, adding up the areas of its
+/// polygons. Two polygons overlap if their [intersection](BooleanOps) has
+/// an area, including where one lies within the other.
+///
+/// The overlapping pairs are returned as indices of the polygons, each in
+/// ascending order, sorted. Only pairs whose bounding rectangles
+/// intersect are checked, so this is quick for polygons which are mostly
+/// apart. Each polygon is expected to be valid itself, as for
+/// [`BooleanOps`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::self_overlaps::SelfOverlaps;
+/// use geo::{polygon, MultiPolygon};
+///
+/// let multi_polygon = MultiPolygon(vec![
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+///     // Sharing an edge with the first
+///     polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)],
+///     // Overlapping the second
+///     polygon![(x: 3., y: 1.), (x: 5., y: 1.), (x: 5., y: 3.), (x: 3., y: 3.)],
+/// ]);
+///
+/// assert_eq!(multi_polygon.self_overlaps(), vec![(1, 2)]);
+/// ```
+pub trait SelfOverlaps {
+    fn self_overlaps(&self) -> Vec<(usize, usize)>;
+}
+
+impl<T: GeoFloat> SelfOverlaps for MultiPolygon<T> {
+    fn self_overlaps(&self) -> Vec<(usize, usize)> {
+        let mut rects: Vec<(usize, Rect<T>)> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, polygon)| Some((index, polygon.bounding_rect()?)))
+            .collect();
+        rects.sort_by(|(_, a), (_, b)| a.min().x.partial_cmp(&b.min().x).unwrap());
+
+        let mut overlaps = vec![];
+        // Sweep across in `x`, so each rect is only compared with those
+        // which start before it ends
+        for (i, &(a, a_rect)) in rects.iter().enumerate() {
+            for &(b, b_rect) in rects[i + 1..]
+                .iter()
+                .take_while(|(_, b_rect)| b_rect.min().x <= a_rect.max().x)
+            {
+                if b_rect.min().y > a_rect.max().y || b_rect.max().y < a_rect.min().y {
+                    continue;
+                }
+                let intersection = self.0[a].intersection(&self.0[b]);
+                if intersection.unsigned_area() > T::zero() {
+                    overlaps.push((a.min(b), a.max(b)));
+                }
+            }
+        }
+        overlaps.sort_unstable();
+        overlaps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Polygon};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    #[test]
+    fn overlapping() {
+        let multi_polygon = MultiPolygon(vec![square(0., 0., 2.), square(1., 1., 2.)]);
+        assert_eq!(multi_polygon.self_overlaps(), vec![(0, 1)]);
+
+        // One inside another, and the same polygon twice
+        let multi_polygon = MultiPolygon(vec![
+            square(10., 10., 1.),
+            square(0., 0., 4.),
+            square(1., 1., 1.),
+            square(10., 10., 1.),
+        ]);
+        assert_eq!(multi_polygon.self_overlaps(), vec![(0, 3), (1, 2)]);
+    }
+
+    #[test]
+    fn touching() {
+        // Sharing an edge, a part of an edge, and a corner
+        let multi_polygon = MultiPolygon(vec![
+            square(0., 0., 2.),
+            square(2., 0., 2.),
+            square(-1., 2., 2.),
+            square(4., 2., 1.),
+        ]);
+        assert_eq!(multi_polygon.self_overlaps(), vec![]);
+
+        // In the hole of another
+        let donut = Polygon::new(
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+            vec![
+                line_string![(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.), (x: 2., y: 2.)],
+            ],
+        );
+        let multi_polygon = MultiPolygon(vec![donut, square(2., 2., 6.), square(5., 5., 1.)]);
+        assert_eq!(multi_polygon.self_overlaps(), vec![(1, 2)]);
+    }
+
+    #[test]
+    fn overlapping_bounding_rects() {
+        // Two triangles whose bounding rectangles overlap, but which don't
+        let multi_polygon = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)],
+            polygon![(x: 4., y: 4.), (x: 1., y: 4.), (x: 4., y: 1.)],
+        ]);
+        assert_eq!(multi_polygon.self_overlaps(), vec![]);
+
+        let empty: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert_eq!(empty.self_overlaps(), vec![]);
+    }
+}
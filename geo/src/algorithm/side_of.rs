@@ -0,0 +1,107 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::{GeoNum, Line, Point};
+
+/// The side of a directed `Line` which a `Point` lies on, as found by
+/// [`SideOf`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// To the left, looking from the line's start towards its end
+    Left,
+    /// To the right, looking from the line's start towards its end
+    Right,
+    /// On the infinite line through the line's ends
+    On,
+}
+
+/// Determine which side of a directed `Line` a `Point` lies on.
+///
+/// The side is the sign of the cross product of the line's direction and
+/// the vector from its start to the point, so it is relative to the
+/// infinite line through the line's ends, rather than the segment between
+/// them. Every point is `On` a line whose ends are the same.
+///
+/// This is the orientation predicate used by the clipping, convex hull and
+/// winding order algorithms. For `f32` and `f64`, it uses the crate's robust
+/// predicates, so the sign is exact, without any tolerance: a point which
+/// is only very nearly on the line is on one side of it or the other, and
+/// the answer is consistent for the same three coordinates. Integer
+/// coordinates are computed exactly, provided the products don't overflow.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::side_of::{Side, SideOf};
+/// use geo::{point, Coordinate, Line};
+///
+/// let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 2., y: 0. });
+///
+/// assert_eq!(line.side_of(point!(x: 1., y: 1.)), Side::Left);
+/// assert_eq!(line.side_of(point!(x: 1., y: -1.)), Side::Right);
+/// // Beyond the end, but still on the line through it
+/// assert_eq!(line.side_of(point!(x: 5., y: 0.)), Side::On);
+/// ```
+pub trait SideOf<T: GeoNum> {
+    fn side_of(&self, p: Point<T>) -> Side;
+}
+
+impl<T: GeoNum> SideOf<T> for Line<T> {
+    fn side_of(&self, p: Point<T>) -> Side {
+        match T::Ker::orient2d(self.start, self.end, p.0) {
+            Orientation::CounterClockwise => Side::Left,
+            Orientation::Clockwise => Side::Right,
+            Orientation::Collinear => Side::On,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Coordinate};
+
+    #[test]
+    fn sides() {
+        let line = Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 3., y: 5. });
+        assert_eq!(line.side_of(point!(x: 0., y: 4.)), Side::Left);
+        assert_eq!(line.side_of(point!(x: 3., y: 1.)), Side::Right);
+        // Reversing the line swaps the sides
+        let reversed = Line::new(line.end, line.start);
+        assert_eq!(reversed.side_of(point!(x: 0., y: 4.)), Side::Right);
+        assert_eq!(reversed.side_of(point!(x: 3., y: 1.)), Side::Left);
+
+        let line = Line::new(Coordinate { x: 0, y: 0 }, Coordinate { x: 0, y: -10 });
+        assert_eq!(line.side_of(point!(x: 1, y: -5)), Side::Left);
+        assert_eq!(line.side_of(point!(x: -1, y: 20)), Side::Right);
+    }
+
+    #[test]
+    fn collinear() {
+        let line = Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 3., y: 5. });
+        assert_eq!(line.side_of(point!(x: 2., y: 3.)), Side::On);
+        assert_eq!(line.side_of(point!(x: 1., y: 1.)), Side::On);
+        assert_eq!(line.side_of(point!(x: 3., y: 5.)), Side::On);
+        // Outside the segment
+        assert_eq!(line.side_of(point!(x: -1., y: -3.)), Side::On);
+        assert_eq!(line.side_of(point!(x: 10., y: 19.)), Side::On);
+
+        let point = Line::new(Coordinate { x: 1., y: 1. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(point.side_of(point!(x: 5., y: 2.)), Side::On);
+    }
+
+    #[test]
+    fn exact() {
+        // A point a tiny distance off a long line, where the naive cross
+        // product is dominated by rounding
+        let line = Line::new(
+            Coordinate { x: 0.1, y: 0.1 },
+            Coordinate {
+                x: 1e10 + 0.1,
+                y: 1e10 + 0.1,
+            },
+        );
+        let above = point!(x: 0.5, y: 0.5 + 1e-15);
+        let below = point!(x: 0.5, y: 0.5 - 1e-15);
+        assert_eq!(line.side_of(above), Side::Left);
+        assert_eq!(line.side_of(below), Side::Right);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::algorithm::contains::Contains;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{GeoFloat, MultiPolygon, Point, Polygon};
+
+/// Calculate the signed distance from a `Point` to the boundary of a
+/// geometry: negative inside the geometry and positive outside it.
+///
+/// The magnitude is the (Euclidean) distance to the nearest edge of any
+/// ring, exterior or interior, so a point in a hole is outside, with the
+/// distance to the edge of the hole. The sign comes from containment, and a
+/// point on the boundary is at a distance of zero. Evaluated over a grid,
+/// this gives a signed distance field for the geometry.
+///
+/// An empty geometry has no inside, so every point is an infinite distance
+/// outside it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::signed_distance::SignedDistance;
+/// use geo::{point, polygon};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+///
+/// assert_eq!(square.signed_distance(point!(x: 1., y: 2.)), -1.);
+/// assert_eq!(square.signed_distance(point!(x: 4., y: 2.)), 0.);
+/// assert_eq!(square.signed_distance(point!(x: 7., y: 0.)), 3.);
+/// ```
+pub trait SignedDistance<T: GeoFloat> {
+    fn signed_distance(&self, p: Point<T>) -> T;
+}
+
+impl<T: GeoFloat> SignedDistance<T> for Polygon<T> {
+    fn signed_distance(&self, p: Point<T>) -> T {
+        let distance = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .filter(|ring| !ring.0.is_empty())
+            .map(|ring| p.euclidean_distance(ring))
+            .fold(T::infinity(), |accum, val| accum.min(val));
+        if self.contains(&p) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+impl<T: GeoFloat> SignedDistance<T> for MultiPolygon<T> {
+    fn signed_distance(&self, p: Point<T>) -> T {
+        let distance = self
+            .iter()
+            .map(|polygon| polygon.signed_distance(p).abs())
+            .fold(T::infinity(), |accum, val| accum.min(val));
+        if self.contains(&p) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn polygon() -> Polygon<f64> {
+        // A square with a square hole, off-centre
+        Polygon::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            vec![line_string![
+                (x: 6., y: 6.),
+                (x: 8., y: 6.),
+                (x: 8., y: 8.),
+                (x: 6., y: 8.),
+                (x: 6., y: 6.),
+            ]],
+        )
+    }
+
+    #[test]
+    fn inside() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        // The centre is an inradius inside
+        assert_eq!(square.signed_distance(point!(x: 5., y: 5.)), -5.);
+        // Nearer the hole than the exterior
+        assert_relative_eq!(
+            polygon().signed_distance(point!(x: 5., y: 5.)),
+            -(2f64.sqrt())
+        );
+        assert_eq!(polygon().signed_distance(point!(x: 2., y: 3.)), -2.);
+    }
+
+    #[test]
+    fn boundary() {
+        let polygon = polygon();
+        assert_eq!(polygon.signed_distance(point!(x: 10., y: 3.)), 0.);
+        assert_eq!(polygon.signed_distance(point!(x: 0., y: 0.)), 0.);
+        assert_eq!(polygon.signed_distance(point!(x: 7., y: 6.)), 0.);
+    }
+
+    #[test]
+    fn outside() {
+        let polygon = polygon();
+        assert_eq!(polygon.signed_distance(point!(x: 13., y: 14.)), 5.);
+        assert_eq!(polygon.signed_distance(point!(x: 5., y: -1.)), 1.);
+        // In the hole
+        assert_eq!(polygon.signed_distance(point!(x: 7., y: 7.5)), 0.5);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon(vec![
+            polygon(),
+            polygon![
+                (x: 20., y: 0.),
+                (x: 30., y: 0.),
+                (x: 30., y: 10.),
+                (x: 20., y: 10.),
+            ],
+        ]);
+        assert_eq!(multi_polygon.signed_distance(point!(x: 16., y: 5.)), 4.);
+        assert_eq!(multi_polygon.signed_distance(point!(x: 22., y: 5.)), -2.);
+        assert_eq!(multi_polygon.signed_distance(point!(x: 2., y: 3.)), -2.);
+        assert_eq!(multi_polygon.signed_distance(point!(x: 7., y: 7.5)), 0.5);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(empty.signed_distance(point!(x: 1., y: 1.)), f64::INFINITY);
+        let empty: MultiPolygon<f64> = MultiPolygon(vec![]);
+        assert_eq!(empty.signed_distance(point!(x: 1., y: 1.)), f64::INFINITY);
+    }
+}
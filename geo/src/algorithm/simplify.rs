@@ -1,6 +1,14 @@
+use crate::algorithm::area::get_linestring_area;
+use crate::algorithm::centroid::Centroid;
 use crate::algorithm::coords_iter::CoordsIter;
 use crate::algorithm::euclidean_distance::EuclideanDistance;
-use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPolygon, OrderedCoord, Polygon,
+};
+use num_traits::FromPrimitive;
+use std::collections::{HashMap, HashSet};
+use std::iter::Sum;
 
 // Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
 // instead, we wrap a simple struct around index and point in a wrapper function,
@@ -243,10 +251,442 @@ where
     }
 }
 
+/// Simplifies a collection of polygons which share boundaries, such as a coverage of adjacent
+/// regions, preserving the shared boundaries.
+///
+/// Simplifying each polygon independently generally moves a shared boundary differently on
+/// either side, resulting in gaps and overlaps. Instead, the rings are first split into _chains_
+/// at the vertices where more than two boundaries meet. Each distinct chain is then simplified
+/// once, using the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), and every ring
+/// using it (in either direction) receives the same result. The vertices where the chains meet
+/// are always retained.
+///
+/// Boundaries are only recognised as shared if they have exactly the same vertices. As with
+/// [`Simplify`], the result may be invalid if `epsilon` is large relative to the features.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyShared<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, simplifying shared boundaries
+    /// identically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::simplify::SimplifyShared;
+    /// use geo::{polygon, MultiPolygon};
+    ///
+    /// // Two polygons sharing a border from (5, 0) to (5, 10)
+    /// let coverage = MultiPolygon(vec![
+    ///     polygon![
+    ///         (x: 0., y: 0.),
+    ///         (x: 5., y: 0.),
+    ///         (x: 5.1, y: 5.),
+    ///         (x: 5., y: 10.),
+    ///         (x: 0., y: 10.),
+    ///     ],
+    ///     polygon![
+    ///         (x: 5., y: 0.),
+    ///         (x: 10., y: 0.),
+    ///         (x: 10., y: 10.),
+    ///         (x: 5., y: 10.),
+    ///         (x: 5.1, y: 5.),
+    ///     ],
+    /// ]);
+    ///
+    /// let simplified = coverage.simplify_shared(&1.0);
+    ///
+    /// let expected = MultiPolygon(vec![
+    ///     polygon![
+    ///         (x: 0., y: 0.),
+    ///         (x: 5., y: 0.),
+    ///         (x: 5., y: 10.),
+    ///         (x: 0., y: 10.),
+    ///     ],
+    ///     polygon![
+    ///         (x: 5., y: 0.),
+    ///         (x: 10., y: 0.),
+    ///         (x: 10., y: 10.),
+    ///         (x: 5., y: 10.),
+    ///     ],
+    /// ]);
+    ///
+    /// assert_eq!(expected, simplified);
+    /// ```
+    fn simplify_shared(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyShared<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_shared(&self, epsilon: &T) -> Self {
+        if *epsilon <= T::zero() {
+            return self.clone();
+        }
+        let ring_coords = |ring: &LineString<T>| {
+            let mut coords = ring.0.clone();
+            coords.dedup();
+            if coords.len() > 1 && coords.first() == coords.last() {
+                coords.pop();
+            }
+            coords
+        };
+        let rings: Vec<Vec<Vec<Coordinate<T>>>> = self
+            .iter()
+            .map(|polygon| {
+                std::iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .map(ring_coords)
+                    .collect()
+            })
+            .collect();
+
+        // The distinct vertices adjacent to each vertex
+        let mut neighbours: HashMap<OrderedCoord<T>, HashSet<OrderedCoord<T>>> = HashMap::new();
+        for ring in rings.iter().flatten() {
+            for (idx, &coord) in ring.iter().enumerate() {
+                let next = ring[(idx + 1) % ring.len()];
+                if coord != next {
+                    neighbours
+                        .entry(coord.into())
+                        .or_default()
+                        .insert(next.into());
+                    neighbours
+                        .entry(next.into())
+                        .or_default()
+                        .insert(coord.into());
+                }
+            }
+        }
+        let is_node = |coord: Coordinate<T>| neighbours[&coord.into()].len() != 2;
+
+        let mut simplified_chains: HashMap<Vec<OrderedCoord<T>>, Vec<Coordinate<T>>> =
+            HashMap::new();
+        let mut simplify_ring = |ring: &[Coordinate<T>]| {
+            if ring.len() < 2 {
+                return LineString(ring.to_vec());
+            }
+            // Start from a node, or from the least vertex of a ring which doesn't touch any
+            // other, so that rings sharing all their vertices are split identically
+            let start = (0..ring.len())
+                .find(|&idx| is_node(ring[idx]))
+                .unwrap_or_else(|| {
+                    (0..ring.len())
+                        .min_by_key(|&idx| OrderedCoord(ring[idx]))
+                        .unwrap()
+                });
+            let mut closed = ring[start..].to_vec();
+            closed.extend_from_slice(&ring[..=start]);
+
+            let mut simplified = vec![closed[0]];
+            let mut chain_start = 0;
+            for idx in 1..closed.len() {
+                if idx != closed.len() - 1 && !is_node(closed[idx]) {
+                    continue;
+                }
+                let chain = &closed[chain_start..=idx];
+                let forward: Vec<OrderedCoord<T>> =
+                    chain.iter().map(|&coord| coord.into()).collect();
+                let mut backward = forward.clone();
+                backward.reverse();
+
+                let simplified_chain = if forward <= backward {
+                    simplified_chains
+                        .entry(forward)
+                        .or_insert_with(|| rdp(chain.iter().copied(), epsilon))
+                        .clone()
+                } else {
+                    let mut reversed = simplified_chains
+                        .entry(backward)
+                        .or_insert_with(|| rdp(chain.iter().rev().copied(), epsilon))
+                        .clone();
+                    reversed.reverse();
+                    reversed
+                };
+                simplified.extend_from_slice(&simplified_chain[1..]);
+                chain_start = idx;
+            }
+
+            // Start from the same vertex as the input, if it was retained
+            simplified.pop();
+            if let Some(idx) = simplified.iter().position(|&coord| coord == ring[0]) {
+                simplified.rotate_left(idx);
+            }
+            simplified.push(simplified[0]);
+            LineString(simplified)
+        };
+
+        MultiPolygon(
+            rings
+                .iter()
+                .map(|rings| {
+                    let mut rings = rings.iter().map(|ring| simplify_ring(ring));
+                    let exterior = rings.next().unwrap();
+                    Polygon::new(exterior, rings.collect())
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Simplifies the members of a `GeometryCollection`, such as a layer of a road network, without
+/// disconnecting them where they meet.
+///
+/// Simplifying each member independently can remove a vertex where another member joins it, so
+/// that the two no longer touch. Instead, every vertex which appears in more than one member is
+/// treated as a _junction_, and always retained. The lines and rings of each member are split at
+/// the junctions, and each part is simplified separately using the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm).
+///
+/// Vertices are only recognised as shared if they are exactly equal. A `Point` member on a vertex
+/// of a line makes that vertex a junction too. Members of a nested `GeometryCollection` are
+/// simplified in the same way, with their vertices counted as part of the top-level member
+/// containing them. `Point`, `Line`, `Rect` and `Triangle` members are returned unaltered.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyPreservingJunctions<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, retaining the vertices shared by its
+    /// members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::simplify::SimplifyPreservingJunctions;
+    /// use geo::{line_string, Geometry, GeometryCollection};
+    ///
+    /// // A side road joining a main road at (5, 0.1)
+    /// let roads = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![
+    ///         (x: 0., y: 0.),
+    ///         (x: 5., y: 0.1),
+    ///         (x: 10., y: 0.),
+    ///     ]),
+    ///     Geometry::LineString(line_string![
+    ///         (x: 5., y: 0.1),
+    ///         (x: 5.1, y: 5.),
+    ///         (x: 5., y: 10.),
+    ///     ]),
+    /// ]);
+    ///
+    /// let simplified = roads.simplify_preserving_junctions(&1.0);
+    ///
+    /// let expected = GeometryCollection(vec![
+    ///     Geometry::LineString(line_string![
+    ///         (x: 0., y: 0.),
+    ///         (x: 5., y: 0.1),
+    ///         (x: 10., y: 0.),
+    ///     ]),
+    ///     Geometry::LineString(line_string![(x: 5., y: 0.1), (x: 5., y: 10.)]),
+    /// ]);
+    ///
+    /// assert_eq!(expected, simplified);
+    /// ```
+    fn simplify_preserving_junctions(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyPreservingJunctions<T> for GeometryCollection<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_preserving_junctions(&self, epsilon: &T) -> Self {
+        // The number of members each vertex appears in
+        let mut member_counts: HashMap<OrderedCoord<T>, usize> = HashMap::new();
+        for member in self.iter() {
+            let vertices: HashSet<OrderedCoord<T>> =
+                member.coords_iter().map(OrderedCoord).collect();
+            for vertex in vertices {
+                *member_counts.entry(vertex).or_default() += 1;
+            }
+        }
+        let junctions: HashSet<OrderedCoord<T>> = member_counts
+            .into_iter()
+            .filter(|&(_, count)| count > 1)
+            .map(|(vertex, _)| vertex)
+            .collect();
+
+        GeometryCollection(
+            self.iter()
+                .map(|member| simplify_around(member, epsilon, &junctions))
+                .collect(),
+        )
+    }
+}
+
+fn simplify_around<T>(
+    geometry: &Geometry<T>,
+    epsilon: &T,
+    junctions: &HashSet<OrderedCoord<T>>,
+) -> Geometry<T>
+where
+    T: GeoFloat,
+{
+    let line_string =
+        |line_string: &LineString<T>| LineString(rdp_around(&line_string.0, epsilon, junctions));
+    let polygon = |polygon: &Polygon<T>| {
+        Polygon::new(
+            line_string(polygon.exterior()),
+            polygon.interiors().iter().map(line_string).collect(),
+        )
+    };
+    match geometry {
+        Geometry::LineString(ls) => Geometry::LineString(line_string(ls)),
+        Geometry::MultiLineString(mls) => {
+            Geometry::MultiLineString(MultiLineString(mls.iter().map(line_string).collect()))
+        }
+        Geometry::Polygon(p) => Geometry::Polygon(polygon(p)),
+        Geometry::MultiPolygon(mp) => {
+            Geometry::MultiPolygon(MultiPolygon(mp.iter().map(polygon).collect()))
+        }
+        Geometry::GeometryCollection(gc) => Geometry::GeometryCollection(GeometryCollection(
+            gc.iter()
+                .map(|member| simplify_around(member, epsilon, junctions))
+                .collect(),
+        )),
+        _ => geometry.clone(),
+    }
+}
+
+// Simplify the parts of a line between the junctions on it, retaining the junctions
+fn rdp_around<T>(
+    coords: &[Coordinate<T>],
+    epsilon: &T,
+    junctions: &HashSet<OrderedCoord<T>>,
+) -> Vec<Coordinate<T>>
+where
+    T: GeoFloat,
+{
+    if coords.len() < 3 {
+        return coords.to_vec();
+    }
+    let mut simplified = vec![coords[0]];
+    let mut part_start = 0;
+    for idx in 1..coords.len() {
+        if idx == coords.len() - 1 || junctions.contains(&OrderedCoord(coords[idx])) {
+            let part = rdp(coords[part_start..=idx].iter().copied(), epsilon);
+            simplified.extend_from_slice(&part[1..]);
+            part_start = idx;
+        }
+    }
+    simplified
+}
+
+/// Simplifies a polygon while preserving its area.
+///
+/// The [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) only retains
+/// vertices, so it tends to cut across the convex parts of a ring, shrinking it. Here, each ring
+/// is simplified with it, as for [`Simplify`], and is then scaled about its centroid, by the
+/// same factor in every direction, so that it encloses the same area as the original ring. The
+/// shape of the simplified ring is unchanged by this, and the area of the result matches the
+/// area of the input, up to floating point rounding.
+///
+/// The scaling moves the vertices, typically outwards, so unlike [`Simplify`], the result may
+/// differ from the input by more than `epsilon`. A ring which is simplified to nothing enclosing
+/// any area is left unsimplified. As with [`Simplify`], the result may be invalid if `epsilon`
+/// is large relative to the features, and interiors may cross the exterior.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyPreserveArea<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, with the same area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::simplify::{Simplify, SimplifyPreserveArea};
+    /// use geo::polygon;
+    ///
+    /// // A square, with its edges bowed out a little
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 5., y: -0.5),
+    ///     (x: 10., y: 0.),
+    ///     (x: 10.5, y: 5.),
+    ///     (x: 10., y: 10.),
+    ///     (x: 5., y: 10.5),
+    ///     (x: 0., y: 10.),
+    ///     (x: -0.5, y: 5.),
+    /// ];
+    /// assert_eq!(polygon.unsigned_area(), 110.);
+    ///
+    /// // Simplified to the square inside it
+    /// assert_eq!(polygon.simplify(&1.0).unsigned_area(), 100.);
+    ///
+    /// let simplified = polygon.simplify_preserve_area(&1.0);
+    /// assert_eq!(simplified.exterior().0.len(), 5);
+    /// assert_relative_eq!(simplified.unsigned_area(), 110., epsilon = 1e-9);
+    /// ```
+    fn simplify_preserve_area(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyPreserveArea<T> for Polygon<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    fn simplify_preserve_area(&self, epsilon: &T) -> Self {
+        let ring = |ring: &LineString<T>| {
+            if *epsilon <= T::zero() {
+                return ring.clone();
+            }
+            rescale_ring(ring, &ring.simplify(epsilon))
+        };
+        Polygon::new(
+            ring(self.exterior()),
+            self.interiors().iter().map(ring).collect(),
+        )
+    }
+}
+
+impl<T> SimplifyPreserveArea<T> for MultiPolygon<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    fn simplify_preserve_area(&self, epsilon: &T) -> Self {
+        MultiPolygon(
+            self.iter()
+                .map(|p| p.simplify_preserve_area(epsilon))
+                .collect(),
+        )
+    }
+}
+
+// Scale a simplified ring about its centroid so that it encloses the area of the original
+fn rescale_ring<T>(original: &LineString<T>, simplified: &LineString<T>) -> LineString<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    let original_area = get_linestring_area(original).abs();
+    let simplified_area = get_linestring_area(simplified).abs();
+    if simplified_area == T::zero() {
+        return original.clone();
+    }
+    let factor = (original_area / simplified_area).sqrt();
+    // Scaling by one may still move coordinates, by rounding
+    if factor == T::one() {
+        return simplified.clone();
+    }
+    let center = match Polygon::new(simplified.clone(), vec![]).centroid() {
+        Some(center) => center.0,
+        None => return original.clone(),
+    };
+    simplified
+        .coords_iter()
+        .map(|coord| center + (coord - center) * factor)
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{line_string, polygon};
+    use crate::{line_string, polygon, Point};
 
     #[test]
     fn rdp_test() {
@@ -360,6 +800,100 @@ mod test {
         );
     }
 
+    #[test]
+    fn shared_border() {
+        // The border from (5, 0) to (5, 10) zigzags, and is shared by both polygons
+        let border = [
+            Coordinate { x: 5., y: 0. },
+            Coordinate { x: 5.1, y: 2. },
+            Coordinate { x: 4.9, y: 4. },
+            Coordinate { x: 5.1, y: 6. },
+            Coordinate { x: 4.9, y: 8. },
+            Coordinate { x: 5., y: 10. },
+        ];
+        // Starting the left polygon within the border means that `simplify` retains that vertex
+        let mut left = border[2..].to_vec();
+        left.extend(vec![
+            Coordinate { x: 0., y: 10. },
+            Coordinate { x: 0., y: 0. },
+        ]);
+        left.extend_from_slice(&border[..2]);
+        let mut right: Vec<_> = border.iter().rev().copied().collect();
+        right.extend(vec![
+            Coordinate { x: 10., y: 0. },
+            Coordinate { x: 10., y: 10. },
+        ]);
+        let coverage = MultiPolygon(vec![
+            Polygon::new(left.into(), vec![]),
+            Polygon::new(right.into(), vec![]),
+        ]);
+
+        let simplified = coverage.simplify_shared(&0.2);
+        let simplified_border = |polygon: &Polygon<f64>| {
+            let mut coords: Vec<_> = polygon
+                .exterior()
+                .0
+                .iter()
+                .filter(|coord| coord.x > 1. && coord.x < 9.)
+                .map(|&coord| OrderedCoord(coord))
+                .collect();
+            coords.sort();
+            coords.dedup();
+            coords
+        };
+        let left_border = simplified_border(&simplified.0[0]);
+        assert_eq!(left_border, simplified_border(&simplified.0[1]));
+        assert_eq!(
+            left_border,
+            vec![
+                OrderedCoord(Coordinate { x: 5., y: 0. }),
+                OrderedCoord(Coordinate { x: 5., y: 10. }),
+            ]
+        );
+
+        // Simplifying each polygon independently moves the border differently
+        let independent = coverage.simplify(&0.2);
+        assert_ne!(
+            simplified_border(&independent.0[0]),
+            simplified_border(&independent.0[1])
+        );
+    }
+
+    #[test]
+    fn shared_hole() {
+        // An island filling the hole of a polygon
+        let island = polygon![
+            (x: 2., y: 2.),
+            (x: 5., y: 2.1),
+            (x: 8., y: 2.),
+            (x: 8., y: 8.),
+            (x: 2., y: 8.),
+        ];
+        let mut hole = island.exterior().clone();
+        hole.0.reverse();
+        let coverage = MultiPolygon(vec![
+            Polygon::new(
+                line_string![
+                    (x: 0., y: 0.),
+                    (x: 10., y: 0.),
+                    (x: 10., y: 10.),
+                    (x: 0., y: 10.),
+                ],
+                vec![hole],
+            ),
+            island,
+        ]);
+
+        let simplified = coverage.simplify_shared(&0.5);
+        let mut hole = simplified.0[0].interiors()[0].clone();
+        hole.0.reverse();
+        assert_eq!(hole.0.len(), 5);
+        assert_eq!(&hole, simplified.0[1].exterior());
+
+        assert_eq!(coverage.simplify_shared(&0.), coverage);
+        assert_eq!(coverage.simplify_shared(&-1.), coverage);
+    }
+
     #[test]
     fn simplify_negative_epsilon() {
         let ls = line_string![
@@ -385,4 +919,169 @@ mod test {
         let indices = ls.simplify_idx(&-1.0);
         assert_eq!(vec![0usize, 1, 2, 3, 4], indices);
     }
+
+    #[test]
+    fn shared_node() {
+        // Two roads meeting at a node in the middle of each, and a third ending there
+        let node = Coordinate { x: 5., y: 0.1 };
+        let roads = GeometryCollection(vec![
+            Geometry::LineString(line_string![
+                (x: 0., y: 0.),
+                (x: 2.5, y: 0.2),
+                (x: 5., y: 0.1),
+                (x: 7.5, y: 0.2),
+                (x: 10., y: 0.),
+            ]),
+            Geometry::LineString(line_string![
+                (x: 5., y: -10.),
+                (x: 5.2, y: -5.),
+                (x: 5., y: 0.1),
+                (x: 5.2, y: 5.),
+                (x: 5., y: 10.),
+            ]),
+            Geometry::MultiLineString(MultiLineString(vec![line_string![
+                (x: 10., y: 10.),
+                (x: 7.6, y: 5.),
+                (x: 5., y: 0.1),
+            ]])),
+        ]);
+
+        let simplified = roads.simplify_preserving_junctions(&1.0);
+        let lines: Vec<LineString<f64>> = simplified
+            .iter()
+            .flat_map(|geometry| match geometry {
+                Geometry::LineString(ls) => vec![ls.clone()],
+                Geometry::MultiLineString(mls) => mls.0.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)],
+                line_string![(x: 5., y: -10.), (x: 5., y: 0.1), (x: 5., y: 10.)],
+                line_string![(x: 10., y: 10.), (x: 5., y: 0.1)],
+            ]
+        );
+        for line in &lines {
+            assert!(line.0.contains(&node));
+        }
+
+        // Simplifying each road independently disconnects them
+        let independent: Vec<LineString<f64>> = roads
+            .iter()
+            .take(2)
+            .map(|geometry| match geometry {
+                Geometry::LineString(ls) => ls.simplify(&1.0),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert!(independent.iter().all(|line| !line.0.contains(&node)));
+    }
+
+    #[test]
+    fn junctions_on_rings() {
+        // A point on a vertex of a polygon's exterior, and a polygon nested in a collection
+        // sharing another vertex
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.1),
+            (x: 10., y: 0.),
+            (x: 10.1, y: 5.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(polygon.clone()),
+            Geometry::Point(Point::new(5., 0.1)),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Line(Line::new(
+                (10.1, 5.),
+                (20., 5.),
+            ))])),
+        ]);
+        let simplified = collection.simplify_preserving_junctions(&1.0);
+        assert_eq!(simplified.0[0], Geometry::Polygon(polygon.clone()));
+        assert_eq!(&simplified.0[1..], &collection.0[1..]);
+
+        // Without them, both vertices are removed
+        let alone = GeometryCollection(vec![Geometry::Polygon(polygon.clone())]);
+        assert_eq!(
+            alone.simplify_preserving_junctions(&1.0).0[0],
+            Geometry::Polygon(polygon.simplify(&1.0))
+        );
+        assert_eq!(polygon.simplify(&1.0).exterior().0.len(), 5);
+    }
+
+    #[test]
+    fn simplify_preserve_area() {
+        use crate::algorithm::area::Area;
+
+        // A wobbly circle of radius 10, with 72 vertices
+        let exterior: LineString<f64> = (0..72)
+            .map(|i| {
+                let angle = (i as f64 * 5.).to_radians();
+                let radius = 10. + 0.3 * (angle * 7.).sin();
+                (radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+        let hole = line_string![
+            (x: -2., y: -2.),
+            (x: -2., y: 0.),
+            (x: -1.9, y: 1.),
+            (x: -2., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: -2.),
+        ];
+        let polygon = Polygon::new(exterior, vec![hole]);
+        let area = polygon.unsigned_area();
+
+        let simplified = polygon.simplify_preserve_area(&0.5);
+        assert!(simplified.exterior().0.len() < 20);
+        assert_eq!(simplified.interiors()[0].0.len(), 5);
+        assert!((simplified.unsigned_area() - area).abs() < area * 0.01);
+        assert_relative_eq!(simplified.unsigned_area(), area, max_relative = 1e-9);
+        // Plain simplification loses much more
+        assert!((polygon.simplify(&0.5).unsigned_area() - area).abs() > area * 0.01);
+
+        let multi = MultiPolygon(vec![polygon.clone(), polygon.clone()]);
+        assert_relative_eq!(
+            multi.simplify_preserve_area(&0.5).unsigned_area(),
+            2. * area,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn simplify_preserve_area_degenerate() {
+        // Simplified to a line, so left alone
+        let thin = polygon![(x: 0., y: 0.), (x: 10., y: 0.1), (x: 20., y: 0.)];
+        assert_eq!(thin.simplify_preserve_area(&1.0), thin);
+
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(empty.simplify_preserve_area(&1.0), empty);
+
+        // No simplification
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert_eq!(square.simplify_preserve_area(&0.), square);
+    }
+
+    #[test]
+    fn simplify_preserve_area_unchanged() {
+        // Coordinates which rescaling about the centroid would round
+        let polygon = polygon![
+            exterior: [
+                (x: 0.1, y: 0.3),
+                (x: 10.7, y: 0.2),
+                (x: 11.3, y: 9.9),
+                (x: 0.3, y: 10.1),
+            ],
+            interiors: [
+                [(x: 2.1, y: 2.3), (x: 2.2, y: 7.7), (x: 7.9, y: 7.1), (x: 7.3, y: 2.9)],
+            ],
+        ];
+        assert_eq!(polygon.simplify_preserve_area(&0.), polygon);
+        assert_eq!(polygon.simplify_preserve_area(&-1.), polygon);
+        // No vertices are removed, so the scale factor is one
+        assert_eq!(polygon.simplify_preserve_area(&0.01), polygon);
+    }
 }
@@ -0,0 +1,222 @@
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::intersects::Intersects;
+use crate::{GeoNum, Geometry, Point};
+
+/// The relation which pairs of geometries must satisfy to be joined by
+/// [`spatial_join`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Predicate {
+    /// The geometries share at least one point, as by [`Intersects`]
+    Intersects,
+}
+
+impl Predicate {
+    fn evaluate<T>(self, lhs: &Geometry<T>, rhs: &Geometry<T>) -> bool
+    where
+        T: GeoNum,
+    {
+        match self {
+            Predicate::Intersects => lhs.intersects(rhs),
+        }
+    }
+}
+
+/// The bounding rect of a geometry, and its index, to be stored in an `RTree`.
+struct Envelope<T>
+where
+    T: GeoNum + RTreeNum,
+{
+    aabb: AABB<Point<T>>,
+    index: usize,
+}
+
+impl<T> RTreeObject for Envelope<T>
+where
+    T: GeoNum + RTreeNum,
+{
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.aabb
+    }
+}
+
+/// Find every pair of geometries, one from `lhs` and one from `rhs`, which
+/// satisfy `predicate`.
+///
+/// Rather than testing every pair, the bounding rects of `rhs` are loaded
+/// into an [R* tree](../../../rstar/struct.RTree.html), and the predicate
+/// is only evaluated for the pairs whose bounding rects intersect, which is
+/// necessary for the geometries to. The result is the same as testing every
+/// pair: the indices `(i, j)` of each pair for which
+/// `predicate` holds of `lhs[i]` and `rhs[j]`, in increasing order. Empty
+/// geometries, which have no bounding rect, are never paired.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::spatial_join::{spatial_join, Predicate};
+/// use geo::{line_string, point, Geometry, Rect};
+///
+/// let lhs: Vec<Geometry<f64>> = vec![
+///     Rect::new((0., 0.), (2., 2.)).into(),
+///     Rect::new((10., 10.), (12., 12.)).into(),
+/// ];
+/// let rhs: Vec<Geometry<f64>> = vec![
+///     point!(x: 11., y: 11.).into(),
+///     line_string![(x: 1.5, y: 3.), (x: 3., y: 1.5)].into(),
+///     line_string![(x: 1., y: 1.), (x: 11., y: 11.)].into(),
+/// ];
+///
+/// // The second line's bounding rect overlaps the first rect, but the line doesn't
+/// assert_eq!(
+///     spatial_join(&lhs, &rhs, Predicate::Intersects),
+///     vec![(0, 2), (1, 0), (1, 2)]
+/// );
+/// ```
+pub fn spatial_join<T>(
+    lhs: &[Geometry<T>],
+    rhs: &[Geometry<T>],
+    predicate: Predicate,
+) -> Vec<(usize, usize)>
+where
+    T: GeoNum + RTreeNum,
+{
+    let aabb = |geometry: &Geometry<T>| {
+        geometry
+            .bounding_rect()
+            .map(|rect| AABB::from_corners(rect.min().into(), rect.max().into()))
+    };
+    let tree = RTree::bulk_load(
+        rhs.iter()
+            .enumerate()
+            .filter_map(|(index, geometry)| {
+                Some(Envelope {
+                    aabb: aabb(geometry)?,
+                    index,
+                })
+            })
+            .collect(),
+    );
+
+    let mut pairs = vec![];
+    for (i, geometry) in lhs.iter().enumerate() {
+        let envelope = match aabb(geometry) {
+            Some(envelope) => envelope,
+            None => continue,
+        };
+        let start = pairs.len();
+        pairs.extend(
+            tree.locate_in_envelope_intersecting(&envelope)
+                .map(|candidate| candidate.index)
+                .filter(|&j| predicate.evaluate(geometry, &rhs[j]))
+                .map(|j| (i, j)),
+        );
+        pairs[start..].sort_unstable();
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Line, LineString, Polygon, Rect};
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force(lhs: &[Geometry<f64>], rhs: &[Geometry<f64>]) -> Vec<(usize, usize)> {
+        let mut pairs = vec![];
+        for (i, a) in lhs.iter().enumerate() {
+            for (j, b) in rhs.iter().enumerate() {
+                if a.intersects(b) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn random_geometries(rng: &mut impl Rng, count: usize) -> Vec<Geometry<f64>> {
+        (0..count)
+            .map(|idx| {
+                let x = rng.gen_range(0. ..100.);
+                let y = rng.gen_range(0. ..100.);
+                let (dx, dy) = (rng.gen_range(0. ..5.), rng.gen_range(0. ..5.));
+                match idx % 4 {
+                    0 => Point::new(x, y).into(),
+                    1 => Line::new((x, y), (x + dx, y - dy)).into(),
+                    2 => Rect::new((x, y), (x + dx, y + dy)).into(),
+                    _ => Polygon::new(
+                        LineString::from(vec![(x, y), (x + dx, y), (x, y + dy)]),
+                        vec![],
+                    )
+                    .into(),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(195);
+        for _ in 0..5 {
+            let lhs = random_geometries(&mut rng, 200);
+            let rhs = random_geometries(&mut rng, 300);
+            let pairs = spatial_join(&lhs, &rhs, Predicate::Intersects);
+            assert!(!pairs.is_empty());
+            assert_eq!(pairs, brute_force(&lhs, &rhs));
+        }
+    }
+
+    #[test]
+    fn touching() {
+        // Bounding rects which only share an edge or a corner
+        let lhs: Vec<Geometry<f64>> = vec![Rect::new((0., 0.), (1., 1.)).into()];
+        let rhs: Vec<Geometry<f64>> = vec![
+            Rect::new((1., 0.), (2., 1.)).into(),
+            Point::new(1., 1.).into(),
+            Point::new(1., 1.000001).into(),
+            Line::new((2., 0.), (0., 2.)).into(),
+            Line::new((2., 0.5), (0.5, 2.)).into(),
+        ];
+        assert_eq!(
+            spatial_join(&lhs, &rhs, Predicate::Intersects),
+            vec![(0, 0), (0, 1), (0, 3)]
+        );
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        let lhs: Vec<Geometry<i64>> = vec![
+            Rect::new((0, 0), (10, 10)).into(),
+            Line::new((20, 0), (30, 10)).into(),
+        ];
+        let rhs: Vec<Geometry<i64>> = vec![
+            Point::new(25, 5).into(),
+            Point::new(25, 6).into(),
+            Line::new((10, 10), (25, 0)).into(),
+            Point::new(-1, 5).into(),
+        ];
+        assert_eq!(
+            spatial_join(&lhs, &rhs, Predicate::Intersects),
+            vec![(0, 2), (1, 0), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let rect: Geometry<f64> = Rect::new((0., 0.), (1., 1.)).into();
+        let empty: Geometry<f64> = LineString::<f64>(vec![]).into();
+        assert!(spatial_join(&[], std::slice::from_ref(&rect), Predicate::Intersects).is_empty());
+        assert!(spatial_join(std::slice::from_ref(&rect), &[], Predicate::Intersects).is_empty());
+        assert_eq!(
+            spatial_join(
+                &[empty.clone(), rect.clone()],
+                &[rect, empty],
+                Predicate::Intersects
+            ),
+            vec![(1, 0)]
+        );
+    }
+}
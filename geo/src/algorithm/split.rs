@@ -0,0 +1,251 @@
+use crate::algorithm::bool_ops::overlay::{
+    assemble_rings, boundary_edges, build_polygons, node_edges,
+};
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Polygon};
+
+/// Split a `Polygon` into pieces by cutting it with a `LineString`.
+///
+/// The cutter is first broken up wherever it meets the boundary of the
+/// polygon. Each part of the cutter that runs through the interior of
+/// the polygon, _from_ one boundary point _to_ another, cuts the polygon.
+/// Both ends of a cut may lie on the exterior, on an interior, or one on
+/// each. Parts of the cutter that start or stop strictly inside the
+/// polygon (i.e. dangling into it), as well as those running outside or
+/// along the boundary, are ignored.
+///
+/// Consequently, if the cutter does not pass all the way through the
+/// polygon, the result contains the polygon unchanged as its only member.
+///
+/// The polygon is expected to be valid, and the cutter to not intersect
+/// itself; the output is unspecified otherwise. Exteriors of the output
+/// are oriented counter-clockwise, and interiors clockwise.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::split::Split;
+/// use geo::{line_string, polygon};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// let halves = square.split(&line_string![
+///     (x: 1., y: -1.),
+///     (x: 1., y: 3.),
+/// ]);
+/// assert_eq!(halves.0.len(), 2);
+/// assert_eq!(halves.0[0].unsigned_area(), 2.);
+/// assert_eq!(halves.0[1].unsigned_area(), 2.);
+///
+/// // Stops halfway through the square, so does not split it
+/// let unsplit = square.split(&line_string![
+///     (x: 1., y: -1.),
+///     (x: 1., y: 1.),
+/// ]);
+/// assert_eq!(unsplit.0.len(), 1);
+/// ```
+pub trait Split<T: GeoFloat> {
+    fn split(&self, cutter: &LineString<T>) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> Split<T> for Polygon<T> {
+    fn split(&self, cutter: &LineString<T>) -> MultiPolygon<T> {
+        let mut edges = boundary_edges(std::slice::from_ref(self));
+        let mut cutter_edges: Vec<_> = cutter
+            .lines()
+            .filter(|line| line.start != line.end)
+            .collect();
+        node_edges(&mut edges, &mut cutter_edges);
+
+        let cuts = cutting_edges(self, &edges, &cutter_edges);
+        if cuts.is_empty() {
+            return MultiPolygon(vec![self.clone()]);
+        }
+
+        // Each cut bounds the pieces on both of its sides
+        for cut in cuts {
+            edges.push(cut);
+            edges.push(Line::new(cut.end, cut.start));
+        }
+        let rings = assemble_rings(edges)
+            .into_iter()
+            .flat_map(remove_slits)
+            .collect();
+        build_polygons(rings)
+    }
+}
+
+/// Select those (noded) `cutter_edges` which belong to a chain running
+/// through the interior of `polygon`, from one boundary node to another.
+fn cutting_edges<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    boundary: &[Line<T>],
+    cutter_edges: &[Line<T>],
+) -> Vec<Line<T>> {
+    // After noding, every point where the cutter meets the boundary is
+    // an end point of some boundary edge.
+    let mut nodes: Vec<Coordinate<T>> = boundary
+        .iter()
+        .flat_map(|edge| vec![edge.start, edge.end])
+        .collect();
+    nodes.sort_by(lex_cmp);
+    nodes.dedup();
+    let on_boundary = |coord: &Coordinate<T>| {
+        nodes
+            .binary_search_by(|probe| lex_cmp(probe, coord))
+            .is_ok()
+    };
+
+    let two = T::one() + T::one();
+    let is_inside = |edge: &Line<T>| {
+        let midpoint = (edge.start + edge.end) / two;
+        polygon.coordinate_position(&midpoint) == CoordPos::Inside
+    };
+
+    let mut cuts = vec![];
+    let mut chain: Vec<Line<T>> = vec![];
+    for edge in cutter_edges {
+        chain.push(*edge);
+        if on_boundary(&edge.end) {
+            if on_boundary(&chain[0].start) && is_inside(&chain[0]) {
+                cuts.append(&mut chain);
+            }
+            chain.clear();
+        }
+    }
+    // Anything left over dangles into the polygon
+    cuts
+}
+
+/// A cut which does not separate two pieces (e.g. one running from the
+/// exterior to an interior) is traversed in both directions by the same
+/// ring. Remove such slits, re-assembling what is left of the ring.
+fn remove_slits<T: GeoFloat>(ring: LineString<T>) -> Vec<LineString<T>> {
+    let cmp_lines = |a: &Line<T>, b: &Line<T>| {
+        lex_cmp(&a.start, &b.start).then_with(|| lex_cmp(&a.end, &b.end))
+    };
+    let mut sorted: Vec<Line<T>> = ring.lines().collect();
+    sorted.sort_by(cmp_lines);
+    let has_reverse = |line: &Line<T>| {
+        let reverse = Line::new(line.end, line.start);
+        sorted
+            .binary_search_by(|probe| cmp_lines(probe, &reverse))
+            .is_ok()
+    };
+
+    if !ring.lines().any(|line| has_reverse(&line)) {
+        return vec![ring];
+    }
+    let remaining = ring.lines().filter(|line| !has_reverse(line)).collect();
+    assemble_rings(remaining)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    fn square() -> Polygon<f64> {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ]
+    }
+
+    #[test]
+    fn split_in_halves() {
+        let cutter = line_string![(x: 2., y: -1.), (x: 2., y: 5.)];
+        let result = square().split(&cutter);
+        assert_eq!(result.0.len(), 2);
+        for half in &result.0 {
+            assert_relative_eq!(half.unsigned_area(), 8.);
+        }
+    }
+
+    #[test]
+    fn split_cutter_ends_on_boundary() {
+        let cutter = line_string![(x: 2., y: 0.), (x: 2., y: 4.)];
+        let result = square().split(&cutter);
+        assert_eq!(result.0.len(), 2);
+        assert_relative_eq!(result.unsigned_area(), 16.);
+
+        // A bent cutter, running corner to corner
+        let cutter = line_string![(x: 0., y: 0.), (x: 1., y: 3.), (x: 4., y: 4.)];
+        let result = square().split(&cutter);
+        assert_eq!(result.0.len(), 2);
+        assert_relative_eq!(result.unsigned_area(), 16.);
+    }
+
+    #[test]
+    fn split_partial_cutter() {
+        let cutter = line_string![(x: 2., y: -1.), (x: 2., y: 2.)];
+        let result = square().split(&cutter);
+        assert_eq!(result, MultiPolygon(vec![square()]));
+
+        // Starting and stopping inside
+        let cutter = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+        assert_eq!(square().split(&cutter), MultiPolygon(vec![square()]));
+
+        // Running along the boundary
+        let cutter = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(square().split(&cutter), MultiPolygon(vec![square()]));
+    }
+
+    #[test]
+    fn split_multiple_pieces() {
+        // Crosses the square three times, with a dangling end
+        let cutter = line_string![
+            (x: 1., y: -1.),
+            (x: 1., y: 5.),
+            (x: 2., y: 5.),
+            (x: 2., y: -1.),
+            (x: 3., y: -1.),
+            (x: 3., y: 2.),
+        ];
+        let result = square().split(&cutter);
+        assert_eq!(result.0.len(), 3);
+        assert_relative_eq!(result.unsigned_area(), 16.);
+    }
+
+    #[test]
+    fn split_through_hole() {
+        let donut = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 4.),
+                (x: 0., y: 4.),
+            ],
+            interiors: [
+                [
+                    (x: 1., y: 1.),
+                    (x: 3., y: 1.),
+                    (x: 3., y: 3.),
+                    (x: 1., y: 3.),
+                ],
+            ],
+        ];
+
+        // Cutting only one side of the ring leaves it in one piece
+        let cutter = line_string![(x: 2., y: -1.), (x: 2., y: 2.)];
+        let result = donut.split(&cutter);
+        assert_eq!(result.0.len(), 1);
+        assert_eq!(result.0[0].interiors().len(), 1);
+        assert_relative_eq!(result.unsigned_area(), 12.);
+
+        let cutter = line_string![(x: 2., y: -1.), (x: 2., y: 5.)];
+        let result = donut.split(&cutter);
+        assert_eq!(result.0.len(), 2);
+        assert_relative_eq!(result.unsigned_area(), 12.);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::algorithm::bentley_ottmann::bentley_ottmann;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString};
+
+/// Split a `LineString` wherever it meets itself, so that each part is
+/// simple, such as to clean up a GPS track which crosses itself.
+///
+/// The self-intersections are found with [`bentley_ottmann`], and the line
+/// is broken at each of them, whether it crosses itself, touches itself,
+/// or runs back along itself. Consecutive segments meeting at their
+/// shared vertex don't count, and neither does a closed line meeting
+/// itself at its start.
+///
+/// The parts are returned in order along the line, each starting where
+/// the previous one ends. A part can be closed, where the line loops back
+/// to a point it passed before. The parts of a closed line are joined
+/// across its start, unless it is a self-intersection too, so a figure of
+/// eight becomes its two loops. A line which doesn't meet itself is
+/// returned as its only part. Repeated consecutive coordinates are
+/// removed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::split_at_self_intersections::SplitAtSelfIntersections;
+/// use geo::{line_string, MultiLineString};
+///
+/// // Crossing itself at (1, 1)
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 0.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// assert_eq!(
+///     line_string.split_at_self_intersections(),
+///     MultiLineString(vec![
+///         line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+///         line_string![(x: 1., y: 1.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 1., y: 1.)],
+///         line_string![(x: 1., y: 1.), (x: 0., y: 2.)],
+///     ])
+/// );
+/// ```
+pub trait SplitAtSelfIntersections<T: GeoFloat> {
+    fn split_at_self_intersections(&self) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat> SplitAtSelfIntersections<T> for LineString<T> {
+    fn split_at_self_intersections(&self) -> MultiLineString<T> {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 2 {
+            return MultiLineString(vec![LineString(coords)]);
+        }
+        let lines: Vec<Line<T>> = coords
+            .windows(2)
+            .map(|pair| Line::new(pair[0], pair[1]))
+            .collect();
+        let n = lines.len();
+        let closed = n > 2 && coords[0] == coords[n];
+
+        // Where to break the line: at vertices, and part way along segments
+        let mut vertex_breaks = vec![false; n + 1];
+        let mut segment_breaks: Vec<Vec<(T, Coordinate<T>)>> = vec![vec![]; n];
+        for (i, j, point) in bentley_ottmann(&lines) {
+            let point = point.0;
+            if (j == i + 1 && point == coords[j])
+                || (closed && i == 0 && j == n - 1 && point == coords[0])
+            {
+                continue;
+            }
+            for &k in &[i, j] {
+                let line = &lines[k];
+                if point == line.start {
+                    vertex_breaks[k] = true;
+                } else if point == line.end {
+                    vertex_breaks[k + 1] = true;
+                } else {
+                    let (delta, offset) = (line.delta(), point - line.start);
+                    let fraction = (delta.x * offset.x + delta.y * offset.y)
+                        / (delta.x * delta.x + delta.y * delta.y);
+                    segment_breaks[k].push((fraction, point));
+                }
+            }
+        }
+
+        let mut parts = vec![];
+        let mut part = vec![coords[0]];
+        for (k, breaks) in segment_breaks.iter_mut().enumerate() {
+            breaks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+            // Where several other segments meet this one at the same point
+            breaks.dedup_by(|a, b| a.1 == b.1);
+            for &(_, point) in breaks.iter() {
+                part.push(point);
+                parts.push(LineString(std::mem::replace(&mut part, vec![point])));
+            }
+            part.push(coords[k + 1]);
+            if k + 1 < n && vertex_breaks[k + 1] {
+                parts.push(LineString(std::mem::replace(
+                    &mut part,
+                    vec![coords[k + 1]],
+                )));
+            }
+        }
+        parts.push(LineString(part));
+
+        if closed && parts.len() > 1 && !vertex_breaks[0] && !vertex_breaks[n] {
+            let first = parts.remove(0);
+            parts.last_mut().unwrap().0.extend_from_slice(&first.0[1..]);
+        }
+        MultiLineString(parts)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn figure_of_eight() {
+        let figure_of_eight = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(
+            figure_of_eight.split_at_self_intersections(),
+            MultiLineString(vec![
+                line_string![(x: 1., y: 1.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 0., y: 2.), (x: 0., y: 0.), (x: 1., y: 1.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn simple() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 3., y: 1.),
+        ];
+        assert_eq!(
+            line_string.split_at_self_intersections(),
+            MultiLineString(vec![line_string.clone()])
+        );
+
+        // A closed ring
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(
+            ring.split_at_self_intersections(),
+            MultiLineString(vec![ring.clone()])
+        );
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(
+            empty.split_at_self_intersections(),
+            MultiLineString(vec![empty])
+        );
+    }
+
+    #[test]
+    fn touching() {
+        // Passing back through its own vertex at (1, 0)
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 2., y: -1.),
+        ];
+        assert_eq!(
+            line_string.split_at_self_intersections(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: -1.)],
+            ])
+        );
+
+        // Running back along itself, and with a repeated coordinate
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 0.),
+            (x: 1., y: 0.),
+        ];
+        assert_eq!(
+            line_string.split_at_self_intersections(),
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn crossing_twice() {
+        // A spiral which crosses its first segment twice
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 5.),
+            (x: 2., y: 5.),
+            (x: 2., y: -2.),
+            (x: 6., y: -2.),
+            (x: 6., y: 3.),
+        ];
+        let parts = line_string.split_at_self_intersections();
+        assert_eq!(
+            parts,
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 6., y: 0.)],
+                line_string![(x: 6., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.), (x: 2., y: 5.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 2., y: -2.), (x: 6., y: -2.), (x: 6., y: 0.)],
+                line_string![(x: 6., y: 0.), (x: 6., y: 3.)],
+            ])
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use crate::{CoordNum, Coordinate, MultiPoint, Point, Polygon, Rect};
+
+/// Cover a `Rect` with a grid of square cells, such as to bin points or
+/// aggregate raster values.
+///
+/// The grid is anchored at the minimum corner of the `Rect`: the cell in
+/// column `i` and row `j` spans `min.x + i * cell_size` to
+/// `min.x + (i + 1) * cell_size` in `x`, and likewise in `y`, so adjacent
+/// cells share their edges exactly. There are as many columns and rows as
+/// are needed to cover the `Rect`, so where its size isn't a multiple of
+/// `cell_size`, the last column and row extend past it, unless `clip`
+/// trims them to it. The cells are the polygons of their
+/// [`Rect`](Rect::to_polygon)s, ordered row by row (increasing `y`), then
+/// by column (increasing `x`).
+///
+/// With integer coordinates, the cells are computed exactly. If
+/// `cell_size` is not a positive, finite number, or the `Rect` has no
+/// area, there are no cells.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::square_grid::SquareGrid;
+/// use geo::{Coordinate, Rect};
+///
+/// let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 3., y: 1.5 });
+///
+/// let cells = rect.square_grid(1., false);
+/// assert_eq!(cells.len(), 6);
+/// assert_eq!(cells[5], Rect::new(Coordinate { x: 2., y: 1. }, Coordinate { x: 3., y: 2. }).to_polygon());
+///
+/// let clipped = rect.square_grid(1., true);
+/// assert_eq!(clipped[5], Rect::new(Coordinate { x: 2., y: 1. }, Coordinate { x: 3., y: 1.5 }).to_polygon());
+/// ```
+pub trait SquareGrid<T: CoordNum> {
+    fn square_grid(&self, cell_size: T, clip: bool) -> Vec<Polygon<T>>;
+}
+
+impl<T: CoordNum> SquareGrid<T> for Rect<T> {
+    fn square_grid(&self, cell_size: T, clip: bool) -> Vec<Polygon<T>> {
+        if !is_valid_cell_size(cell_size) {
+            return vec![];
+        }
+        let min = self.min();
+        // Each edge is a whole number of cells from the minimum corner
+        let edge = |start: T, idx: usize| start + T::from(idx).unwrap() * cell_size;
+        let edges = |start: T, end: T| -> Vec<T> {
+            // The division truncates, so add a cell if it falls short
+            let mut count = match ((end - start) / cell_size).to_usize() {
+                Some(count) => count,
+                None => return vec![],
+            };
+            if edge(start, count) < end {
+                count += 1;
+            }
+            (0..=count)
+                .map(|idx| {
+                    let edge = edge(start, idx);
+                    if clip && edge > end {
+                        end
+                    } else {
+                        edge
+                    }
+                })
+                .collect()
+        };
+        let xs = edges(min.x, self.max().x);
+        let ys = edges(min.y, self.max().y);
+
+        let mut cells = Vec::with_capacity(xs.len().saturating_sub(1) * ys.len().saturating_sub(1));
+        for y in ys.windows(2) {
+            for x in xs.windows(2) {
+                let cell = Rect::new(
+                    Coordinate { x: x[0], y: y[0] },
+                    Coordinate { x: x[1], y: y[1] },
+                );
+                cells.push(cell.to_polygon());
+            }
+        }
+        cells
+    }
+}
+
+/// Bin points into the cells of a square grid, without creating the
+/// cells, such as to aggregate many points into a heatmap.
+///
+/// The grid is anchored at `origin`: cell `(i, j)` spans
+/// `origin.x + i * cell_size <= x < origin.x + (i + 1) * cell_size` in
+/// `x`, and likewise in `y`, so a point on the edge between two cells is
+/// in the one with the greater index, and cells below or left of `origin`
+/// have negative indices. These are the cells of a
+/// [`SquareGrid`] anchored at the same corner, computed with the same
+/// rounding.
+///
+/// The indices of the points in each cell are in increasing order. Points
+/// with a non-finite coordinate, or too far away for their cell to be
+/// indexed, are left out, and if `cell_size` is not a positive, finite
+/// number, there are no cells.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::square_grid::BinPoints;
+/// use geo::{point, MultiPoint};
+///
+/// let points = MultiPoint(vec![
+///     point!(x: 0.5, y: 0.5),
+///     point!(x: 1., y: 0.2),
+///     point!(x: -0.1, y: 3.),
+///     point!(x: 0.9, y: 0.1),
+/// ]);
+///
+/// let bins = points.bin_points(point!(x: 0., y: 0.), 1.);
+/// assert_eq!(bins.len(), 3);
+/// assert_eq!(bins[&(0, 0)], vec![0, 3]);
+/// assert_eq!(bins[&(1, 0)], vec![1]);
+/// assert_eq!(bins[&(-1, 3)], vec![2]);
+/// ```
+pub trait BinPoints<T: CoordNum> {
+    fn bin_points(&self, origin: Point<T>, cell_size: T) -> HashMap<(i64, i64), Vec<usize>>;
+}
+
+impl<T: CoordNum> BinPoints<T> for MultiPoint<T> {
+    fn bin_points(&self, origin: Point<T>, cell_size: T) -> HashMap<(i64, i64), Vec<usize>> {
+        let mut bins: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        if !is_valid_cell_size(cell_size) {
+            return bins;
+        }
+        for (idx, point) in self.iter().enumerate() {
+            let cell = cell_index(origin.x(), point.x(), cell_size).zip(cell_index(
+                origin.y(),
+                point.y(),
+                cell_size,
+            ));
+            if let Some(cell) = cell {
+                bins.entry(cell).or_default().push(idx);
+            }
+        }
+        bins
+    }
+}
+
+/// Whether `cell_size` is a positive, finite number.
+fn is_valid_cell_size<T: CoordNum>(cell_size: T) -> bool {
+    cell_size > T::zero() && matches!(cell_size.to_f64(), Some(size) if size.is_finite())
+}
+
+/// The index of the cell containing `value`, with edges at whole numbers
+/// of cells from `start`, as in `SquareGrid`.
+fn cell_index<T: CoordNum>(start: T, value: T, cell_size: T) -> Option<i64> {
+    // The division truncates towards zero, and may round across an edge,
+    // so check against the edges themselves
+    let mut index = ((value - start) / cell_size).to_i64()?;
+    let edge = |index: i64| start + T::from(index).unwrap() * cell_size;
+    while value < edge(index) {
+        index -= 1;
+    }
+    while value >= edge(index + 1) {
+        index += 1;
+    }
+    Some(index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bounding_rect::BoundingRect;
+
+    fn cell_rects(cells: &[Polygon<f64>]) -> Vec<Rect<f64>> {
+        cells
+            .iter()
+            .map(|cell| cell.bounding_rect().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn count() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 3. });
+        let cells = rect.square_grid(1., false);
+        assert_eq!(cells.len(), 12);
+        assert_eq!(cells, rect.square_grid(1., true));
+
+        // Not a multiple of the cell size
+        let rect = Rect::new(Coordinate { x: -1., y: 2. }, Coordinate { x: 1.5, y: 2.25 });
+        assert_eq!(rect.square_grid(1., false).len(), 3);
+        assert_eq!(rect.square_grid(0.25, true).len(), 10);
+    }
+
+    #[test]
+    fn tiles_without_gaps() {
+        let rect = Rect::new(Coordinate { x: 10., y: -5. }, Coordinate { x: 17., y: 0. });
+        let cells = rect.square_grid(2., false);
+        let rects = cell_rects(&cells);
+        let (columns, rows) = (4, 3);
+        assert_eq!(rects.len(), columns * rows);
+        for (idx, cell) in rects.iter().enumerate() {
+            assert_eq!(cell.width(), 2.);
+            assert_eq!(cell.height(), 2.);
+            // Each cell starts where the one before it, and the one below
+            // it, end
+            if idx % columns != 0 {
+                assert_eq!(cell.min().x, rects[idx - 1].max().x);
+                assert_eq!(cell.min().y, rects[idx - 1].min().y);
+            }
+            if idx >= columns {
+                assert_eq!(cell.min().y, rects[idx - columns].max().y);
+                assert_eq!(cell.min().x, rects[idx - columns].min().x);
+            }
+        }
+        assert_eq!(rects[0].min(), rect.min());
+        assert_eq!(rects[11].max(), Coordinate { x: 18., y: 1. });
+        let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_eq!(area, 48.);
+    }
+
+    #[test]
+    fn clip() {
+        let rect = Rect::new(Coordinate { x: 10., y: -5. }, Coordinate { x: 17., y: 0. });
+        let clipped = rect.square_grid(2., true);
+        let rects = cell_rects(&clipped);
+        assert_eq!(rects.len(), 12);
+        assert_eq!(rects[3], Rect::new((16., -5.), (17., -3.)));
+        assert_eq!(rects[8], Rect::new((10., -1.), (12., 0.)));
+        assert_eq!(rects[11], Rect::new((16., -1.), (17., 0.)));
+        let area: f64 = clipped.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(area, 35.);
+    }
+
+    #[test]
+    fn degenerate() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 5. });
+        for &cell_size in &[0., -1., f64::NAN, f64::INFINITY] {
+            assert!(rect.square_grid(cell_size, false).is_empty());
+        }
+        let line = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 0. });
+        assert!(line.square_grid(1., false).is_empty());
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        let rect = Rect::new(Coordinate { x: -4, y: 0 }, Coordinate { x: 5, y: 4 });
+        let cells = rect.square_grid(3, false);
+        assert_eq!(cells.len(), 6);
+        assert_eq!(
+            cells[5],
+            Rect::new(Coordinate { x: 2, y: 3 }, Coordinate { x: 5, y: 6 }).to_polygon()
+        );
+        let clipped = rect.square_grid(3, true);
+        assert_eq!(
+            clipped[5],
+            Rect::new(Coordinate { x: 2, y: 3 }, Coordinate { x: 5, y: 4 }).to_polygon()
+        );
+        assert!(rect.square_grid(0, false).is_empty());
+        assert!(rect.square_grid(-1, false).is_empty());
+    }
+
+    #[test]
+    fn bin_points_near_edges() {
+        let origin = Point::new(10., -5.);
+        let points = MultiPoint(vec![
+            // On the edges, which belong to the greater cell
+            Point::new(10., -5.),
+            Point::new(10.3, -4.7),
+            Point::new(10.29999999, -4.70000001),
+            // Just below and left of the origin
+            Point::new(9.99999999, -5.00000001),
+            Point::new(f64::NAN, 0.),
+            Point::new(10.05, -4.95),
+        ]);
+        let bins = points.bin_points(origin, 0.1);
+        assert_eq!(bins.len(), 4);
+        assert_eq!(bins[&(0, 0)], vec![0, 5]);
+        assert_eq!(bins[&(3, 3)], vec![1]);
+        assert_eq!(bins[&(2, 2)], vec![2]);
+        assert_eq!(bins[&(-1, -1)], vec![3]);
+    }
+
+    #[test]
+    fn bin_points_matches_square_grid() {
+        let rect = Rect::new(Coordinate { x: 0.1, y: 0.2 }, Coordinate { x: 0.9, y: 0.7 });
+        let cell_size = 0.1;
+        let cells = cell_rects(&rect.square_grid(cell_size, false));
+        let columns = 8;
+        // Points on every corner of every cell
+        let points: MultiPoint<f64> = cells.iter().map(|cell| Point(cell.min())).collect();
+        let bins = points.bin_points(Point(rect.min()), cell_size);
+        assert_eq!(bins.len(), cells.len());
+        for (idx, _) in cells.iter().enumerate() {
+            let cell = ((idx % columns) as i64, (idx / columns) as i64);
+            assert_eq!(bins[&cell], vec![idx]);
+        }
+    }
+
+    #[test]
+    fn bin_points_integer_coordinates() {
+        let points = MultiPoint(vec![
+            Point::new(0, 0),
+            Point::new(-1, -1),
+            Point::new(-3, 2),
+            Point::new(-4, 3),
+            Point::new(2, 5),
+        ]);
+        // The division truncates towards zero, but cells are floored
+        let bins = points.bin_points(Point::new(0, 0), 3);
+        assert_eq!(bins.len(), 5);
+        assert_eq!(bins[&(0, 0)], vec![0]);
+        assert_eq!(bins[&(-1, -1)], vec![1]);
+        assert_eq!(bins[&(-1, 0)], vec![2]);
+        assert_eq!(bins[&(-2, 1)], vec![3]);
+        assert_eq!(bins[&(0, 1)], vec![4]);
+    }
+
+    #[test]
+    fn bin_points_degenerate() {
+        let points = MultiPoint(vec![Point::new(1., 1.)]);
+        for &cell_size in &[0., -1., f64::NAN, f64::INFINITY] {
+            assert!(points.bin_points(Point::new(0., 0.), cell_size).is_empty());
+        }
+        // Too far away to index
+        let far = MultiPoint(vec![Point::new(1e300, 0.)]);
+        assert!(far.bin_points(Point::new(0., 0.), 1.).is_empty());
+    }
+}
@@ -0,0 +1,550 @@
+use crate::algorithm::area::get_linestring_area;
+use crate::{Coordinate, GeoFloat, LineString, MultiLineString, Polygon};
+
+/// Compute the straight skeleton of a `Polygon`: the paths traced by its
+/// vertices as its edges move inwards at the same speed, until it shrinks
+/// away to nothing.
+///
+/// The skeleton runs along the middle of the polygon, so it is useful for
+/// turning an areal feature into a linear one, such as a river into its
+/// centreline. Each vertex starts an arc along the bisector of its corner,
+/// and the arcs meet where edges of the shrinking polygon collapse, or
+/// where a reflex vertex splits the polygon in two.
+///
+/// The skeleton is returned as a `MultiLineString` of its arcs, each a
+/// `LineString` of two coordinates. The arcs from a convex polygon meet at
+/// a point, or along a spine for an elongated one, like a rectangle.
+///
+/// The shrinking polygon is simulated directly, finding and handling its
+/// events one at a time, which takes `O(n³)` time for a polygon with `n`
+/// vertices. Only the exterior is used, so holes are ignored. Repeated and
+/// collinear vertices are ignored too, so they don't start arcs. The
+/// polygon is expected to be valid; an empty `MultiLineString` is returned
+/// if it has no area.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::straight_skeleton::StraightSkeleton;
+/// use geo::{line_string, polygon, MultiLineString};
+///
+/// let square = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// // An X, from each corner to the centre
+/// assert_eq!(
+///     square.straight_skeleton(),
+///     MultiLineString(vec![
+///         line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+///         line_string![(x: 2., y: 0.), (x: 1., y: 1.)],
+///         line_string![(x: 2., y: 2.), (x: 1., y: 1.)],
+///         line_string![(x: 0., y: 2.), (x: 1., y: 1.)],
+///     ])
+/// );
+/// ```
+pub trait StraightSkeleton<T: GeoFloat> {
+    fn straight_skeleton(&self) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat> StraightSkeleton<T> for Polygon<T> {
+    fn straight_skeleton(&self) -> MultiLineString<T> {
+        let mut coords = corners(self.exterior());
+        if coords.len() < 3 {
+            return MultiLineString(vec![]);
+        }
+        // Counter-clockwise, so that the inside is to the left of each edge
+        if get_linestring_area(self.exterior()) < T::zero() {
+            coords.reverse();
+        }
+        let (min, max) = coords.iter().fold((coords[0], coords[0]), |(min, max), c| {
+            (
+                Coordinate {
+                    x: min.x.min(c.x),
+                    y: min.y.min(c.y),
+                },
+                Coordinate {
+                    x: max.x.max(c.x),
+                    y: max.y.max(c.y),
+                },
+            )
+        });
+        let extent = (max.x - min.x).max(max.y - min.y);
+        let mut skeleton = Skeleton {
+            arcs: vec![],
+            tolerance: extent * T::from(1e-10).unwrap(),
+        };
+
+        let n = coords.len();
+        let wavefront: Vec<Vertex<T>> = (0..n)
+            .map(|i| {
+                let (prev, coord, next) = (coords[(i + n - 1) % n], coords[i], coords[(i + 1) % n]);
+                Vertex::new(coord, unit(coord - prev), unit(next - coord))
+            })
+            .collect();
+        let mut wavefronts = vec![wavefront];
+
+        // Every event removes a vertex, or a reflex vertex, so this is only
+        // a guard against numerical trouble
+        for _ in 0..(4 * n * n + 16) {
+            let (time, event) = match skeleton.next_event(&wavefronts) {
+                Some(next) => next,
+                None => break,
+            };
+            for wavefront in &mut wavefronts {
+                for vertex in wavefront.iter_mut() {
+                    vertex.position = vertex.position + vertex.velocity * time;
+                }
+            }
+            let new_wavefronts = match event {
+                Event::Edge { wavefront, index } => {
+                    vec![skeleton.edge_event(wavefronts.swap_remove(wavefront), index)]
+                }
+                Event::Split {
+                    wavefront,
+                    reflex,
+                    edge,
+                } => skeleton.split_event(wavefronts.swap_remove(wavefront), reflex, edge),
+            };
+            for wavefront in new_wavefronts {
+                if wavefront.len() < 3 {
+                    skeleton.collapse(&wavefront);
+                } else {
+                    wavefronts.push(wavefront);
+                }
+            }
+            if wavefronts.is_empty() {
+                break;
+            }
+        }
+        MultiLineString(skeleton.arcs)
+    }
+}
+
+/// A vertex of the shrinking polygon.
+#[derive(Clone, Copy)]
+struct Vertex<T: GeoFloat> {
+    /// Where the vertex started, and its arc starts
+    origin: Coordinate<T>,
+    position: Coordinate<T>,
+    /// How far the vertex moves as the edges move in by a unit
+    velocity: Coordinate<T>,
+    /// The direction of the edge to the next vertex, as a unit vector
+    edge: Coordinate<T>,
+    reflex: bool,
+}
+
+impl<T: GeoFloat> Vertex<T> {
+    fn new(position: Coordinate<T>, incoming: Coordinate<T>, outgoing: Coordinate<T>) -> Self {
+        let (n1, n2) = (left(incoming), left(outgoing));
+        // The velocity moves the vertex along both edges' normals at unit
+        // speed. Where the edges run back along each other, it is unbounded,
+        // but then the vertex is about to be removed.
+        let denominator = T::one() + dot(n1, n2);
+        let velocity = if denominator > T::epsilon() {
+            (n1 + n2) / denominator
+        } else {
+            Coordinate::zero()
+        };
+        Vertex {
+            origin: position,
+            position,
+            velocity,
+            edge: outgoing,
+            reflex: cross(incoming, outgoing) < -T::epsilon(),
+        }
+    }
+}
+
+enum Event {
+    /// The edge after the vertex at `index` shrinks to nothing
+    Edge { wavefront: usize, index: usize },
+    /// The `reflex` vertex runs into the edge after the vertex at `edge`
+    Split {
+        wavefront: usize,
+        reflex: usize,
+        edge: usize,
+    },
+}
+
+struct Skeleton<T: GeoFloat> {
+    arcs: Vec<LineString<T>>,
+    tolerance: T,
+}
+
+impl<T: GeoFloat> Skeleton<T> {
+    fn add_arc(&mut self, from: Coordinate<T>, to: Coordinate<T>) {
+        let delta = to - from;
+        if delta.x.hypot(delta.y) > self.tolerance {
+            self.arcs.push(LineString(vec![from, to]));
+        }
+    }
+
+    /// The time until the next event, and the event
+    fn next_event(&self, wavefronts: &[Vec<Vertex<T>>]) -> Option<(T, Event)> {
+        let mut next: Option<(T, Event)> = None;
+        let mut consider = |time: T, event: Event| {
+            if next.as_ref().map(|(best, _)| time < *best) != Some(false) {
+                next = Some((time, event));
+            }
+        };
+        for (w, wavefront) in wavefronts.iter().enumerate() {
+            let n = wavefront.len();
+            for index in 0..n {
+                let (a, b) = (&wavefront[index], &wavefront[(index + 1) % n]);
+                let length = dot(b.position - a.position, a.edge);
+                let closing_speed = dot(a.velocity - b.velocity, a.edge);
+                if length <= self.tolerance {
+                    consider(
+                        T::zero(),
+                        Event::Edge {
+                            wavefront: w,
+                            index,
+                        },
+                    );
+                } else if closing_speed > T::zero() {
+                    consider(
+                        length / closing_speed,
+                        Event::Edge {
+                            wavefront: w,
+                            index,
+                        },
+                    );
+                }
+            }
+
+            for reflex in 0..n {
+                let r = &wavefront[reflex];
+                if !r.reflex {
+                    continue;
+                }
+                for edge in 0..n {
+                    let next = (edge + 1) % n;
+                    if edge == reflex || next == reflex {
+                        continue;
+                    }
+                    let (x, y) = (&wavefront[edge], &wavefront[next]);
+                    if let Some(time) = self.split_time(r, x, y) {
+                        consider(
+                            time,
+                            Event::Split {
+                                wavefront: w,
+                                reflex,
+                                edge,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+        next
+    }
+
+    /// The time until the reflex vertex `r` reaches the edge from `x` to `y`
+    fn split_time(&self, r: &Vertex<T>, x: &Vertex<T>, y: &Vertex<T>) -> Option<T> {
+        let normal = left(x.edge);
+        let distance = dot(r.position - x.position, normal);
+        let closing_speed = T::one() - dot(r.velocity, normal);
+        if distance < -self.tolerance || closing_speed <= T::epsilon() {
+            return None;
+        }
+        let time = distance.max(T::zero()) / closing_speed;
+        let hit = r.position + r.velocity * time;
+        let start = x.position + x.velocity * time;
+        let end = y.position + y.velocity * time;
+        let along = dot(hit - start, x.edge);
+        let length = dot(end - start, x.edge);
+        if along >= -self.tolerance && along <= length + self.tolerance {
+            Some(time)
+        } else {
+            None
+        }
+    }
+
+    fn edge_event(&mut self, mut wavefront: Vec<Vertex<T>>, index: usize) -> Vec<Vertex<T>> {
+        let n = wavefront.len();
+        let (prev, next) = ((index + n - 1) % n, (index + 1) % n);
+        let (a, b) = (wavefront[index], wavefront[next]);
+        let two = T::one() + T::one();
+        let meeting = (a.position + b.position) / two;
+        self.add_arc(a.origin, meeting);
+        self.add_arc(b.origin, meeting);
+        wavefront[index] = Vertex::new(meeting, wavefront[prev].edge, b.edge);
+        wavefront.remove(next);
+        wavefront
+    }
+
+    fn split_event(
+        &mut self,
+        mut wavefront: Vec<Vertex<T>>,
+        reflex: usize,
+        edge: usize,
+    ) -> Vec<Vec<Vertex<T>>> {
+        let n = wavefront.len();
+        let r = wavefront[reflex];
+        let split_edge = wavefront[edge].edge;
+        self.add_arc(r.origin, r.position);
+
+        // Starting from the reflex vertex, the edge runs from `x` to `x + 1`
+        wavefront.rotate_left(reflex);
+        let x = (edge + n - reflex) % n;
+        let before = Vertex::new(r.position, wavefront[n - 1].edge, split_edge);
+        let after = Vertex::new(r.position, split_edge, r.edge);
+
+        let mut first = vec![after];
+        first.extend_from_slice(&wavefront[1..=x]);
+        let mut second = vec![before];
+        second.extend_from_slice(&wavefront[x + 1..]);
+        vec![first, second]
+    }
+
+    /// Finish a wavefront which has shrunk to fewer than three vertices
+    fn collapse(&mut self, wavefront: &[Vertex<T>]) {
+        for vertex in wavefront {
+            self.add_arc(vertex.origin, vertex.position);
+        }
+        if let [a, b] = wavefront {
+            self.add_arc(a.position, b.position);
+        }
+    }
+}
+
+/// The coordinates of a ring, without its closing coordinate, or any
+/// repeated or collinear coordinates
+fn corners<T: GeoFloat>(ring: &LineString<T>) -> Vec<Coordinate<T>> {
+    let mut coords: Vec<Coordinate<T>> = ring.0.clone();
+    coords.dedup();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    loop {
+        let n = coords.len();
+        if n < 3 {
+            return coords;
+        }
+        let collinear = (0..n).find(|&i| {
+            let (prev, coord, next) = (coords[(i + n - 1) % n], coords[i], coords[(i + 1) % n]);
+            cross(coord - prev, next - coord) == T::zero()
+        });
+        match collinear {
+            Some(i) => {
+                coords.remove(i);
+            }
+            None => return coords,
+        }
+    }
+}
+
+fn unit<T: GeoFloat>(c: Coordinate<T>) -> Coordinate<T> {
+    c / c.x.hypot(c.y)
+}
+
+/// The normal to the left of a direction
+fn left<T: GeoFloat>(c: Coordinate<T>) -> Coordinate<T> {
+    Coordinate { x: -c.y, y: c.x }
+}
+
+fn dot<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>) -> T {
+    a.x * b.x + a.y * b.y
+}
+
+fn cross<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+    use crate::{line_string, polygon};
+
+    type Arc = ((i64, i64), (i64, i64));
+
+    /// The arcs, as ordered pairs of rounded coordinates, sorted
+    fn normalized(skeleton: &MultiLineString<f64>) -> Vec<Arc> {
+        let round = |c: Coordinate<f64>| ((c.x * 1e6).round() as i64, (c.y * 1e6).round() as i64);
+        let mut arcs: Vec<_> = skeleton
+            .iter()
+            .map(|arc| {
+                assert_eq!(arc.0.len(), 2);
+                let (a, b) = (round(arc.0[0]), round(arc.0[1]));
+                if a < b {
+                    (a, b)
+                } else {
+                    (b, a)
+                }
+            })
+            .collect();
+        arcs.sort_unstable();
+        arcs
+    }
+
+    fn expected(arcs: &[[(f64, f64); 2]]) -> Vec<Arc> {
+        normalized(&MultiLineString(
+            arcs.iter()
+                .map(|&arc| LineString::from(arc.to_vec()))
+                .collect(),
+        ))
+    }
+
+    #[test]
+    fn square() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let x = expected(&[
+            [(0., 0.), (1., 1.)],
+            [(2., 0.), (1., 1.)],
+            [(2., 2.), (1., 1.)],
+            [(0., 2.), (1., 1.)],
+        ]);
+        assert_eq!(normalized(&square.straight_skeleton()), x);
+
+        // Clockwise, with a repeated and a collinear vertex
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: 0., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+        ];
+        assert_eq!(normalized(&square.straight_skeleton()), x);
+    }
+
+    #[test]
+    fn rectangle() {
+        let rectangle = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_eq!(
+            normalized(&rectangle.straight_skeleton()),
+            expected(&[
+                [(0., 0.), (1., 1.)],
+                [(0., 2.), (1., 1.)],
+                [(4., 0.), (3., 1.)],
+                [(4., 2.), (3., 1.)],
+                // The central spine
+                [(1., 1.), (3., 1.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn triangle() {
+        // A 3-4-5 right triangle, whose incircle has radius 1
+        let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 3.)];
+        assert_eq!(
+            normalized(&triangle.straight_skeleton()),
+            expected(&[
+                [(0., 0.), (1., 1.)],
+                [(4., 0.), (1., 1.)],
+                [(0., 3.), (1., 1.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn l_shape() {
+        // Two arms, both 2 wide, meeting at a reflex vertex at (2, 2)
+        let l_shape = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        assert_eq!(
+            normalized(&l_shape.straight_skeleton()),
+            expected(&[
+                [(0., 0.), (1., 1.)],
+                [(2., 2.), (1., 1.)],
+                [(4., 0.), (3., 1.)],
+                [(4., 2.), (3., 1.)],
+                [(1., 1.), (3., 1.)],
+                [(2., 4.), (1., 3.)],
+                [(0., 4.), (1., 3.)],
+                [(1., 1.), (1., 3.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn concave() {
+        // An irregular polygon with a reflex vertex at (3, 2.5)
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 7., y: 0.),
+            (x: 7., y: 3.),
+            (x: 3., y: 2.5),
+            (x: 2., y: 6.),
+            (x: 0., y: 5.),
+        ];
+        let skeleton = polygon.straight_skeleton();
+        // Without any coincident events, a skeleton of a polygon with n
+        // vertices has 2n - 3 arcs
+        assert_eq!(skeleton.0.len(), 9);
+        for arc in skeleton.iter() {
+            for &coord in &arc.0 {
+                assert_ne!(polygon.coordinate_position(&coord), CoordPos::Outside);
+            }
+            let middle = (arc.0[0] + arc.0[1]) / 2.;
+            assert_eq!(polygon.coordinate_position(&middle), CoordPos::Inside);
+        }
+        // Each vertex starts one arc
+        for vertex in polygon.exterior().0.iter().skip(1) {
+            let starting = skeleton.iter().filter(|arc| arc.0.contains(vertex)).count();
+            assert_eq!(starting, 1);
+        }
+    }
+
+    #[test]
+    fn star() {
+        // Five points, with a reflex vertex between each pair
+        let exterior: Vec<(f64, f64)> = (0..10)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 5.;
+                let radius = if i % 2 == 0 { 5. } else { 2. };
+                (radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+        let star = Polygon::new(LineString::from(exterior), vec![]);
+        let skeleton = star.straight_skeleton();
+        for arc in skeleton.iter() {
+            let middle = (arc.0[0] + arc.0[1]) / 2.;
+            assert_eq!(star.coordinate_position(&middle), CoordPos::Inside);
+        }
+        for vertex in star.exterior().0.iter().skip(1) {
+            let starting = skeleton.iter().filter(|arc| arc.0.contains(vertex)).count();
+            assert_eq!(starting, 1);
+        }
+        // By symmetry, every arc runs straight to the centre
+        let at_centre = skeleton
+            .iter()
+            .filter(|arc| arc.0.iter().any(|c| c.x.hypot(c.y) < 1e-9))
+            .count();
+        assert_eq!(at_centre, 10);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(empty.straight_skeleton(), MultiLineString(vec![]));
+
+        let flat = Polygon::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)],
+            vec![],
+        );
+        assert_eq!(flat.straight_skeleton(), MultiLineString(vec![]));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{CoordFloat, Coordinate, LineString, Point, Polygon};
+
+/// Approximate a circle or ellipse around a `Point` as a `Polygon`, such
+/// as for a range ring.
+///
+/// The polygon is a regular `segments`-gon inscribed in the circle, or an
+/// affine image of one for an ellipse, so its vertices lie on the curve
+/// and its edges cut inside it. Its exterior is closed and wound
+/// counter-clockwise, starting from the end of the `x` radius, and it has
+/// no interiors. Fewer than three segments are treated as three.
+pub trait ToCircle<T: CoordFloat> {
+    /// Approximate a circle of `radius` around the point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::to_circle::ToCircle;
+    /// use geo::{line_string, point};
+    ///
+    /// let square = point!(x: 1., y: 1.).to_circle(2., 4);
+    ///
+    /// assert_relative_eq!(
+    ///     *square.exterior(),
+    ///     line_string![
+    ///         (x: 3., y: 1.),
+    ///         (x: 1., y: 3.),
+    ///         (x: -1., y: 1.),
+    ///         (x: 1., y: -1.),
+    ///         (x: 3., y: 1.),
+    ///     ],
+    ///     epsilon = 1e-9
+    /// );
+    /// ```
+    fn to_circle(&self, radius: T, segments: usize) -> Polygon<T>;
+
+    /// Approximate an ellipse around the point, with radii `x_radius` and
+    /// `y_radius` along its axes, rotated counter-clockwise by `angle`
+    /// degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::to_circle::ToCircle;
+    /// use geo::{line_string, point};
+    ///
+    /// // A rhombus, turned to stand on its point
+    /// let rhombus = point!(x: 0., y: 0.).to_ellipse(2., 1., 90., 4);
+    ///
+    /// assert_relative_eq!(
+    ///     *rhombus.exterior(),
+    ///     line_string![
+    ///         (x: 0., y: 2.),
+    ///         (x: -1., y: 0.),
+    ///         (x: 0., y: -2.),
+    ///         (x: 1., y: 0.),
+    ///         (x: 0., y: 2.),
+    ///     ],
+    ///     epsilon = 1e-9
+    /// );
+    /// ```
+    fn to_ellipse(&self, x_radius: T, y_radius: T, angle: T, segments: usize) -> Polygon<T>;
+}
+
+impl<T: CoordFloat> ToCircle<T> for Point<T> {
+    fn to_circle(&self, radius: T, segments: usize) -> Polygon<T> {
+        self.to_ellipse(radius, radius, T::zero(), segments)
+    }
+
+    fn to_ellipse(&self, x_radius: T, y_radius: T, angle: T, segments: usize) -> Polygon<T> {
+        let segments = segments.max(3);
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let step = (T::one() + T::one()) * T::from(std::f64::consts::PI).unwrap()
+            / T::from(segments).unwrap();
+        let exterior: LineString<T> = (0..segments)
+            .map(|idx| {
+                let (y, x) = (step * T::from(idx).unwrap()).sin_cos();
+                let (x, y) = (x * x_radius, y * y_radius);
+                Coordinate {
+                    x: self.x() + x * cos - y * sin,
+                    y: self.y() + x * sin + y * cos,
+                }
+            })
+            .collect();
+        Polygon::new(exterior, vec![])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::algorithm::winding_order::Winding;
+    use crate::point;
+
+    #[test]
+    fn circle() {
+        let center = point!(x: 3., y: -2.);
+        for &segments in &[3, 4, 7, 32, 1000] {
+            let circle = center.to_circle(5., segments);
+            let exterior = circle.exterior();
+            assert_eq!(exterior.0.len(), segments + 1);
+            assert!(exterior.is_closed());
+            assert!(exterior.is_ccw());
+            assert!(circle.interiors().is_empty());
+            for point in exterior.points_iter() {
+                assert_relative_eq!(point.euclidean_distance(&center), 5., epsilon = 1e-9);
+            }
+        }
+
+        // Too few segments for a polygon
+        assert_eq!(center.to_circle(1., 0).exterior().0.len(), 4);
+    }
+
+    #[test]
+    fn ellipse() {
+        let center = point!(x: 10., y: 20.);
+        let ellipse = center.to_ellipse(4., 1., 30., 64);
+        let exterior = ellipse.exterior();
+        assert_eq!(exterior.0.len(), 65);
+        assert!(exterior.is_closed());
+        assert!(exterior.is_ccw());
+
+        // Every vertex is on the ellipse, in its own rotated frame
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        for point in exterior.points_iter() {
+            let (dx, dy) = (point.x() - center.x(), point.y() - center.y());
+            let (x, y) = (dx * cos + dy * sin, dy * cos - dx * sin);
+            assert_relative_eq!((x / 4.).powi(2) + y.powi(2), 1., epsilon = 1e-9);
+        }
+        // Starting at the end of the x radius
+        assert_relative_eq!(
+            exterior.0[0],
+            Coordinate {
+                x: 10. + 4. * cos,
+                y: 20. + 4. * sin
+            }
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::algorithm::map_coords::{MapCoords, MapCoordsInplace};
-use crate::CoordNum;
+use crate::{CoordNum, Point};
 
 pub trait Translate<T> {
     /// Translate a Geometry along its axes by the given offsets
@@ -32,6 +32,32 @@ pub trait Translate<T> {
     fn translate_inplace(&mut self, xoff: T, yoff: T)
     where
         T: CoordNum;
+
+    /// Translate a Geometry so that the point `from` on it moves to `to`,
+    /// such as to move a chosen anchor, or its centroid, to a location
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::translate::Translate;
+    /// use geo::{line_string, point};
+    ///
+    /// let ls = line_string![
+    ///     (x: 1.0, y: 1.0),
+    ///     (x: 5.0, y: 3.0),
+    /// ];
+    ///
+    /// // Move the end of the line to (10, 10)
+    /// let translated = ls.translate_to(point!(x: 5.0, y: 3.0), point!(x: 10.0, y: 10.0));
+    ///
+    /// assert_eq!(translated, line_string![
+    ///     (x: 6.0, y: 8.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ]);
+    /// ```
+    fn translate_to(&self, from: Point<T>, to: Point<T>) -> Self
+    where
+        T: CoordNum;
 }
 
 impl<T, G> Translate<T> for G
@@ -46,12 +72,16 @@ where
     fn translate_inplace(&mut self, xoff: T, yoff: T) {
         self.map_coords_inplace(|&(x, y)| (x + xoff, y + yoff))
     }
+
+    fn translate_to(&self, from: Point<T>, to: Point<T>) -> Self {
+        self.translate(to.x() - from.x(), to.y() - from.y())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{line_string, point, polygon, Coordinate, LineString, Polygon};
+    use crate::{line_string, point, polygon, Coordinate, LineString, Polygon, Rect};
 
     #[test]
     fn test_translate_point() {
@@ -142,4 +172,48 @@ mod test {
         assert_eq!(rotated.exterior().0, correct_outside);
         assert_eq!(rotated.interiors()[0].0, correct_inside);
     }
+    #[test]
+    fn test_translate_to_centroid() {
+        use crate::algorithm::centroid::Centroid;
+        use crate::Geometry;
+
+        let poly = polygon![
+            (x: 2.0, y: 1.0),
+            (x: 8.0, y: 1.0),
+            (x: 8.0, y: 5.0),
+            (x: 2.0, y: 5.0),
+        ];
+        let origin = point!(x: 0.0, y: 0.0);
+        let centered = poly.translate_to(poly.centroid().unwrap(), origin);
+        assert_eq!(
+            centered,
+            polygon![
+                (x: -3.0, y: -2.0),
+                (x: 3.0, y: -2.0),
+                (x: 3.0, y: 2.0),
+                (x: -3.0, y: 2.0),
+            ]
+        );
+        assert_eq!(centered.centroid(), Some(origin));
+
+        let geometry = Geometry::Polygon(poly.clone());
+        assert_eq!(
+            geometry.translate_to(poly.centroid().unwrap(), origin),
+            Geometry::Polygon(centered)
+        );
+    }
+
+    #[test]
+    fn test_translate_integer() {
+        let line_string: LineString<i32> = vec![(0, 0), (3, 4), (-2, 7)].into();
+        assert_eq!(
+            line_string.translate(10, -5),
+            vec![(10, -5), (13, -1), (8, 2)].into()
+        );
+        let rect = Rect::new((0, 0), (2, 2));
+        assert_eq!(
+            rect.translate_to(point!(x: 1, y: 1), point!(x: 5, y: 5)),
+            Rect::new((4, 4), (6, 6))
+        );
+    }
 }
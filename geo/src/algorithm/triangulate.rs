@@ -0,0 +1,335 @@
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::kernels::*;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Polygon, Triangle};
+
+/// Triangulate a geometry by ear clipping.
+///
+/// Interiors are first connected to the exterior by "bridges" (pairs
+/// of coincident edges), resulting in a single ring which is then
+/// clipped. The resulting triangles are oriented counter-clockwise, do
+/// not overlap, and their union is the geometry.
+///
+/// Degenerate input, such as a `Polygon` with fewer than three distinct
+/// vertices, results in an empty `Vec`. The polygon is expected to be
+/// valid; if it is not, the triangles may not cover it.
+///
+/// This takes `O(n²)` time in the number of vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::triangulate::Triangulate;
+/// use geo::polygon;
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 1., y: 1.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// let triangles = polygon.triangulate();
+/// assert_eq!(triangles.len(), 3);
+/// let area: f64 = triangles.iter().map(|t| t.unsigned_area()).sum();
+/// assert_eq!(area, polygon.unsigned_area());
+/// ```
+pub trait Triangulate<T: GeoFloat> {
+    fn triangulate(&self) -> Vec<Triangle<T>>;
+}
+
+impl<T: GeoFloat> Triangulate<T> for Polygon<T> {
+    fn triangulate(&self) -> Vec<Triangle<T>> {
+        let polygon = self.orient(Direction::Default);
+        let mut ring = open_ring(polygon.exterior());
+        if ring.len() < 3 {
+            return vec![];
+        }
+
+        // Bridge the interiors with the largest x coordinates first, so
+        // that the ones to their right are already part of the ring.
+        let mut holes: Vec<Vec<Coordinate<T>>> = polygon
+            .interiors()
+            .iter()
+            .map(open_ring)
+            .filter(|hole| hole.len() >= 3)
+            .collect();
+        holes.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap());
+        for idx in 0..holes.len() {
+            let (hole, remaining) = holes[idx..].split_first().unwrap();
+            if let Some(merged) = bridge_hole(&ring, hole, remaining) {
+                ring = merged;
+            }
+        }
+
+        clip_ears(ring)
+    }
+}
+
+impl<T: GeoFloat> Triangulate<T> for MultiPolygon<T> {
+    fn triangulate(&self) -> Vec<Triangle<T>> {
+        self.iter()
+            .flat_map(|polygon| polygon.triangulate())
+            .collect()
+    }
+}
+
+/// The coordinates of a closed ring, without the closing coordinate and
+/// any consecutive duplicates.
+fn open_ring<T: GeoFloat>(ring: &LineString<T>) -> Vec<Coordinate<T>> {
+    let mut coords = ring.0.clone();
+    coords.dedup();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    coords
+}
+
+fn max_x<T: GeoFloat>(coords: &[Coordinate<T>]) -> T {
+    coords
+        .iter()
+        .map(|coord| coord.x)
+        .fold(T::neg_infinity(), T::max)
+}
+
+/// Connect `hole` to `ring` by a bridge from its right-most vertex to a
+/// visible vertex of `ring`, returning the merged ring. The bridge may not
+/// cross the `other_holes`.
+fn bridge_hole<T: GeoFloat>(
+    ring: &[Coordinate<T>],
+    hole: &[Coordinate<T>],
+    other_holes: &[Vec<Coordinate<T>>],
+) -> Option<Vec<Coordinate<T>>> {
+    let (m_idx, m) = hole
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.x.partial_cmp(&b.x).unwrap())?;
+
+    let squared_distance = |coord: Coordinate<T>| {
+        let delta = coord - m;
+        delta.x * delta.x + delta.y * delta.y
+    };
+    let mut candidates: Vec<usize> = (0..ring.len()).collect();
+    candidates.sort_by(|&i, &j| {
+        squared_distance(ring[i])
+            .partial_cmp(&squared_distance(ring[j]))
+            .unwrap()
+    });
+
+    let edges = |coords: &[Coordinate<T>]| -> Vec<Line<T>> {
+        (0..coords.len())
+            .map(|i| Line::new(coords[i], coords[(i + 1) % coords.len()]))
+            .collect()
+    };
+    let mut obstacles = edges(ring);
+    obstacles.extend(edges(hole));
+    for other in other_holes {
+        obstacles.extend(edges(other));
+    }
+
+    let v_idx = candidates.into_iter().find(|&k| {
+        let v = ring[k];
+        let prev = ring[(k + ring.len() - 1) % ring.len()];
+        let next = ring[(k + 1) % ring.len()];
+        let bridge = Line::new(m, v);
+        v != m
+            && locally_inside(prev, v, next, m)
+            && obstacles.iter().all(|edge| {
+                let incident = [edge.start, edge.end]
+                    .iter()
+                    .any(|&end| end == v || end == m);
+                incident || !bridge.intersects(edge)
+            })
+    })?;
+
+    let mut merged = Vec::with_capacity(ring.len() + hole.len() + 2);
+    merged.extend_from_slice(&ring[..=v_idx]);
+    merged.extend_from_slice(&hole[m_idx..]);
+    merged.extend_from_slice(&hole[..=m_idx]);
+    merged.extend_from_slice(&ring[v_idx..]);
+    Some(merged)
+}
+
+/// Whether the direction from `vertex` towards `target` points into the
+/// interior of a counter-clockwise ring, at a vertex between `prev` and
+/// `next`.
+fn locally_inside<T: GeoFloat>(
+    prev: Coordinate<T>,
+    vertex: Coordinate<T>,
+    next: Coordinate<T>,
+    target: Coordinate<T>,
+) -> bool {
+    let ccw = |p, q, r| T::Ker::orient2d(p, q, r) == Orientation::CounterClockwise;
+    if T::Ker::orient2d(prev, vertex, next) == Orientation::Clockwise {
+        // reflex
+        ccw(vertex, next, target) || ccw(prev, vertex, target)
+    } else {
+        ccw(vertex, next, target) && ccw(prev, vertex, target)
+    }
+}
+
+/// Triangulate a counter-clockwise, weakly simple, ring.
+fn clip_ears<T: GeoFloat>(mut ring: Vec<Coordinate<T>>) -> Vec<Triangle<T>> {
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    let mut idx = 0;
+    // The number of vertices visited since the last one was clipped
+    let mut stalled = 0;
+    while ring.len() >= 3 && stalled < ring.len() {
+        let len = ring.len();
+        idx %= len;
+        let prev = ring[(idx + len - 1) % len];
+        let vertex = ring[idx];
+        let next = ring[(idx + 1) % len];
+
+        match T::Ker::orient2d(prev, vertex, next) {
+            // Clipping a degenerate ear doesn't change the area
+            Orientation::Collinear => {}
+            Orientation::CounterClockwise if is_ear(&ring, prev, vertex, next) => {
+                triangles.push(Triangle(prev, vertex, next));
+            }
+            _ => {
+                idx += 1;
+                stalled += 1;
+                continue;
+            }
+        }
+        ring.remove(idx);
+        stalled = 0;
+        // The previous vertex may have become an ear
+        idx = (idx + len - 2) % (len - 1);
+    }
+    triangles
+}
+
+/// Whether no other vertex of `ring` lies in the (counter-clockwise)
+/// triangle `prev`, `vertex`, `next`.
+fn is_ear<T: GeoFloat>(
+    ring: &[Coordinate<T>],
+    prev: Coordinate<T>,
+    vertex: Coordinate<T>,
+    next: Coordinate<T>,
+) -> bool {
+    let not_cw = |p, q, r| T::Ker::orient2d(p, q, r) != Orientation::Clockwise;
+    ring.iter().all(|&coord| {
+        coord == prev
+            || coord == vertex
+            || coord == next
+            || !(not_cw(prev, vertex, coord)
+                && not_cw(vertex, next, coord)
+                && not_cw(next, prev, coord))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bool_ops::BooleanOps;
+    use crate::polygon;
+
+    fn assert_valid_triangulation(polygon: &Polygon<f64>, triangles: &[Triangle<f64>]) {
+        let area: f64 = triangles.iter().map(|t| t.signed_area()).sum();
+        assert_relative_eq!(area, polygon.unsigned_area(), epsilon = 1e-10);
+        for (i, a) in triangles.iter().enumerate() {
+            assert!(a.signed_area() > 0.);
+            for b in &triangles[i + 1..] {
+                let overlap = a.to_polygon().intersection(&b.to_polygon());
+                assert_relative_eq!(overlap.unsigned_area(), 0., epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn convex() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let triangles = square.triangulate();
+        assert_eq!(triangles.len(), 2);
+        assert_valid_triangulation(&square, &triangles);
+    }
+
+    #[test]
+    fn concave() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 3., y: 4.),
+            (x: 3., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let triangles = polygon.triangulate();
+        assert_eq!(triangles.len(), 6);
+        assert_valid_triangulation(&polygon, &triangles);
+
+        // Clockwise input
+        let mut reversed = polygon.clone();
+        reversed.exterior_mut(|exterior| exterior.0.reverse());
+        assert_valid_triangulation(&polygon, &reversed.triangulate());
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+                [(x: 6., y: 2.), (x: 8., y: 2.), (x: 7., y: 8.)],
+                [(x: 2., y: 6.), (x: 4., y: 6.), (x: 3., y: 8.)],
+            ],
+        ];
+        let triangles = polygon.triangulate();
+        assert_valid_triangulation(&polygon, &triangles);
+    }
+
+    #[test]
+    fn collinear_vertices() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_valid_triangulation(&polygon, &polygon.triangulate());
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert!(empty.triangulate().is_empty());
+
+        let line = polygon![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert!(line.triangulate().is_empty());
+
+        let collinear = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        assert!(collinear.triangulate().is_empty());
+    }
+
+    #[test]
+    fn louisiana() {
+        let polygon: Polygon<f64> =
+            Polygon::new(include!("test_fixtures/louisiana.rs").into(), vec![]);
+        let area: f64 = polygon
+            .triangulate()
+            .iter()
+            .map(|triangle| triangle.signed_area())
+            .sum();
+        assert_relative_eq!(area, polygon.unsigned_area(), max_relative = 1e-12);
+    }
+}
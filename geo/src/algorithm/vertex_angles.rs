@@ -0,0 +1,131 @@
+use crate::{CoordFloat, Coordinate, LineString};
+
+/// Calculate the turn angle at each interior vertex of a `LineString`.
+///
+/// The angle at a vertex is the change of direction between the segment
+/// arriving at it and the segment leaving it, in radians, in the range
+/// `[-π, π]`. It is positive for a left (counter-clockwise) turn, negative
+/// for a right (clockwise) turn, and zero when passing straight through.
+///
+/// The result has one angle per vertex, in order, excluding the first and
+/// last coordinates. For a closed `LineString`, the angle at the vertex
+/// where the ring wraps around, between its last and first segments, is
+/// included at the end.
+///
+/// Repeated consecutive coordinates are treated as a single vertex, since
+/// a zero-length segment has no direction.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::vertex_angles::VertexAngles;
+/// use geo::line_string;
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 1., y: 1.),
+///     (x: 2., y: 1.),
+/// ];
+///
+/// assert_eq!(line_string.vertex_angles(), vec![FRAC_PI_2, -FRAC_PI_2]);
+/// ```
+pub trait VertexAngles<T: CoordFloat> {
+    fn vertex_angles(&self) -> Vec<T>;
+}
+
+impl<T: CoordFloat> VertexAngles<T> for LineString<T> {
+    fn vertex_angles(&self) -> Vec<T> {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 3 {
+            return vec![];
+        }
+
+        let deltas: Vec<Coordinate<T>> = coords.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let mut angles: Vec<T> = deltas
+            .windows(2)
+            .map(|pair| turn_angle(pair[0], pair[1]))
+            .collect();
+        if self.is_closed() {
+            angles.push(turn_angle(deltas[deltas.len() - 1], deltas[0]));
+        }
+        angles
+    }
+}
+
+/// The signed change of direction from `before` to `after`.
+pub(crate) fn turn_angle<T: CoordFloat>(before: Coordinate<T>, after: Coordinate<T>) -> T {
+    let cross = before.x * after.y - before.y * after.x;
+    let dot = before.x * after.x + before.y * after.y;
+    cross.atan2(dot)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn right_angles() {
+        let left = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(left.vertex_angles(), vec![FRAC_PI_2]);
+        let right = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: -1.)];
+        assert_eq!(right.vertex_angles(), vec![-FRAC_PI_2]);
+    }
+
+    #[test]
+    fn straight() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 3., y: 3.),
+            (x: 3., y: 3.),
+            (x: 4., y: 4.),
+        ];
+        assert_eq!(line_string.vertex_angles(), vec![0., 0.]);
+    }
+
+    #[test]
+    fn closed() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(square.vertex_angles(), vec![FRAC_PI_2; 4]);
+
+        let mut clockwise = square.clone();
+        clockwise.0.reverse();
+        assert_eq!(clockwise.vertex_angles(), vec![-FRAC_PI_2; 4]);
+        // The turns of a simple ring add up to a full turn
+        let diamond = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let total: f64 = diamond.vertex_angles().iter().sum();
+        assert_relative_eq!(total, 2. * PI);
+        assert_relative_eq!(diamond.vertex_angles()[0], 3. * FRAC_PI_4);
+    }
+
+    #[test]
+    fn reversal() {
+        let line_string: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(line_string.vertex_angles()[0].abs(), PI);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.vertex_angles().is_empty());
+        let line = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.)];
+        assert!(line.vertex_angles().is_empty());
+    }
+}
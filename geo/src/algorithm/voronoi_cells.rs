@@ -0,0 +1,213 @@
+use crate::algorithm::delaunay_triangulation::DelaunayTriangulation;
+use crate::{Coordinate, GeoFloat, LineString, MultiPoint, OrderedCoord, Polygon, Rect};
+use std::collections::HashMap;
+
+/// Compute the [Voronoi diagram] of a set of points, clipped to a
+/// bounding `Rect`.
+///
+/// The cell of a point is the region of `bounds` which is at least as
+/// close to it as to any other point. The cells are computed from the
+/// [`DelaunayTriangulation`] of the points: each cell is bounded by the
+/// perpendicular bisectors between its point and its Delaunay neighbours.
+///
+/// The `i`th `Polygon` of the result is the cell of the `i`th point, with
+/// its exterior oriented counter-clockwise. Duplicate points have the same
+/// cell. A point whose cell doesn't overlap `bounds`, or which has `NaN` or
+/// infinite coordinates, is given an empty `Polygon`.
+///
+/// [Voronoi diagram]: https://en.wikipedia.org/wiki/Voronoi_diagram
+/// [`DelaunayTriangulation`]: crate::algorithm::delaunay_triangulation::DelaunayTriangulation
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::voronoi_cells::VoronoiCells;
+/// use geo::{point, Coordinate, MultiPoint, Rect};
+///
+/// let points = MultiPoint(vec![point!(x: 1., y: 1.), point!(x: 3., y: 1.)]);
+/// let bounds = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 2. });
+///
+/// let cells = points.voronoi_cells(bounds);
+/// assert_eq!(cells.len(), 2);
+/// assert_eq!(cells[0].unsigned_area(), 4.);
+/// assert_eq!(cells[1].unsigned_area(), 4.);
+/// ```
+pub trait VoronoiCells<T: GeoFloat> {
+    fn voronoi_cells(&self, bounds: Rect<T>) -> Vec<Polygon<T>>;
+}
+
+impl<T: GeoFloat> VoronoiCells<T> for MultiPoint<T> {
+    fn voronoi_cells(&self, bounds: Rect<T>) -> Vec<Polygon<T>> {
+        // The Delaunay neighbours of each point
+        let mut neighbours: HashMap<OrderedCoord<T>, Vec<Coordinate<T>>> = HashMap::new();
+        for triangle in self.delaunay_triangulation() {
+            let [a, b, c] = [triangle.0, triangle.1, triangle.2];
+            for &(from, to) in &[(a, b), (b, c), (c, a)] {
+                neighbours.entry(from.into()).or_default().push(to);
+                neighbours.entry(to.into()).or_default().push(from);
+            }
+        }
+
+        self.iter()
+            .map(|point| {
+                let coord = point.0;
+                if !(coord.x.is_finite() && coord.y.is_finite()) {
+                    return Polygon::new(LineString(vec![]), vec![]);
+                }
+                match neighbours.get(&coord.into()) {
+                    Some(neighbours) => cell(coord, neighbours.iter().copied(), bounds),
+                    // Without a triangulation, the points are collinear (or
+                    // too few), and every other point may be a neighbour
+                    None => cell(coord, self.iter().map(|other| other.0), bounds),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The region of `bounds` which is at least as close to `site` as to each
+/// of the `others`.
+fn cell<T: GeoFloat>(
+    site: Coordinate<T>,
+    others: impl Iterator<Item = Coordinate<T>>,
+    bounds: Rect<T>,
+) -> Polygon<T> {
+    let (min, max) = (bounds.min(), bounds.max());
+    let mut ring = vec![
+        min,
+        Coordinate { x: max.x, y: min.y },
+        max,
+        Coordinate { x: min.x, y: max.y },
+    ];
+
+    let two = T::one() + T::one();
+    for other in others {
+        if other == site || !(other.x.is_finite() && other.y.is_finite()) {
+            continue;
+        }
+        let midpoint = Coordinate {
+            x: (site.x + other.x) / two,
+            y: (site.y + other.y) / two,
+        };
+        let normal = other - site;
+        ring = clip_ring(&ring, |coord| {
+            let delta = coord - midpoint;
+            delta.x * normal.x + delta.y * normal.y
+        });
+    }
+
+    if ring.len() < 3 {
+        return Polygon::new(LineString(vec![]), vec![]);
+    }
+    ring.push(ring[0]);
+    Polygon::new(LineString(ring), vec![])
+}
+
+/// Clip a convex ring to the half-plane where `distance` is not positive.
+fn clip_ring<T: GeoFloat>(
+    ring: &[Coordinate<T>],
+    distance: impl Fn(Coordinate<T>) -> T,
+) -> Vec<Coordinate<T>> {
+    let mut clipped = Vec::with_capacity(ring.len() + 1);
+    for (idx, &start) in ring.iter().enumerate() {
+        let end = ring[(idx + 1) % ring.len()];
+        let (start_distance, end_distance) = (distance(start), distance(end));
+        if start_distance <= T::zero() {
+            clipped.push(start);
+        }
+        if (start_distance < T::zero() && end_distance > T::zero())
+            || (start_distance > T::zero() && end_distance < T::zero())
+        {
+            let ratio = start_distance / (start_distance - end_distance);
+            clipped.push(start + (end - start) * ratio);
+        }
+    }
+    clipped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bool_ops::BooleanOps;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::algorithm::intersects::Intersects;
+    use crate::point;
+
+    fn bounds() -> Rect<f64> {
+        Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 4., y: 4. })
+    }
+
+    #[test]
+    fn three_points() {
+        let points = MultiPoint(vec![
+            point!(x: 1., y: 1.),
+            point!(x: 3., y: 1.),
+            point!(x: 2., y: 3.),
+        ]);
+        let cells = points.voronoi_cells(bounds());
+        assert_eq!(cells.len(), 3);
+
+        for (point, cell) in points.iter().zip(&cells) {
+            assert!(cell.contains(point));
+            assert!(cell.signed_area() > 0.);
+        }
+        // Each pair of cells is adjacent, without overlapping
+        for i in 0..3 {
+            for j in i + 1..3 {
+                assert!(cells[i].intersects(&cells[j]));
+                assert_relative_eq!(cells[i].intersection(&cells[j]).unsigned_area(), 0.);
+            }
+        }
+        let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(area, 16.);
+
+        // The Voronoi vertex is the circumcenter of the points
+        let circumcenter = point!(x: 2., y: 1.75);
+        for cell in &cells {
+            assert!(cell
+                .exterior()
+                .points_iter()
+                .any(|vertex| vertex.euclidean_distance(&circumcenter) < 1e-12));
+        }
+    }
+
+    #[test]
+    fn collinear() {
+        let points = MultiPoint(vec![
+            point!(x: 1., y: 1.),
+            point!(x: 3., y: 3.),
+            point!(x: 2., y: 2.),
+        ]);
+        let cells = points.voronoi_cells(bounds());
+        for (point, cell) in points.iter().zip(&cells) {
+            assert!(cell.contains(point));
+        }
+        let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(area, 16.);
+    }
+
+    #[test]
+    fn degenerate() {
+        let single = MultiPoint(vec![point!(x: 1., y: 1.)]);
+        let cells = single.voronoi_cells(bounds());
+        assert_relative_eq!(cells[0].unsigned_area(), 16.);
+
+        let duplicates = MultiPoint(vec![
+            point!(x: 1., y: 1.),
+            point!(x: 3., y: 1.),
+            point!(x: 1., y: 1.),
+        ]);
+        let cells = duplicates.voronoi_cells(bounds());
+        assert_eq!(cells[0], cells[2]);
+        assert_relative_eq!(cells[0].unsigned_area(), 8.);
+
+        // A point whose cell lies entirely outside the bounds
+        let outside = MultiPoint(vec![point!(x: 1., y: 1.), point!(x: 9., y: 1.)]);
+        let cells = outside.voronoi_cells(bounds());
+        assert_relative_eq!(cells[0].unsigned_area(), 16.);
+        assert!(cells[1].exterior().0.is_empty());
+    }
+}
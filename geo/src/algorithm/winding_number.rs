@@ -0,0 +1,222 @@
+use crate::kernels::*;
+use crate::{Coordinate, GeoNum, Line, LineString, MultiPolygon, Point, Polygon};
+
+/// How to decide which points are inside a polygon whose rings wind around
+/// them more than once, such as a self-overlapping ring.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// Inside if the rings wind around the point an odd number of times
+    EvenOdd,
+    /// Inside if the rings wind around the point any non-zero number of
+    /// times, counting clockwise turns as negative
+    NonZero,
+}
+
+/// Calculate the winding number of a `Point` with respect to the rings of
+/// a polygon: the signed number of times they wind around it, counting
+/// counter-clockwise turns as positive.
+///
+/// The winding numbers of all of the rings are added up, so for a polygon
+/// with a counter-clockwise exterior and clockwise interiors, as made by
+/// [`Orient`](crate::algorithm::orient::Orient), it is `1` inside the
+/// polygon and `0` outside it, including within its holes. A
+/// self-overlapping ring can wind around a point more than once.
+///
+/// The winding number of a point on the boundary isn't well defined, and
+/// is that of the points to one side or the other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::winding_number::{FillRule, WindingNumber};
+/// use geo::{point, polygon};
+///
+/// // A ring which goes around twice, with a hole
+/// let polygon = polygon!(
+///     exterior: [
+///         (x: 0., y: 0.),
+///         (x: 10., y: 0.),
+///         (x: 10., y: 10.),
+///         (x: 1., y: 10.),
+///         (x: 1., y: 1.),
+///         (x: 9., y: 1.),
+///         (x: 9., y: 9.),
+///         (x: 0., y: 9.),
+///     ],
+///     interiors: [
+///         [(x: 4., y: 4.), (x: 4., y: 6.), (x: 6., y: 6.), (x: 6., y: 4.)],
+///     ],
+/// );
+///
+/// let point = point!(x: 3., y: 3.);
+/// assert_eq!(polygon.winding_number(point), 2);
+/// assert!(!polygon.contains_with_fill_rule(point, FillRule::EvenOdd));
+/// assert!(polygon.contains_with_fill_rule(point, FillRule::NonZero));
+///
+/// // In the hole
+/// assert_eq!(polygon.winding_number(point!(x: 5., y: 5.)), 1);
+/// ```
+pub trait WindingNumber<T: GeoNum> {
+    fn winding_number(&self, p: Point<T>) -> i32;
+
+    /// Whether a `Point` is inside the polygon under the given fill rule.
+    ///
+    /// Like [`Contains`](crate::algorithm::contains::Contains), points on
+    /// the boundary are not contained. `Contains` itself checks each ring
+    /// with the even-odd rule, and is the same as `FillRule::EvenOdd`
+    /// where the rings don't overlap each other. `FillRule::NonZero`
+    /// depends on the orientation of the rings, and a hole must be
+    /// oriented opposite to the exterior to be left out.
+    fn contains_with_fill_rule(&self, p: Point<T>, rule: FillRule) -> bool;
+}
+
+impl<T: GeoNum> WindingNumber<T> for Polygon<T> {
+    fn winding_number(&self, p: Point<T>) -> i32 {
+        self.interiors()
+            .iter()
+            .fold(ring_winding_number(self.exterior(), p.0), |total, ring| {
+                total + ring_winding_number(ring, p.0)
+            })
+    }
+
+    fn contains_with_fill_rule(&self, p: Point<T>, rule: FillRule) -> bool {
+        let on_boundary = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .any(|ring| ring.lines().any(|line| on_line(&line, p.0)));
+        !on_boundary && fill(self.winding_number(p), rule)
+    }
+}
+
+impl<T: GeoNum> WindingNumber<T> for MultiPolygon<T> {
+    fn winding_number(&self, p: Point<T>) -> i32 {
+        self.0
+            .iter()
+            .fold(0, |total, polygon| total + polygon.winding_number(p))
+    }
+
+    fn contains_with_fill_rule(&self, p: Point<T>, rule: FillRule) -> bool {
+        let on_boundary = self.0.iter().any(|polygon| {
+            std::iter::once(polygon.exterior())
+                .chain(polygon.interiors())
+                .any(|ring| ring.lines().any(|line| on_line(&line, p.0)))
+        });
+        !on_boundary && fill(self.winding_number(p), rule)
+    }
+}
+
+fn fill(winding_number: i32, rule: FillRule) -> bool {
+    match rule {
+        FillRule::EvenOdd => winding_number % 2 != 0,
+        FillRule::NonZero => winding_number != 0,
+    }
+}
+
+fn ring_winding_number<T: GeoNum>(ring: &LineString<T>, coord: Coordinate<T>) -> i32 {
+    ring.lines().map(|line| crossing(&line, coord)).sum()
+}
+
+fn on_line<T: GeoNum>(line: &Line<T>, coord: Coordinate<T>) -> bool {
+    let within = |a: T, b: T, c: T| (a <= c && c <= b) || (b <= c && c <= a);
+    T::Ker::orient2d(line.start, line.end, coord) == Orientation::Collinear
+        && within(line.start.x, line.end.x, coord.x)
+        && within(line.start.y, line.end.y, coord.y)
+}
+
+/// The contribution of `edge` to the winding number of `coord`: `1` or
+/// `-1` if it crosses the ray from `coord` towards positive `x` upwards or
+/// downwards respectively, and `0` otherwise.
+pub(crate) fn crossing<T: GeoNum>(edge: &Line<T>, coord: Coordinate<T>) -> i32 {
+    if (edge.start.y > coord.y) == (edge.end.y > coord.y) {
+        return 0;
+    }
+    let upwards = edge.end.y > edge.start.y;
+    match (upwards, T::Ker::orient2d(edge.start, edge.end, coord)) {
+        (true, Orientation::CounterClockwise) => 1,
+        (false, Orientation::Clockwise) => -1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::contains::Contains;
+    use crate::{point, polygon};
+
+    #[test]
+    fn simple() {
+        let square = polygon![
+            (x: 0, y: 0),
+            (x: 4, y: 0),
+            (x: 4, y: 4),
+            (x: 0, y: 4),
+        ];
+        assert_eq!(square.winding_number(point!(x: 1, y: 2)), 1);
+        assert_eq!(square.winding_number(point!(x: 5, y: 2)), 0);
+        assert_eq!(square.winding_number(point!(x: -1, y: 4)), 0);
+
+        let clockwise = polygon![
+            (x: 0, y: 0),
+            (x: 0, y: 4),
+            (x: 4, y: 4),
+            (x: 4, y: 0),
+        ];
+        assert_eq!(clockwise.winding_number(point!(x: 1, y: 2)), -1);
+        assert_eq!(clockwise.winding_number(point!(x: 5, y: 2)), 0);
+
+        for polygon in &[square, clockwise] {
+            for &rule in &[FillRule::EvenOdd, FillRule::NonZero] {
+                assert!(polygon.contains_with_fill_rule(point!(x: 1, y: 2), rule));
+                assert!(!polygon.contains_with_fill_rule(point!(x: 5, y: 2), rule));
+                // On the boundary, at a vertex and along an edge
+                assert!(!polygon.contains_with_fill_rule(point!(x: 0, y: 0), rule));
+                assert!(!polygon.contains_with_fill_rule(point!(x: 4, y: 1), rule));
+            }
+        }
+    }
+
+    #[test]
+    fn star() {
+        // A pentagram, drawn by joining every second point of a pentagon,
+        // so it winds twice around its center
+        let pentagon: Vec<_> = (0..5)
+            .map(|idx| {
+                let angle = (90. + 72. * idx as f64).to_radians();
+                (10. * angle.cos(), 10. * angle.sin())
+            })
+            .collect();
+        let star = Polygon::new((0..5).map(|idx| pentagon[idx * 2 % 5]).collect(), vec![]);
+        let (center, tip) = (point!(x: 0., y: 0.), point!(x: 0., y: 9.));
+
+        assert_eq!(star.winding_number(center), 2);
+        assert_eq!(star.winding_number(tip), 1);
+        assert_eq!(star.winding_number(point!(x: 0., y: 11.)), 0);
+
+        assert!(!star.contains_with_fill_rule(center, FillRule::EvenOdd));
+        assert!(star.contains_with_fill_rule(center, FillRule::NonZero));
+        assert!(star.contains_with_fill_rule(tip, FillRule::EvenOdd));
+        assert!(star.contains_with_fill_rule(tip, FillRule::NonZero));
+        assert_eq!(
+            star.contains(&center),
+            star.contains_with_fill_rule(center, FillRule::EvenOdd)
+        );
+    }
+
+    #[test]
+    fn holes() {
+        let donut = polygon!(
+            exterior: [(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10), (x: 0, y: 10)],
+            interiors: [[(x: 4, y: 4), (x: 4, y: 6), (x: 6, y: 6), (x: 6, y: 4)]],
+        );
+        assert_eq!(donut.winding_number(point!(x: 5, y: 5)), 0);
+        assert_eq!(donut.winding_number(point!(x: 2, y: 5)), 1);
+        assert!(!donut.contains_with_fill_rule(point!(x: 5, y: 5), FillRule::NonZero));
+        assert!(!donut.contains_with_fill_rule(point!(x: 4, y: 5), FillRule::NonZero));
+
+        // With two copies, overlapping
+        let multi_polygon = MultiPolygon(vec![donut.clone(), donut]);
+        assert_eq!(multi_polygon.winding_number(point!(x: 2, y: 5)), 2);
+        assert!(!multi_polygon.contains_with_fill_rule(point!(x: 2, y: 5), FillRule::EvenOdd));
+        assert!(multi_polygon.contains_with_fill_rule(point!(x: 2, y: 5), FillRule::NonZero));
+    }
+}
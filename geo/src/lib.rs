@@ -24,7 +24,7 @@
 //!
 //! ## Optional Features (these can be activated in your `cargo.toml`)
 //! The following optional features are available:
-//! - `use-proj`: enable coordinate conversion and transformation of `Point` geometries using the [`proj`](https://docs.rs/proj) crate
+//! - `use-proj`: enable coordinate conversion and transformation of geometries using the [`proj`](https://docs.rs/proj) crate
 //! - `proj-network`: enables functionality for `proj` crate's network grid. After enabling
 //! this feature, some [further
 //! configuration](https://docs.rs/proj/0.20.5/proj/#grid-file-download) is
@@ -55,7 +55,8 @@ pub use crate::types::*;
 
 pub use geo_types::{
     line_string, point, polygon, CoordFloat, CoordNum, Coordinate, Geometry, GeometryCollection,
-    Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    Line, LineString, MultiLineString, MultiPoint, MultiPolygon, OrderedCoord, OrderedGeometry,
+    Point, Polygon, Rect, Triangle,
 };
 
 /// This module includes all the functions of geometric calculations
@@ -90,19 +91,31 @@ const EARTH_FLATTENING: f64 =
 /// A prelude which re-exports the traits for manipulating objects in this
 /// crate. Typically imported with `use geo::prelude::*`.
 pub mod prelude {
-    pub use crate::algorithm::area::Area;
+    pub use crate::algorithm::area::{Area, TotalArea};
     pub use crate::algorithm::bearing::Bearing;
+    pub use crate::algorithm::bool_ops::BooleanOps;
+    pub use crate::algorithm::bounding_circle::BoundingCircle;
     pub use crate::algorithm::bounding_rect::BoundingRect;
-    pub use crate::algorithm::centroid::Centroid;
+    pub use crate::algorithm::centroid::{BoundaryCentroid, Centroid};
+    pub use crate::algorithm::chaikin_smoothing::ChaikinSmoothing;
     pub use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    pub use crate::algorithm::clip_to_rect::ClipToRect;
+    pub use crate::algorithm::closest_pair::ClosestPair;
     pub use crate::algorithm::closest_point::ClosestPoint;
     pub use crate::algorithm::contains::Contains;
     pub use crate::algorithm::convex_hull::ConvexHull;
+    pub use crate::algorithm::dbscan::Dbscan;
+    pub use crate::algorithm::delaunay_triangulation::DelaunayTriangulation;
+    pub use crate::algorithm::diameter::Diameter;
+    pub use crate::algorithm::diff::Diff;
     pub use crate::algorithm::dimensions::HasDimensions;
     pub use crate::algorithm::euclidean_distance::EuclideanDistance;
-    pub use crate::algorithm::euclidean_length::EuclideanLength;
+    pub use crate::algorithm::euclidean_length::{EuclideanLength, TotalLength};
     pub use crate::algorithm::extremes::Extremes;
+    pub use crate::algorithm::fit_to_rect::FitToRect;
     pub use crate::algorithm::frechet_distance::FrechetDistance;
+    pub use crate::algorithm::geodesic_area::GeodesicArea;
+    pub use crate::algorithm::geodesic_azimuth::GeodesicAzimuth;
     pub use crate::algorithm::geodesic_distance::GeodesicDistance;
     pub use crate::algorithm::geodesic_intermediate::GeodesicIntermediate;
     pub use crate::algorithm::geodesic_length::GeodesicLength;
@@ -110,18 +123,50 @@ pub mod prelude {
     pub use crate::algorithm::haversine_distance::HaversineDistance;
     pub use crate::algorithm::haversine_intermediate::HaversineIntermediate;
     pub use crate::algorithm::haversine_length::HaversineLength;
+    pub use crate::algorithm::hex_grid::HexGrid;
+    pub use crate::algorithm::insert_point_on_line::InsertPointOnLine;
+    pub use crate::algorithm::intersection_over_union::IntersectionOverUnion;
     pub use crate::algorithm::intersects::Intersects;
     pub use crate::algorithm::is_convex::IsConvex;
+    pub use crate::algorithm::largest_polygon::LargestPolygon;
+    pub use crate::algorithm::make_valid::MakeValid;
     pub use crate::algorithm::map_coords::MapCoords;
+    pub use crate::algorithm::minkowski_sum::MinkowskiSum;
+    pub use crate::algorithm::normalize::Normalize;
+    pub use crate::algorithm::offset::Offset;
     pub use crate::algorithm::orient::Orient;
+    pub use crate::algorithm::perimeter::Perimeter;
+    pub use crate::algorithm::pole_of_inaccessibility::PoleOfInaccessibility;
     #[cfg(feature = "use-proj")]
     pub use crate::algorithm::proj::Proj;
+    pub use crate::algorithm::project_point::ProjectPoint;
+    pub use crate::algorithm::quantized_hash::QuantizedHash;
+    pub use crate::algorithm::remove_spikes::RemoveSpikes;
+    pub use crate::algorithm::resample::Resample;
     pub use crate::algorithm::rotate::{Rotate, RotatePoint};
-    pub use crate::algorithm::simplify::Simplify;
+    pub use crate::algorithm::round_coords::RoundCoords;
+    pub use crate::algorithm::round_corners::RoundCorners;
+    pub use crate::algorithm::sample_grid::SampleGrid;
+    pub use crate::algorithm::scale::Scale;
+    pub use crate::algorithm::self_overlaps::SelfOverlaps;
+    pub use crate::algorithm::side_of::SideOf;
+    pub use crate::algorithm::signed_distance::SignedDistance;
+    pub use crate::algorithm::simplify::{
+        Simplify, SimplifyPreserveArea, SimplifyPreservingJunctions, SimplifyShared,
+    };
     pub use crate::algorithm::simplifyvw::SimplifyVW;
+    pub use crate::algorithm::split::Split;
+    pub use crate::algorithm::split_at_self_intersections::SplitAtSelfIntersections;
+    pub use crate::algorithm::square_grid::{BinPoints, SquareGrid};
+    pub use crate::algorithm::straight_skeleton::StraightSkeleton;
+    pub use crate::algorithm::to_circle::ToCircle;
     pub use crate::algorithm::translate::Translate;
+    pub use crate::algorithm::triangulate::Triangulate;
+    pub use crate::algorithm::vertex_angles::VertexAngles;
     pub use crate::algorithm::vincenty_distance::VincentyDistance;
     pub use crate::algorithm::vincenty_length::VincentyLength;
+    pub use crate::algorithm::voronoi_cells::VoronoiCells;
+    pub use crate::algorithm::winding_number::WindingNumber;
 }
 
 /// A common numeric trait used for geo algorithms.